@@ -0,0 +1,63 @@
+//! Benchmarks comparing generated swizzle functions against the equivalent hand-written field
+//! shuffle, and against a naive copy of the value, for structs of `u8`, `f32`, and `f64`.
+//!
+//! If the generated `const fn` + `#[inline]` functions are zero-cost, all three should compile
+//! down to the same handful of register moves and take the same time.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use swizzle::swizzle;
+
+macro_rules! bench_vec4 {
+    ($name:ident, $vec:ident, $t:ty, [$x:expr, $y:expr, $z:expr, $w:expr]) => {
+        #[derive(Clone, Copy)]
+        struct $vec {
+            x: $t,
+            y: $t,
+            z: $t,
+            w: $t,
+        }
+
+        impl $vec {
+            swizzle!($vec { x, y, z, w });
+
+            #[inline]
+            fn hand_wzyx(&self) -> $vec {
+                $vec {
+                    x: self.w,
+                    y: self.z,
+                    z: self.y,
+                    w: self.x,
+                }
+            }
+        }
+
+        fn $name(c: &mut Criterion) {
+            let mut group = c.benchmark_group(stringify!($vec));
+            let v = $vec {
+                x: $x,
+                y: $y,
+                z: $z,
+                w: $w,
+            };
+
+            group.bench_function("swizzle wzyx", |b| b.iter(|| black_box(v).wzyx()));
+            group.bench_function("hand-written wzyx", |b| {
+                b.iter(|| black_box(v).hand_wzyx())
+            });
+            group.bench_function("copy", |b| b.iter(|| black_box(v)));
+            group.bench_function("swizzle chain", |b| {
+                b.iter(|| black_box(v).wzyx().yxwz().zwxy())
+            });
+            group.finish();
+        }
+    };
+}
+
+bench_vec4!(bench_u8, Vec4U8, u8, [1, 2, 3, 4]);
+bench_vec4!(bench_f32, Vec4F32, f32, [1.0, 2.0, 3.0, 4.0]);
+bench_vec4!(bench_f64, Vec4F64, f64, [1.0, 2.0, 3.0, 4.0]);
+
+criterion_group!(benches, bench_u8, bench_f32, bench_f64);
+criterion_main!(benches);
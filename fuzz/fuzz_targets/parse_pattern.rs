@@ -0,0 +1,54 @@
+//! Fuzzes the parsing of runtime swizzle patterns from untrusted strings, e.g. config files.
+//!
+//! Every input must parse the same way through `parse`, `FromStr`, and `swizzle_str` without
+//! panicking, and every valid pattern must swizzle the same as the generated function of the same
+//! name.
+//!
+//! ```text
+//! cargo +nightly fuzz run parse_pattern
+//! ```
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use swizzle::{swizzle, swizzle_dyn};
+
+#[derive(Debug, PartialEq)]
+struct Vec4 {
+    x: u8,
+    y: u8,
+    z: u8,
+    w: u8,
+}
+
+impl Vec4 {
+    swizzle!(table Vec4 { x, y, z, w });
+}
+
+swizzle_dyn!(Vec4: u8 { x, y, z, w });
+
+const VALUE: Vec4 = Vec4 {
+    x: 1,
+    y: 2,
+    z: 3,
+    w: 4,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = core::str::from_utf8(data) else {
+        return;
+    };
+    let parsed = Vec4Pattern::parse(s);
+    assert_eq!(s.parse::<Vec4Pattern>(), parsed);
+
+    let swizzled = VALUE.swizzle_str(s);
+    match parsed {
+        Ok(pattern) => {
+            let (name, swizzle) = Vec4::SWIZZLE_FNS[pattern.rank().unwrap()];
+            assert_eq!(name, s);
+            assert_eq!(pattern.to_string(), s);
+            assert_eq!(swizzled, Some(swizzle(&VALUE)));
+        }
+        Err(_) => assert_eq!(swizzled, None),
+    }
+});
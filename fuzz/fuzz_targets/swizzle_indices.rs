@@ -0,0 +1,60 @@
+//! Fuzzes the checked runtime swizzle with untrusted indices.
+//!
+//! Each input byte is an index, so both wrong numbers of indices and indices out of range are
+//! covered. `try_swizzle` must return an error for those without panicking, and otherwise swizzle
+//! the same as `swizzle_dyn` and the generated function taking the fields at the indices.
+//!
+//! ```text
+//! cargo +nightly fuzz run swizzle_indices
+//! ```
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use swizzle::{SwizzleError, swizzle, swizzle_dyn};
+
+#[derive(Debug, PartialEq)]
+struct Vec4 {
+    x: u8,
+    y: u8,
+    z: u8,
+    w: u8,
+}
+
+impl Vec4 {
+    swizzle!(table Vec4 { x, y, z, w });
+}
+
+swizzle_dyn!(Vec4: u8 { x, y, z, w });
+
+const VALUE: Vec4 = Vec4 {
+    x: 1,
+    y: 2,
+    z: 3,
+    w: 4,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let idx: Vec<usize> = data.iter().map(|&index| usize::from(index % 8)).collect();
+    let result = VALUE.try_swizzle(&idx);
+
+    let Ok(idx) = <[usize; 4]>::try_from(idx.as_slice()) else {
+        assert_eq!(
+            result,
+            Err(SwizzleError::WrongArity {
+                expected: 4,
+                found: idx.len()
+            })
+        );
+        return;
+    };
+    if let Some(&index) = idx.iter().find(|&&index| index >= 4) {
+        assert_eq!(result, Err(SwizzleError::IndexOutOfRange { index, len: 4 }));
+        return;
+    }
+
+    let pattern = Vec4Pattern::new(idx.map(|index| Vec4Axis::ALL[index]));
+    let (_, swizzle) = Vec4::SWIZZLE_FNS[pattern.rank().unwrap()];
+    assert_eq!(result, Ok(VALUE.swizzle_dyn(idx)));
+    assert_eq!(result, Ok(swizzle(&VALUE)));
+});
@@ -0,0 +1,354 @@
+//! Generic reordering of the fields of any struct implementing [`Components`].
+//!
+//! ```rust
+//! use swizzle::{algo, swizzle_dyn};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Rgb {
+//!     r: u8,
+//!     g: u8,
+//!     b: u8,
+//! }
+//!
+//! swizzle_dyn!(Rgb: u8 { r, g, b });
+//!
+//! let rgb = Rgb { r: 10, g: 20, b: 30 };
+//! let bgr = algo::reverse(&rgb);
+//! assert_eq!(bgr, Rgb { r: 30, g: 20, b: 10 });
+//!
+//! // Recover the channel order that turned one pixel into the other.
+//! let order = algo::find_permutation(&rgb, &bgr).unwrap();
+//! assert_eq!(order.indices(), [2, 1, 0]);
+//! assert_eq!(algo::permute(&rgb, order), bgr);
+//! ```
+
+use crate::{Components, Permutation, SwizzleError};
+
+/// Create an instance where the value at each position is taken from the field of `value` at the
+/// index.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+#[must_use]
+pub fn swizzle<T: Components<N>, const N: usize>(value: &T, idx: [usize; N]) -> T {
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    T::from_fn(|i| value.get(idx[i]))
+}
+
+/// Swizzle `value` in place by a slice of indices, where the value at each position is taken from
+/// the field at the index. This is all that's needed to implement
+/// [`DynSwizzle`](crate::DynSwizzle) for a [`Components`] implementor.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::WrongArity`] if the number of indices doesn't match the number of
+/// fields, or [`SwizzleError::IndexOutOfRange`] if any index is out of range. The value is
+/// unchanged on error.
+pub fn try_swizzle_in_place<T: Components<N>, const N: usize>(
+    value: &mut T,
+    idx: &[usize],
+) -> Result<(), SwizzleError> {
+    let idx: [usize; N] = idx.try_into().map_err(|_| SwizzleError::WrongArity {
+        expected: N,
+        found: idx.len(),
+    })?;
+    if let Some(&index) = idx.iter().find(|&&index| index >= N) {
+        return Err(SwizzleError::IndexOutOfRange { index, len: N });
+    }
+    *value = T::from_fn(|i| value.get(idx[i]));
+    Ok(())
+}
+
+/// Swizzle every item of `items` in place, where the value at each position is taken from the
+/// field of the item at the index. The indices are checked once for the whole slice.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+pub fn swizzle_slice_in_place<T: Components<N>, const N: usize>(items: &mut [T], idx: [usize; N]) {
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    for item in items {
+        *item = T::from_fn(|i| item.get(idx[i]));
+    }
+}
+
+/// Swizzle every item of `src` into the item of `dst` at the same position, where the value at
+/// each position is taken from the field of the source item at the index. The destination may
+/// have a different number of fields, e.g. to drop the alpha channel of a row of pixels.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range, or if the slices have different lengths.
+pub fn swizzle_slice<T, U, const N: usize, const M: usize>(
+    src: &[T],
+    dst: &mut [U],
+    idx: [usize; M],
+) where
+    T: Components<N>,
+    U: Components<M, Component = T::Component>,
+{
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    assert_eq!(src.len(), dst.len(), "swizzle slice lengths differ");
+    for (from, to) in src.iter().zip(dst) {
+        *to = U::from_fn(|i| from.get(idx[i]));
+    }
+}
+
+/// Like [`swizzle_slice_in_place`], but with the items split across threads with `rayon`.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+#[cfg(feature = "rayon")]
+pub fn par_swizzle_slice_in_place<T, const N: usize>(items: &mut [T], idx: [usize; N])
+where
+    T: Components<N> + Send,
+{
+    use rayon::prelude::*;
+
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    items
+        .par_iter_mut()
+        .for_each(|item| *item = T::from_fn(|i| item.get(idx[i])));
+}
+
+/// Like [`swizzle_slice`], but with the items split across threads with `rayon`.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range, or if the slices have different lengths.
+#[cfg(feature = "rayon")]
+pub fn par_swizzle_slice<T, U, const N: usize, const M: usize>(
+    src: &[T],
+    dst: &mut [U],
+    idx: [usize; M],
+) where
+    T: Components<N> + Sync,
+    U: Components<M, Component = T::Component> + Send,
+{
+    use rayon::prelude::*;
+
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    assert_eq!(src.len(), dst.len(), "swizzle slice lengths differ");
+    src.par_iter()
+        .zip(dst)
+        .for_each(|(from, to)| *to = U::from_fn(|i| from.get(idx[i])));
+}
+
+/// Create an instance with the fields of `value` in reverse order.
+#[must_use]
+pub fn reverse<T: Components<N>, const N: usize>(value: &T) -> T {
+    T::from_fn(|i| value.get(N - 1 - i))
+}
+
+/// Create an instance with the fields of `value` rotated towards the start by `mid` positions, so
+/// the field at index `mid` comes first. `mid` wraps around the number of fields.
+#[must_use]
+pub fn rotate_left<T: Components<N>, const N: usize>(value: &T, mid: usize) -> T {
+    T::from_fn(|i| value.get((i + mid) % N))
+}
+
+/// Create an instance with the fields of `value` rotated towards the end by `k` positions, so the
+/// last `k` fields come first. `k` wraps around the number of fields.
+#[must_use]
+pub fn rotate_right<T: Components<N>, const N: usize>(value: &T, k: usize) -> T {
+    T::from_fn(|i| value.get((i + N - k % N) % N))
+}
+
+/// Create an instance with the fields at indices `a` and `b` of `value` swapped.
+///
+/// # Panics
+///
+/// Panics if either index is out of range.
+#[must_use]
+pub fn swap<T: Components<N>, const N: usize>(value: &T, a: usize, b: usize) -> T {
+    assert!(a < N && b < N, "swizzle index out of range");
+    T::from_fn(|i| {
+        value.get(if i == a {
+            b
+        } else if i == b {
+            a
+        } else {
+            i
+        })
+    })
+}
+
+/// Create an instance with the fields of `value` permuted, where the value at each position is
+/// taken from the field at the permutation's index.
+#[must_use]
+pub fn permute<T: Components<N>, const N: usize>(value: &T, permutation: Permutation<N>) -> T {
+    let idx = permutation.indices();
+    T::from_fn(|i| value.get(idx[i]))
+}
+
+/// Find a permutation that turns `from` into `to`, or `None` if `to` isn't a reordering of the
+/// fields of `from`. If some fields are equal, the first unused match is taken for each position.
+#[must_use]
+pub fn find_permutation<T, const N: usize>(from: &T, to: &T) -> Option<Permutation<N>>
+where
+    T: Components<N>,
+    T::Component: PartialEq,
+{
+    let mut idx = [0; N];
+    let mut used = [false; N];
+    for (i, index) in idx.iter_mut().enumerate() {
+        let target = to.get(i);
+        let j = (0..N).find(|&j| !used[j] && from.get(j) == target)?;
+        used[j] = true;
+        *index = j;
+    }
+    Permutation::new(idx).ok()
+}
+
+/// Whether `to` is a reordering of the fields of `from`.
+#[must_use]
+pub fn is_permutation_of<T, const N: usize>(from: &T, to: &T) -> bool
+where
+    T: Components<N>,
+    T::Component: PartialEq,
+{
+    find_permutation(from, to).is_some()
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::swizzle_dyn;
+
+    #[derive(Debug, PartialEq)]
+    struct TestStruct {
+        a: u8,
+        b: u8,
+        c: u8,
+        d: u8,
+    }
+
+    swizzle_dyn!(TestStruct: u8 { a, b, c, d });
+
+    const S: TestStruct = TestStruct {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+    };
+
+    fn fields(s: &TestStruct) -> [u8; 4] {
+        [s.a, s.b, s.c, s.d]
+    }
+
+    #[test]
+    fn test_swizzle() {
+        assert_eq!(fields(&swizzle(&S, [3, 3, 0, 1])), [4, 4, 1, 2]);
+
+        let mut s = swizzle(&S, [0, 1, 2, 3]);
+        assert_eq!(try_swizzle_in_place(&mut s, &[1, 0, 3, 2]), Ok(()));
+        assert_eq!(fields(&s), [2, 1, 4, 3]);
+        assert_eq!(
+            try_swizzle_in_place(&mut s, &[0, 4, 0, 0]),
+            Err(SwizzleError::IndexOutOfRange { index: 4, len: 4 })
+        );
+        assert_eq!(
+            try_swizzle_in_place(&mut s, &[0]),
+            Err(SwizzleError::WrongArity {
+                expected: 4,
+                found: 1
+            })
+        );
+        assert_eq!(fields(&s), [2, 1, 4, 3]);
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_swizzle_out_of_range() {
+        let _ = swizzle(&S, [0, 1, 2, 4]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Pair {
+        a: u8,
+        b: u8,
+    }
+
+    swizzle_dyn!(Pair: u8 { a, b });
+
+    #[test]
+    fn test_swizzle_slice() {
+        let mut items = [S, swizzle(&S, [3, 2, 1, 0])];
+        swizzle_slice_in_place(&mut items, [1, 0, 0, 3]);
+        assert_eq!(fields(&items[0]), [2, 1, 1, 4]);
+        assert_eq!(fields(&items[1]), [3, 4, 4, 1]);
+
+        let mut pairs = [Pair { a: 0, b: 0 }, Pair { a: 0, b: 0 }];
+        swizzle_slice(&items, &mut pairs, [3, 0]);
+        assert_eq!(pairs, [Pair { a: 4, b: 2 }, Pair { a: 1, b: 3 }]);
+    }
+
+    #[test]
+    #[should_panic = "swizzle slice lengths differ"]
+    fn test_swizzle_slice_lengths_differ() {
+        let mut pairs = [Pair { a: 0, b: 0 }];
+        swizzle_slice(&[S, S], &mut pairs, [0, 1]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_swizzle_slice() {
+        let mut items = [S, swizzle(&S, [3, 2, 1, 0])];
+        par_swizzle_slice_in_place(&mut items, [1, 0, 0, 3]);
+        assert_eq!(fields(&items[0]), [2, 1, 1, 4]);
+        assert_eq!(fields(&items[1]), [3, 4, 4, 1]);
+
+        let mut pairs = [Pair { a: 0, b: 0 }, Pair { a: 0, b: 0 }];
+        par_swizzle_slice(&items, &mut pairs, [3, 0]);
+        assert_eq!(pairs, [Pair { a: 4, b: 2 }, Pair { a: 1, b: 3 }]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(fields(&reverse(&S)), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(fields(&rotate_left(&S, 1)), [2, 3, 4, 1]);
+        assert_eq!(fields(&rotate_left(&S, 5)), [2, 3, 4, 1]);
+        assert_eq!(fields(&rotate_right(&S, 1)), [4, 1, 2, 3]);
+        assert_eq!(fields(&rotate_right(&S, 6)), [3, 4, 1, 2]);
+        assert_eq!(rotate_right(&rotate_left(&S, 3), 3), S);
+    }
+
+    #[test]
+    fn test_swap() {
+        assert_eq!(fields(&swap(&S, 0, 3)), [4, 2, 3, 1]);
+        assert_eq!(swap(&S, 2, 2), S);
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_swap_out_of_range() {
+        let _ = swap(&S, 0, 4);
+    }
+
+    #[test]
+    fn test_find_permutation() {
+        let to = permute(&S, Permutation::new([3, 0, 2, 1]).unwrap());
+        assert_eq!(fields(&to), [4, 1, 3, 2]);
+
+        let found = find_permutation(&S, &to).unwrap();
+        assert_eq!(found.indices(), [3, 0, 2, 1]);
+        assert!(is_permutation_of(&S, &to));
+        assert!(!is_permutation_of(&S, &TestStruct::from_fn(|_| 1)));
+
+        let repeated = TestStruct {
+            a: 1,
+            b: 1,
+            c: 2,
+            d: 1,
+        };
+        let found = find_permutation(&repeated, &reverse(&repeated)).unwrap();
+        assert_eq!(permute(&repeated, found), reverse(&repeated));
+    }
+}
@@ -0,0 +1,364 @@
+//! Swizzles with target-specific SIMD instructions, with a scalar fallback on other targets.
+//!
+//! ```rust
+//! use swizzle::arch::swizzle_bytes;
+//!
+//! // Convert a row of RGBA pixels to BGRA in place.
+//! let mut row = [1, 2, 3, 4, 5, 6, 7, 8];
+//! swizzle_bytes(&mut row, [2, 1, 0, 3]);
+//! assert_eq!(row, [3, 2, 1, 4, 7, 6, 5, 8]);
+//! ```
+
+/// A 32-bit lane type that 4-field structs can be shuffled by, used by `swizzle_dyn!(sse ...)`.
+pub trait Shuffle4: Copy {
+    /// Returns the values with the value at each position taken from the value at the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of range.
+    fn shuffle4(values: [Self; 4], idx: [usize; 4]) -> [Self; 4];
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod sse {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// Expands to a match on the indices calling `$shuffle` with the matching shuffle mask, as the
+    /// mask of the intrinsics must be a constant. Once inlined with constant indices, the match
+    /// folds to a single shuffle.
+    macro_rules! shuffle_match {
+        ($shuffle:ident($v:expr), $idx:expr) => {
+            shuffle_match!(@outer $shuffle($v), $idx;
+                (0 0) (0 1) (0 2) (0 3) (1 0) (1 1) (1 2) (1 3)
+                (2 0) (2 1) (2 2) (2 3) (3 0) (3 1) (3 2) (3 3))
+        };
+        (@outer $shuffle:ident($v:expr), $idx:expr; $( ($a:literal $b:literal) )+) => {
+            match [$idx[0], $idx[1]] {
+                $( [$a, $b] => shuffle_match!(@inner $shuffle($v), $idx, $a, $b;
+                    (0 0) (0 1) (0 2) (0 3) (1 0) (1 1) (1 2) (1 3)
+                    (2 0) (2 1) (2 2) (2 3) (3 0) (3 1) (3 2) (3 3)), )+
+                _ => panic!("swizzle index out of range"),
+            }
+        };
+        (@inner $shuffle:ident($v:expr), $idx:expr, $a:literal, $b:literal; $( ($c:literal $d:literal) )+) => {
+            match [$idx[2], $idx[3]] {
+                $( [$c, $d] => $shuffle::<{ $a | ($b << 2) | ($c << 4) | ($d << 6) }>($v), )+
+                _ => panic!("swizzle index out of range"),
+            }
+        };
+    }
+
+    #[inline(always)]
+    fn shuffle_ps<const MASK: i32>(v: __m128) -> __m128 {
+        // SAFETY: SSE is enabled for the target, as checked by the `cfg` of the module.
+        unsafe { _mm_shuffle_ps::<MASK>(v, v) }
+    }
+
+    #[inline(always)]
+    fn shuffle_epi32<const MASK: i32>(v: __m128i) -> __m128i {
+        // SAFETY: SSE2 is enabled for the target, as checked by the `cfg` of the module.
+        unsafe { _mm_shuffle_epi32::<MASK>(v) }
+    }
+
+    impl super::Shuffle4 for f32 {
+        #[inline(always)]
+        fn shuffle4(values: [f32; 4], idx: [usize; 4]) -> [f32; 4] {
+            let mut out = [0.0; 4];
+            // SAFETY: SSE is enabled for the target, and both arrays hold 4 `f32`s.
+            unsafe {
+                let v = _mm_loadu_ps(values.as_ptr());
+                _mm_storeu_ps(out.as_mut_ptr(), shuffle_match!(shuffle_ps(v), idx));
+            }
+            out
+        }
+    }
+
+    macro_rules! impl_shuffle_epi32 {
+        ($( $lane:ty ),+) => {
+            $(
+                impl super::Shuffle4 for $lane {
+                    #[inline(always)]
+                    fn shuffle4(values: [$lane; 4], idx: [usize; 4]) -> [$lane; 4] {
+                        let mut out = [0; 4];
+                        // SAFETY: SSE2 is enabled for the target, and both arrays hold 16 bytes.
+                        unsafe {
+                            let v = _mm_loadu_si128(values.as_ptr().cast());
+                            _mm_storeu_si128(out.as_mut_ptr().cast(), shuffle_match!(shuffle_epi32(v), idx));
+                        }
+                        out
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_shuffle_epi32!(i32, u32);
+}
+
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(
+        feature = "wasm-simd",
+        target_arch = "wasm32",
+        target_feature = "simd128"
+    )
+)))]
+mod scalar {
+    macro_rules! impl_shuffle_scalar {
+        ($( $lane:ty ),+) => {
+            $(
+                impl super::Shuffle4 for $lane {
+                    #[inline]
+                    fn shuffle4(values: [$lane; 4], idx: [usize; 4]) -> [$lane; 4] {
+                        assert!(idx.iter().all(|&i| i < 4), "swizzle index out of range");
+                        idx.map(|i| values[i])
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_shuffle_scalar!(f32, i32, u32);
+}
+
+/// Swizzle every chunk of `N` bytes in place, e.g. every pixel of a row of bytes with a channel
+/// each, where the byte at each position of a chunk is taken from the byte of the chunk at the
+/// index.
+///
+/// On AArch64 the chunks are swizzled 16 bytes at a time with NEON table lookups, and likewise on
+/// WebAssembly with `simd128` swizzles when the `wasm-simd` feature is enabled, for chunks of up to
+/// 16 bytes. Other targets use scalar code.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range, or if the length of `bytes` isn't a multiple of
+/// `N`.
+pub fn swizzle_bytes<const N: usize>(bytes: &mut [u8], idx: [usize; N]) {
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    assert!(
+        N != 0 && bytes.len() % N == 0,
+        "swizzle byte slice length is not a multiple of the number of channels"
+    );
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    let start = neon::swizzle_bytes(bytes, idx);
+    #[cfg(all(
+        feature = "wasm-simd",
+        target_arch = "wasm32",
+        target_feature = "simd128"
+    ))]
+    let start = wasm::swizzle_bytes(bytes, idx);
+    #[cfg(not(any(
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(
+            feature = "wasm-simd",
+            target_arch = "wasm32",
+            target_feature = "simd128"
+        )
+    )))]
+    let start = 0;
+
+    for chunk in bytes[start..].chunks_exact_mut(N) {
+        let values: [u8; N] = (&*chunk).try_into().expect("chunk has N bytes");
+        for (byte, &i) in chunk.iter_mut().zip(&idx) {
+            *byte = values[i];
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use core::arch::aarch64::{vld1q_u8, vqtbl1q_u8, vst1q_u8};
+
+    /// Swizzles as many chunks of `N` bytes as fit in whole 16 byte loads, returning the number of
+    /// bytes swizzled, which is a multiple of `N`.
+    #[inline]
+    pub(super) fn swizzle_bytes<const N: usize>(bytes: &mut [u8], idx: [usize; N]) -> usize {
+        if N == 0 || N > 16 {
+            return 0;
+        }
+
+        // Table lookup indices swizzling the whole chunks in 16 bytes, leaving the rest in place.
+        let step = 16 / N * N;
+        let mut table = [0; 16];
+        for (k, index) in table.iter_mut().enumerate() {
+            *index = (if k < step { k / N * N + idx[k % N] } else { k }) as u8;
+        }
+
+        let mut offset = 0;
+        // SAFETY: NEON is enabled for the target, as checked by the `cfg` of the module, and every
+        // load and store is of 16 bytes within the slice.
+        unsafe {
+            let table = vld1q_u8(table.as_ptr());
+            while offset + 16 <= bytes.len() {
+                let ptr = bytes.as_mut_ptr().add(offset);
+                vst1q_u8(ptr, vqtbl1q_u8(vld1q_u8(ptr), table));
+                offset += step;
+            }
+        }
+        offset
+    }
+}
+
+/// The number of chunks swizzled by each task of [`par_swizzle_bytes`], large enough to amortize
+/// the cost of scheduling the task.
+#[cfg(feature = "rayon")]
+const PAR_CHUNKS_PER_TASK: usize = 16 * 1024;
+
+/// Like [`swizzle_bytes`], but with the bytes split across threads with `rayon`, e.g. to convert
+/// a whole image between RGBA and BGRA in one call.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range, or if the length of `bytes` isn't a multiple of
+/// `N`.
+#[cfg(feature = "rayon")]
+pub fn par_swizzle_bytes<const N: usize>(bytes: &mut [u8], idx: [usize; N]) {
+    use rayon::prelude::*;
+
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    assert!(
+        N != 0 && bytes.len() % N == 0,
+        "swizzle byte slice length is not a multiple of the number of channels"
+    );
+    bytes
+        .par_chunks_mut(N * PAR_CHUNKS_PER_TASK)
+        .for_each(|chunk| swizzle_bytes(chunk, idx));
+}
+
+#[cfg(all(
+    feature = "wasm-simd",
+    target_arch = "wasm32",
+    target_feature = "simd128"
+))]
+mod wasm {
+    use core::arch::wasm32::{u8x16_swizzle, v128, v128_load, v128_store};
+
+    /// Swizzles as many chunks of `N` bytes as fit in whole 16 byte loads, returning the number of
+    /// bytes swizzled, which is a multiple of `N`.
+    #[inline]
+    pub(super) fn swizzle_bytes<const N: usize>(bytes: &mut [u8], idx: [usize; N]) -> usize {
+        if N == 0 || N > 16 {
+            return 0;
+        }
+
+        // Swizzle indices reordering the whole chunks in 16 bytes, leaving the rest in place.
+        let step = 16 / N * N;
+        let mut table = [0u8; 16];
+        for (k, index) in table.iter_mut().enumerate() {
+            *index = (if k < step { k / N * N + idx[k % N] } else { k }) as u8;
+        }
+
+        let mut offset = 0;
+        // SAFETY: every load and store is of 16 bytes within the slice or the table, and the loads
+        // and stores are unaligned.
+        unsafe {
+            let table = v128_load(table.as_ptr().cast::<v128>());
+            while offset + 16 <= bytes.len() {
+                let ptr = bytes.as_mut_ptr().add(offset).cast::<v128>();
+                v128_store(ptr, u8x16_swizzle(v128_load(ptr), table));
+                offset += step;
+            }
+        }
+        offset
+    }
+
+    /// Lanes are shuffled with a byte swizzle rather than `i8x16_shuffle`, as the lanes of the
+    /// latter must be constants that can't be computed from the indices.
+    macro_rules! impl_shuffle_wasm {
+        ($( $lane:ty ),+) => {
+            $(
+                impl super::Shuffle4 for $lane {
+                    #[inline]
+                    fn shuffle4(values: [$lane; 4], idx: [usize; 4]) -> [$lane; 4] {
+                        assert!(idx.iter().all(|&i| i < 4), "swizzle index out of range");
+                        let mut table = [0u8; 16];
+                        for (k, index) in table.iter_mut().enumerate() {
+                            *index = (idx[k / 4] * 4 + k % 4) as u8;
+                        }
+                        let mut out = values;
+                        // SAFETY: the arrays and the table all hold 16 bytes, and the loads and
+                        // stores are unaligned.
+                        unsafe {
+                            let v = v128_load(values.as_ptr().cast::<v128>());
+                            let table = v128_load(table.as_ptr().cast::<v128>());
+                            v128_store(out.as_mut_ptr().cast::<v128>(), u8x16_swizzle(v, table));
+                        }
+                        out
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_shuffle_wasm!(f32, i32, u32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle4() {
+        for rank in 0..256 {
+            let idx = [rank & 3, (rank >> 2) & 3, (rank >> 4) & 3, rank >> 6];
+            let expected = idx.map(|i| i as f32 + 0.5);
+            assert_eq!(f32::shuffle4([0.5, 1.5, 2.5, 3.5], idx), expected);
+            assert_eq!(
+                i32::shuffle4([0, -1, -2, -3], idx),
+                idx.map(|i| -(i as i32))
+            );
+            assert_eq!(u32::shuffle4([0, 1, 2, 3], idx), idx.map(|i| i as u32));
+        }
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_shuffle4_out_of_range() {
+        let _ = f32::shuffle4([0.0; 4], [0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_swizzle_bytes() {
+        let mut bytes: [u8; 64] = core::array::from_fn(|i| i as u8);
+        swizzle_bytes(&mut bytes, [2, 1, 0, 3]);
+        for (i, chunk) in bytes.chunks(4).enumerate() {
+            let base = i as u8 * 4;
+            assert_eq!(chunk, [base + 2, base + 1, base, base + 3]);
+        }
+
+        let mut bytes: [u8; 21] = core::array::from_fn(|i| i as u8);
+        swizzle_bytes(&mut bytes, [2, 2, 0]);
+        for (i, chunk) in bytes.chunks(3).enumerate() {
+            let base = i as u8 * 3;
+            assert_eq!(chunk, [base + 2, base + 2, base]);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_swizzle_bytes() {
+        // Two whole tasks and a partial one.
+        let mut bytes = [0u8; 4 * (2 * PAR_CHUNKS_PER_TASK + 3)];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i % 4) as u8;
+        }
+        par_swizzle_bytes(&mut bytes, [2, 1, 0, 3]);
+        assert!(bytes.chunks(4).all(|chunk| chunk == [2, 1, 0, 3]));
+    }
+
+    #[test]
+    #[should_panic = "swizzle byte slice length is not a multiple of the number of channels"]
+    fn test_swizzle_bytes_partial_chunk() {
+        swizzle_bytes(&mut [0; 6], [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,30 @@
+/// Defines a trait for swizzles by field index into a destination with a fixed number of fields.
+macro_rules! arity_trait {
+    ($( $trait:ident $method:ident $len:literal ),+ $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Swizzle by field index into a destination type with ", $len, " field(s), ",
+                "implemented by `swizzle_dyn!`.\n\n",
+                "Generic code can accept anything that can be swizzled into a type without ",
+                "naming the source type."
+            )]
+            pub trait $trait<Dst> {
+                /// Create an instance of the destination type, where the value at each position
+                /// is taken from the field of `self` at the index.
+                ///
+                /// # Panics
+                ///
+                /// Panics if any of the indices is out of range.
+                #[must_use]
+                fn $method(&self, idx: [usize; $len]) -> Dst;
+            }
+        )+
+    };
+}
+
+arity_trait!(
+    Swizzle1 swizzle1 1,
+    Swizzle2 swizzle2 2,
+    Swizzle3 swizzle3 3,
+    Swizzle4 swizzle4 4,
+);
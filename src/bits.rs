@@ -0,0 +1,260 @@
+//! Swizzles of the bits of integers: Morton (Z-order) codes interleaving the bits of 2D and 3D
+//! coordinates, and swizzles of fixed-width groups of bits within an integer.
+//!
+//! Morton codes take the bits of the coordinates in turn from the least significant, starting with
+//! `x`, so nearby coordinates have nearby codes, as used by texture tiling, spatial hashing, and
+//! GPU address swizzling.
+//!
+//! ```rust
+//! use swizzle::bits::{BitSwizzle, morton_decode_2d, morton_encode_2d, morton_encode_3d};
+//!
+//! assert_eq!(morton_encode_2d([0b11, 0b01]), 0b0111);
+//! assert_eq!(morton_decode_2d(0b0111), [0b11, 0b01]);
+//! assert_eq!(morton_encode_3d([1, 1, 1]), 0b111);
+//!
+//! // Swap the 4-bit halves of a byte.
+//! const SWAP_NIBBLES: BitSwizzle<2> = BitSwizzle::new(4, [1, 0]).unwrap();
+//! assert_eq!(SWAP_NIBBLES.apply(0xAB), 0xBA);
+//! ```
+
+/// Spreads the low 32 bits of `value` to the even bits of the result.
+const fn spread_2(value: u32) -> u64 {
+    let mut v = value as u64;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Gathers the even bits of `value` to the low 32 bits of the result, the inverse of [`spread_2`].
+const fn compact_2(value: u64) -> u32 {
+    let mut v = value & 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+    ((v | (v >> 16)) & 0xFFFF_FFFF) as u32
+}
+
+/// Spreads the low 21 bits of `value` to every third bit of the result.
+const fn spread_3(value: u32) -> u64 {
+    let mut v = value as u64 & 0x1F_FFFF;
+    v = (v | (v << 32)) & 0x001F_0000_0000_FFFF;
+    v = (v | (v << 16)) & 0x001F_0000_FF00_00FF;
+    v = (v | (v << 8)) & 0x100F_00F0_0F00_F00F;
+    v = (v | (v << 4)) & 0x10C3_0C30_C30C_30C3;
+    (v | (v << 2)) & 0x1249_2492_4924_9249
+}
+
+/// Gathers every third bit of `value` to the low 21 bits of the result, the inverse of
+/// [`spread_3`].
+const fn compact_3(value: u64) -> u32 {
+    let mut v = value & 0x1249_2492_4924_9249;
+    v = (v | (v >> 2)) & 0x10C3_0C30_C30C_30C3;
+    v = (v | (v >> 4)) & 0x100F_00F0_0F00_F00F;
+    v = (v | (v >> 8)) & 0x001F_0000_FF00_00FF;
+    v = (v | (v >> 16)) & 0x001F_0000_0000_FFFF;
+    ((v | (v >> 32)) & 0x1F_FFFF) as u32
+}
+
+/// Returns the Morton code of the 2D coordinates `[x, y]`, interleaving their bits from the least
+/// significant, starting with `x`.
+#[must_use]
+#[inline]
+pub const fn morton_encode_2d(coords: [u32; 2]) -> u64 {
+    spread_2(coords[0]) | (spread_2(coords[1]) << 1)
+}
+
+/// Returns the 2D coordinates `[x, y]` of the Morton code, the inverse of [`morton_encode_2d`].
+#[must_use]
+#[inline]
+pub const fn morton_decode_2d(code: u64) -> [u32; 2] {
+    [compact_2(code), compact_2(code >> 1)]
+}
+
+/// Returns the Morton code of the 3D coordinates `[x, y, z]`, interleaving their bits from the
+/// least significant, starting with `x`. Only the low 21 bits of each coordinate fit in the code.
+///
+/// # Panics
+///
+/// Panics if any of the coordinates doesn't fit in 21 bits.
+#[must_use]
+#[inline]
+pub const fn morton_encode_3d(coords: [u32; 3]) -> u64 {
+    assert!(
+        coords[0] < 1 << 21 && coords[1] < 1 << 21 && coords[2] < 1 << 21,
+        "coordinate out of range of a 3D Morton code"
+    );
+    spread_3(coords[0]) | (spread_3(coords[1]) << 1) | (spread_3(coords[2]) << 2)
+}
+
+/// Returns the 3D coordinates `[x, y, z]` of the Morton code, the inverse of
+/// [`morton_encode_3d`]. The most significant bit of the code is ignored.
+#[must_use]
+#[inline]
+pub const fn morton_decode_3d(code: u64) -> [u32; 3] {
+    [compact_3(code), compact_3(code >> 1), compact_3(code >> 2)]
+}
+
+/// A swizzle of `N` groups of bits of the same width within a `u64`, counting the groups from the
+/// least significant bits.
+///
+/// The group at each position of the result is taken from the group of the value at the index,
+/// like the swizzles of struct fields, so groups can be moved, repeated, or dropped. Bits above the
+/// `N` groups are cleared.
+///
+/// ```rust
+/// use swizzle::bits::BitSwizzle;
+///
+/// // Reverse the 2-bit fields of a byte.
+/// const REVERSE: BitSwizzle<4> = BitSwizzle::new(2, [3, 2, 1, 0]).unwrap();
+/// assert_eq!(REVERSE.apply(0b11_10_01_00), 0b00_01_10_11);
+///
+/// // Broadcast the low 10 bits to three fields.
+/// let broadcast = BitSwizzle::new(10, [0, 0, 0]).unwrap();
+/// assert_eq!(broadcast.apply(0x3FF), 0x3FFF_FFFF);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitSwizzle<const N: usize> {
+    width: u32,
+    idx: [usize; N],
+}
+
+impl<const N: usize> BitSwizzle<N> {
+    /// Create the swizzle of `N` groups of `width` bits. Returns `None` if the width is `0`, the
+    /// groups don't fit in a `u64`, or any of the indices is out of range.
+    #[must_use]
+    pub const fn new(width: u32, idx: [usize; N]) -> Option<Self> {
+        if width == 0 || N == 0 || N > (u64::BITS / width) as usize {
+            return None;
+        }
+        let mut i = 0;
+        while i < N {
+            if idx[i] >= N {
+                return None;
+            }
+            i += 1;
+        }
+        Some(Self { width, idx })
+    }
+
+    /// The width of each group of bits.
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The index of the group that each position is taken from.
+    #[must_use]
+    pub const fn indices(&self) -> [usize; N] {
+        self.idx
+    }
+
+    /// Returns the value with the group at each position taken from the group of `value` at the
+    /// index.
+    #[must_use]
+    #[inline]
+    pub const fn apply(&self, value: u64) -> u64 {
+        let mask = u64::MAX >> (u64::BITS - self.width);
+        let mut swizzled = 0;
+        let mut i = 0;
+        while i < N {
+            let group = (value >> (self.idx[i] as u32 * self.width)) & mask;
+            swizzled |= group << (i as u32 * self.width);
+            i += 1;
+        }
+        swizzled
+    }
+
+    /// The swizzle equivalent to applying this one followed by `next`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the swizzles have groups of different widths.
+    #[must_use]
+    pub const fn then(&self, next: Self) -> Self {
+        assert!(
+            self.width == next.width,
+            "bit swizzles have groups of different widths"
+        );
+        let mut idx = [0; N];
+        let mut i = 0;
+        while i < N {
+            idx[i] = self.idx[next.idx[i]];
+            i += 1;
+        }
+        Self {
+            width: self.width,
+            idx,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_morton_2d() {
+        assert_eq!(morton_encode_2d([0, 0]), 0);
+        assert_eq!(morton_encode_2d([1, 0]), 1);
+        assert_eq!(morton_encode_2d([0, 1]), 2);
+        assert_eq!(morton_encode_2d([3, 3]), 15);
+        assert_eq!(morton_encode_2d([u32::MAX, 0]), 0x5555_5555_5555_5555);
+        assert_eq!(morton_encode_2d([0, u32::MAX]), 0xAAAA_AAAA_AAAA_AAAA);
+
+        for coords in [[0, 0], [5, 9], [123_456, 789], [u32::MAX, 1 << 31]] {
+            assert_eq!(morton_decode_2d(morton_encode_2d(coords)), coords);
+        }
+    }
+
+    #[test]
+    fn test_morton_3d() {
+        assert_eq!(morton_encode_3d([1, 0, 0]), 1);
+        assert_eq!(morton_encode_3d([0, 1, 0]), 2);
+        assert_eq!(morton_encode_3d([0, 0, 1]), 4);
+        assert_eq!(morton_encode_3d([2, 0, 0]), 8);
+        assert_eq!(morton_encode_3d([0x1F_FFFF; 3]), u64::MAX >> 1);
+
+        for coords in [[0, 0, 0], [5, 9, 17], [0x1F_FFFF, 0, 123_456]] {
+            assert_eq!(morton_decode_3d(morton_encode_3d(coords)), coords);
+        }
+    }
+
+    #[test]
+    #[should_panic = "coordinate out of range of a 3D Morton code"]
+    fn test_morton_3d_out_of_range() {
+        let _ = morton_encode_3d([0, 1 << 21, 0]);
+    }
+
+    #[test]
+    fn test_bit_swizzle() {
+        let swap = BitSwizzle::new(32, [1, 0]).unwrap();
+        assert_eq!(swap.apply(0x1111_2222_3333_4444), 0x3333_4444_1111_2222);
+        assert_eq!(swap.width(), 32);
+        assert_eq!(swap.indices(), [1, 0]);
+
+        let rotate = BitSwizzle::new(3, [1, 2, 0]).unwrap();
+        assert_eq!(rotate.apply(0o7654_0321), 0o132);
+        assert_eq!(rotate.then(rotate).apply(0o321), 0o213);
+        assert_eq!(rotate.then(rotate).then(rotate).apply(0o321), 0o321);
+
+        let bits = BitSwizzle::new(1, core::array::from_fn::<_, 64, _>(|i| 63 - i)).unwrap();
+        assert_eq!(bits.apply(1), 1 << 63);
+
+        assert_eq!(BitSwizzle::new(0, [0]), None);
+        assert_eq!(BitSwizzle::new(33, [0, 1]), None);
+        assert_eq!(BitSwizzle::new(1, [0; 65]), None);
+        assert_eq!(BitSwizzle::new(4, [0, 2]), None);
+        assert_eq!(BitSwizzle::new(4, []), None);
+    }
+
+    #[test]
+    #[should_panic = "bit swizzles have groups of different widths"]
+    fn test_bit_swizzle_then_different_widths() {
+        let _ = BitSwizzle::new(4, [1, 0])
+            .unwrap()
+            .then(BitSwizzle::new(8, [1, 0]).unwrap());
+    }
+}
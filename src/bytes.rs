@@ -0,0 +1,121 @@
+/// Macro for reordering the bytes of an integer by a pattern checked at compile time.
+///
+/// The pattern lists the bytes of the result from the least significant, the same as the order of
+/// `to_le_bytes`, with each digit the index of the byte of the value it's taken from, counting from
+/// the least significant byte. So `"3210"` reverses the bytes of a `u32` like `swap_bytes`,
+/// `"1230"` rotates them right by one byte, and `"1032"` swaps the bytes of each 16-bit half,
+/// which `swap_bytes` can't express. Bytes can be repeated or dropped, e.g. `"0000"` broadcasts the
+/// least significant byte. Indices above 9 are written as `a` to `f`, for the 16 bytes of a `u128`.
+///
+/// Given the integer type and pattern, it expands to a `const fn` taking and returning the type.
+/// Given a value before the type, it expands to the reordered value, which can be used in const
+/// contexts, e.g. as the body of a named `const fn`. A pattern without one valid index per byte of
+/// the type fails to compile.
+///
+/// ```rust
+/// use swizzle::swizzle_bytes;
+///
+/// let swap = swizzle_bytes!(u32, "3210");
+/// assert_eq!(swap(0x4433_2211), 0x4433_2211_u32.swap_bytes());
+///
+/// const fn swap_halves(value: u32) -> u32 {
+///     swizzle_bytes!(value, u32, "1032")
+/// }
+/// assert_eq!(swap_halves(0x4433_2211), 0x3344_1122);
+///
+/// const ROTATED: u64 = swizzle_bytes!(0x8877_6655_4433_2211_u64, u64, "12345670");
+/// assert_eq!(ROTATED, 0x1188_7766_5544_3322);
+/// ```
+#[macro_export]
+macro_rules! swizzle_bytes {
+    ( $type:ident, $pattern:literal $(,)? ) => {{
+        const fn swizzle_bytes(value: $type) -> $type {
+            $crate::swizzle_bytes!(value, $type, $pattern)
+        }
+        swizzle_bytes
+    }};
+    ( $value:expr, $type:ident, $pattern:literal $(,)? ) => {{
+        const IDX: [usize; ::core::mem::size_of::<$type>()] =
+            $crate::__private::byte_pattern($pattern);
+        let bytes = <$type>::to_le_bytes($value);
+        let mut swizzled = [0; ::core::mem::size_of::<$type>()];
+        let mut i = 0;
+        while i < IDX.len() {
+            swizzled[i] = bytes[IDX[i]];
+            i += 1;
+        }
+        <$type>::from_le_bytes(swizzled)
+    }};
+}
+
+/// Parses a `swizzle_bytes!` pattern of `N` bytes, in const contexts.
+///
+/// # Panics
+///
+/// Panics if the pattern doesn't have exactly `N` digits, or any of them is out of range.
+#[must_use]
+pub const fn byte_pattern<const N: usize>(pattern: &str) -> [usize; N] {
+    let pattern = pattern.as_bytes();
+    assert!(
+        pattern.len() == N,
+        "swizzle_bytes!: the pattern must have one digit per byte"
+    );
+    let mut idx = [0; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = match pattern[i] {
+            b @ b'0'..=b'9' => (b - b'0') as usize,
+            b @ b'a'..=b'f' => (b - b'a') as usize + 10,
+            _ => usize::MAX,
+        };
+        assert!(idx[i] < N, "swizzle_bytes!: byte index out of range");
+        i += 1;
+    }
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swizzle_bytes() {
+        assert_eq!(swizzle_bytes!(u16, "10")(0x2211), 0x1122);
+        assert_eq!(swizzle_bytes!(0x2211_u16, u16, "00"), 0x1111);
+        assert_eq!(swizzle_bytes!(u32, "3210")(0x4433_2211), 0x1122_3344);
+        assert_eq!(swizzle_bytes!(u32, "1230")(0x4433_2211), 0x1144_3322);
+        assert_eq!(swizzle_bytes!(i32, "0123")(-2), -2);
+        assert_eq!(
+            swizzle_bytes!(u128, "fedcba9876543210")(u128::MAX / 255),
+            u128::MAX / 255
+        );
+        assert_eq!(
+            swizzle_bytes!(u128, "fedcba9876543210")(0x0102),
+            0x0201 << 112
+        );
+
+        let swap = swizzle_bytes!(u64, "76543210");
+        assert_eq!(swap(0x0807_0605_0403_0201), 0x0102_0304_0506_0708);
+    }
+
+    #[test]
+    fn test_byte_pattern() {
+        assert_eq!(byte_pattern::<4>("3210"), [3, 2, 1, 0]);
+        assert_eq!(
+            byte_pattern::<16>("0123456789abcdef")[10..],
+            [10, 11, 12, 13, 14, 15]
+        );
+    }
+
+    #[test]
+    #[should_panic = "swizzle_bytes!: byte index out of range"]
+    fn test_byte_pattern_out_of_range() {
+        let _ = byte_pattern::<2>("02");
+    }
+
+    #[test]
+    #[should_panic = "swizzle_bytes!: the pattern must have one digit per byte"]
+    fn test_byte_pattern_wrong_length() {
+        let _ = byte_pattern::<4>("210");
+    }
+}
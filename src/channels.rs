@@ -0,0 +1,569 @@
+//! Reorders of the channels of images in raw byte buffers, with padding at the end of each row.
+//!
+//! GUI toolkits, screen capture, and camera pipelines hand over images as a byte buffer with a
+//! stride, the number of bytes from the start of one row to the next, which is often more than
+//! the width of the row. [`reorder_channels`] converts every row between two orders of 8-bit
+//! channels in place, leaving the padding unchanged.
+//!
+//! ```rust
+//! use swizzle::channels::{ChannelOrder, reorder_channels};
+//!
+//! // A 2x2 RGBA image with 4 bytes of padding at the end of each row.
+//! let mut pixels = [1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 0, 0, 0, 0];
+//! reorder_channels(&mut pixels, 2, 2, 12, ChannelOrder::Rgba, ChannelOrder::Bgra).unwrap();
+//! assert_eq!(pixels[..8], [3, 2, 1, 4, 7, 6, 5, 8]);
+//! assert_eq!(pixels[12..20], [11, 10, 9, 12, 15, 14, 13, 16]);
+//! ```
+//!
+//! # Planar Layouts
+//!
+//! Video frames and the inputs of ML models are often planar, with all the values of each channel
+//! together, e.g. `RRR…GGG…BBB…`, rather than interleaved, e.g. `RGBRGB…`.
+//! [`planar_to_interleaved`] and [`interleaved_to_planar`] convert between them for any number of
+//! channels of any type, reordering the channels with a [`Permutation`] on the way, or leaving
+//! them in order with [`Permutation::IDENTITY`].
+//!
+//! ```rust
+//! use swizzle::Permutation;
+//! use swizzle::channels::{interleaved_to_planar, planar_to_interleaved};
+//!
+//! // BGR pixels to RGB planes, e.g. for a model trained on RGB images.
+//! let bgr = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+//! let mut planes = [0.0; 6];
+//! interleaved_to_planar(&bgr, &mut planes, Permutation::new([2, 1, 0]).unwrap());
+//! assert_eq!(planes, [0.3, 0.6, 0.2, 0.5, 0.1, 0.4]);
+//!
+//! let mut rgb = [0.0; 6];
+//! planar_to_interleaved(&planes, &mut rgb, Permutation::<3>::IDENTITY);
+//! assert_eq!(rgb, [0.3, 0.2, 0.1, 0.6, 0.5, 0.4]);
+//! ```
+
+//!
+//! # Packed YUV
+//!
+//! The packed 4:2:2 YUV formats of webcams and capture cards store each pair of pixels as 4
+//! bytes, two luma samples sharing one pair of chroma samples, and differ only in the order of
+//! those bytes. [`reorder_yuv422`] converts between them in place.
+//!
+//! ```rust
+//! use swizzle::channels::{Yuv422Order, reorder_yuv422};
+//!
+//! // One pair of pixels, Y0 U Y1 V.
+//! let mut pixels = [16, 128, 235, 64];
+//! reorder_yuv422(&mut pixels, 2, 1, 4, Yuv422Order::Yuyv, Yuv422Order::Uyvy);
+//! assert_eq!(pixels, [128, 16, 64, 235]);
+//! ```
+//!
+//! # Audio
+//!
+//! Channel swizzles aren't only for pixels: [`reorder_frames`] permutes the channels of every
+//! frame of interleaved audio samples, and [`Surround51`] has the permutations between the common
+//! orders of 5.1 channels.
+//!
+//! ```rust
+//! use swizzle::channels::{Surround51, reorder_frames};
+//!
+//! // One frame of 5.1 from an AAC decoder, in C, L, R, Ls, Rs, LFE order.
+//! let mut samples = [0.3, 0.1, 0.2, 0.5, 0.6, 0.4];
+//! reorder_frames(&mut samples, Surround51::Aac.permutation_to(Surround51::Smpte));
+//! assert_eq!(samples, [0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+//! ```
+
+use crate::{Permutation, SwizzleError};
+
+/// An order of 8-bit channels in memory, where `x` is an unused padding channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    /// Red, green, blue.
+    Rgb,
+    /// Blue, green, red.
+    Bgr,
+    /// Red, green, blue, alpha.
+    Rgba,
+    /// Blue, green, red, alpha.
+    Bgra,
+    /// Alpha, red, green, blue.
+    Argb,
+    /// Alpha, blue, green, red.
+    Abgr,
+    /// Red, green, blue, padding.
+    Rgbx,
+    /// Blue, green, red, padding.
+    Bgrx,
+    /// Padding, red, green, blue.
+    Xrgb,
+    /// Padding, blue, green, red.
+    Xbgr,
+}
+
+impl ChannelOrder {
+    /// The channels in memory order, e.g. `"bgra"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Rgb => "rgb",
+            Self::Bgr => "bgr",
+            Self::Rgba => "rgba",
+            Self::Bgra => "bgra",
+            Self::Argb => "argb",
+            Self::Abgr => "abgr",
+            Self::Rgbx => "rgbx",
+            Self::Bgrx => "bgrx",
+            Self::Xrgb => "xrgb",
+            Self::Xbgr => "xbgr",
+        }
+    }
+
+    /// The number of channels, and bytes, of each pixel.
+    #[must_use]
+    pub const fn channel_count(self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns the swizzle converting pixels of this order to `dst`, taking the channel at each
+    /// position of `dst` from the channel of this order at the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwizzleError::IncompatibleFormats`] if the orders don't both have the same `N`
+    /// channels.
+    pub fn swizzle_to<const N: usize>(self, dst: Self) -> Result<[usize; N], SwizzleError> {
+        let (src, dst) = (self.as_str(), dst.as_str());
+        if src.len() != N || dst.len() != N {
+            return Err(SwizzleError::IncompatibleFormats);
+        }
+        let mut idx = [0; N];
+        for (i, channel) in dst.bytes().enumerate() {
+            idx[i] = src
+                .bytes()
+                .position(|c| c == channel)
+                .ok_or(SwizzleError::IncompatibleFormats)?;
+        }
+        Ok(idx)
+    }
+}
+
+/// Convert the channels of every pixel of an image of `width` by `height` pixels from the `src`
+/// order to `dst` in place, where `stride` is the number of bytes from the start of one row to
+/// the next, which may include padding.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::IncompatibleFormats`] if the orders don't have the same channels. The
+/// pixels are unchanged on error.
+///
+/// # Panics
+///
+/// Panics if `stride` is less than the width of a row, or `pixels` is too short for `width` by
+/// `height` pixels.
+pub fn reorder_channels(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    src: ChannelOrder,
+    dst: ChannelOrder,
+) -> Result<(), SwizzleError> {
+    match src.channel_count() {
+        3 => reorder_rows(pixels, width, height, stride, src.swizzle_to::<3>(dst)?),
+        _ => reorder_rows(pixels, width, height, stride, src.swizzle_to::<4>(dst)?),
+    }
+    Ok(())
+}
+
+fn reorder_rows<const N: usize>(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    idx: [usize; N],
+) {
+    let row_len = width * N;
+    assert!(
+        stride >= row_len,
+        "pixel stride is less than the width of a row"
+    );
+    assert!(
+        height == 0 || pixels.len() >= (height - 1) * stride + row_len,
+        "image is too short for its size"
+    );
+    if row_len == 0 {
+        return;
+    }
+    for row in pixels.chunks_mut(stride).take(height) {
+        crate::arch::swizzle_bytes(&mut row[..row_len], idx);
+    }
+}
+
+/// An order of the bytes of each pair of pixels of a packed 4:2:2 YUV image, where the first and
+/// second `y` are the luma of the first and second pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Yuv422Order {
+    /// Y0, U, Y1, V, also known as YUY2.
+    Yuyv,
+    /// U, Y0, V, Y1.
+    Uyvy,
+    /// Y0, V, Y1, U.
+    Yvyu,
+    /// V, Y0, U, Y1.
+    Vyuy,
+}
+
+impl Yuv422Order {
+    /// The bytes in memory order, e.g. `"yuyv"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Yuyv => "yuyv",
+            Self::Uyvy => "uyvy",
+            Self::Yvyu => "yvyu",
+            Self::Vyuy => "vyuy",
+        }
+    }
+
+    /// The positions of Y0, U, Y1, and V.
+    const fn positions(self) -> [usize; 4] {
+        match self {
+            Self::Yuyv => [0, 1, 2, 3],
+            Self::Uyvy => [1, 0, 3, 2],
+            Self::Yvyu => [0, 3, 2, 1],
+            Self::Vyuy => [1, 2, 3, 0],
+        }
+    }
+
+    /// Returns the swizzle converting pairs of pixels of this order to `dst`, taking the byte at
+    /// each position of `dst` from the byte of this order at the index.
+    #[must_use]
+    pub const fn swizzle_to(self, dst: Self) -> [usize; 4] {
+        let (src, dst) = (self.positions(), dst.positions());
+        let mut idx = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            idx[dst[i]] = src[i];
+            i += 1;
+        }
+        idx
+    }
+}
+
+/// Convert every pair of pixels of a packed 4:2:2 YUV image of `width` by `height` pixels from
+/// the `src` order to `dst` in place, where `stride` is the number of bytes from the start of one
+/// row to the next, which may include padding. An odd `width` is rounded up to the pair of pixels
+/// the last one is stored in.
+///
+/// # Panics
+///
+/// Panics if `stride` is less than the width of a row, or `pixels` is too short for `width` by
+/// `height` pixels.
+pub fn reorder_yuv422(
+    pixels: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    src: Yuv422Order,
+    dst: Yuv422Order,
+) {
+    reorder_rows(
+        pixels,
+        width.div_ceil(2),
+        height,
+        stride,
+        src.swizzle_to(dst),
+    );
+}
+
+/// An order of the channels of 5.1 surround audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Surround51 {
+    /// Front left, front right, center, LFE, back left, back right: the order of SMPTE, WAVE, and
+    /// FFmpeg.
+    Smpte,
+    /// Front left, center, front right, back left, back right, LFE: the order of Vorbis, Opus,
+    /// and film.
+    Vorbis,
+    /// Center, front left, front right, back left, back right, LFE: the order of AAC.
+    Aac,
+}
+
+impl Surround51 {
+    /// The positions of the channels in SMPTE order.
+    const fn positions(self) -> [usize; 6] {
+        match self {
+            Self::Smpte => [0, 1, 2, 3, 4, 5],
+            Self::Vorbis => [0, 2, 1, 5, 3, 4],
+            Self::Aac => [1, 2, 0, 5, 3, 4],
+        }
+    }
+
+    /// Returns the permutation converting frames of this order to `dst`, for [`reorder_frames`].
+    #[must_use]
+    pub const fn permutation_to(self, dst: Self) -> Permutation<6> {
+        let (src, dst) = (self.positions(), dst.positions());
+        let mut idx = [0; 6];
+        let mut i = 0;
+        while i < 6 {
+            idx[dst[i]] = src[i];
+            i += 1;
+        }
+        match Permutation::new(idx) {
+            Ok(permutation) => permutation,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// Permute the channels of every frame of `N` interleaved samples in place, taking the channel at
+/// each position of a frame from the channel at the permutation's index.
+///
+/// # Panics
+///
+/// Panics if the number of samples isn't a multiple of `N`.
+pub fn reorder_frames<T: Copy, const N: usize>(samples: &mut [T], permutation: Permutation<N>) {
+    assert!(
+        N > 0 && samples.len() % N == 0,
+        "sample count is not a multiple of the frame size"
+    );
+    let idx = permutation.indices();
+    for frame in samples.chunks_exact_mut(N) {
+        let reordered: [T; N] = core::array::from_fn(|i| frame[idx[i]]);
+        frame.copy_from_slice(&reordered);
+    }
+}
+
+/// Interleave the `N` planes of `planar`, stored one after another, into `interleaved`, taking
+/// the channel at each position of a pixel from the plane at the permutation's index.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths, or their length isn't a multiple of `N`.
+pub fn planar_to_interleaved<T: Copy, const N: usize>(
+    planar: &[T],
+    interleaved: &mut [T],
+    permutation: Permutation<N>,
+) {
+    let plane_len = check_planar(planar.len(), interleaved.len(), N);
+    let idx = permutation.indices();
+    for (i, pixel) in interleaved.chunks_exact_mut(N).enumerate() {
+        for (channel, &plane) in pixel.iter_mut().zip(&idx) {
+            *channel = planar[plane * plane_len + i];
+        }
+    }
+}
+
+/// Split the pixels of `interleaved` into `N` planes of `planar`, stored one after another, taking
+/// each plane from the channel at the permutation's index.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths, or their length isn't a multiple of `N`.
+pub fn interleaved_to_planar<T: Copy, const N: usize>(
+    interleaved: &[T],
+    planar: &mut [T],
+    permutation: Permutation<N>,
+) {
+    let plane_len = check_planar(planar.len(), interleaved.len(), N);
+    for (plane, &channel) in planar
+        .chunks_exact_mut(plane_len.max(1))
+        .zip(&permutation.indices())
+    {
+        for (value, pixel) in plane.iter_mut().zip(interleaved.chunks_exact(N)) {
+            *value = pixel[channel];
+        }
+    }
+}
+
+/// Returns the length of each plane.
+fn check_planar(planar_len: usize, interleaved_len: usize, channels: usize) -> usize {
+    assert_eq!(
+        planar_len, interleaved_len,
+        "planar and interleaved lengths differ"
+    );
+    assert!(
+        channels > 0 && planar_len % channels == 0,
+        "length is not a multiple of the number of channels"
+    );
+    planar_len / channels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swizzle_to() {
+        assert_eq!(
+            ChannelOrder::Rgba.swizzle_to::<4>(ChannelOrder::Argb),
+            Ok([3, 0, 1, 2])
+        );
+        assert_eq!(
+            ChannelOrder::Xrgb.swizzle_to::<4>(ChannelOrder::Bgrx),
+            Ok([3, 2, 1, 0])
+        );
+        assert_eq!(
+            ChannelOrder::Rgb.swizzle_to::<3>(ChannelOrder::Bgr),
+            Ok([2, 1, 0])
+        );
+        assert_eq!(ChannelOrder::Bgra.channel_count(), 4);
+        assert_eq!(ChannelOrder::Bgr.as_str(), "bgr");
+
+        for (src, dst) in [
+            (ChannelOrder::Rgba, ChannelOrder::Rgbx),
+            (ChannelOrder::Rgb, ChannelOrder::Rgba),
+            (ChannelOrder::Rgba, ChannelOrder::Bgr),
+        ] {
+            assert_eq!(
+                src.swizzle_to::<4>(dst),
+                Err(SwizzleError::IncompatibleFormats)
+            );
+            assert_eq!(
+                src.swizzle_to::<3>(dst),
+                Err(SwizzleError::IncompatibleFormats)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reorder_channels() {
+        let mut pixels = [1, 2, 3, 4, 5, 6, 0, 7, 8, 9, 10, 11, 12, 0, 0xFF];
+        reorder_channels(&mut pixels, 2, 2, 7, ChannelOrder::Bgr, ChannelOrder::Rgb).unwrap();
+        assert_eq!(pixels, [3, 2, 1, 6, 5, 4, 0, 9, 8, 7, 12, 11, 10, 0, 0xFF]);
+
+        assert_eq!(
+            reorder_channels(&mut pixels, 2, 2, 7, ChannelOrder::Rgb, ChannelOrder::Rgba),
+            Err(SwizzleError::IncompatibleFormats)
+        );
+        assert_eq!(pixels[..3], [3, 2, 1]);
+
+        let mut empty = [];
+        reorder_channels(&mut empty, 0, 0, 0, ChannelOrder::Rgba, ChannelOrder::Bgra).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "pixel stride is less than the width of a row"]
+    fn test_reorder_channels_short_stride() {
+        let _ = reorder_channels(
+            &mut [0; 16],
+            2,
+            2,
+            4,
+            ChannelOrder::Rgba,
+            ChannelOrder::Bgra,
+        );
+    }
+
+    #[test]
+    #[should_panic = "image is too short for its size"]
+    fn test_reorder_channels_short_image() {
+        let _ = reorder_channels(
+            &mut [0; 15],
+            2,
+            2,
+            8,
+            ChannelOrder::Rgba,
+            ChannelOrder::Bgra,
+        );
+    }
+
+    #[test]
+    fn test_planar() {
+        let rgba: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut planes = [0; 8];
+        interleaved_to_planar(&rgba, &mut planes, Permutation::<4>::IDENTITY);
+        assert_eq!(planes, [1, 5, 2, 6, 3, 7, 4, 8]);
+
+        let mut argb = [0; 8];
+        planar_to_interleaved(&planes, &mut argb, Permutation::new([3, 0, 1, 2]).unwrap());
+        assert_eq!(argb, [4, 1, 2, 3, 8, 5, 6, 7]);
+
+        let mut round_trip = [0; 8];
+        interleaved_to_planar(&argb, &mut planes, Permutation::new([1, 2, 3, 0]).unwrap());
+        planar_to_interleaved(&planes, &mut round_trip, Permutation::<4>::IDENTITY);
+        assert_eq!(round_trip, rgba);
+
+        interleaved_to_planar::<u8, 3>(&[], &mut [], Permutation::IDENTITY);
+    }
+
+    #[test]
+    #[should_panic = "planar and interleaved lengths differ"]
+    fn test_planar_lengths_differ() {
+        planar_to_interleaved(&[0; 6], &mut [0; 3], Permutation::<3>::IDENTITY);
+    }
+
+    #[test]
+    #[should_panic = "length is not a multiple of the number of channels"]
+    fn test_planar_partial_pixel() {
+        interleaved_to_planar(&[0; 5], &mut [0; 5], Permutation::<2>::IDENTITY);
+    }
+
+    #[test]
+    fn test_yuv422() {
+        use Yuv422Order::*;
+
+        let orders = [Yuyv, Uyvy, Yvyu, Vyuy];
+        for src in orders {
+            assert_eq!(src.swizzle_to(src), [0, 1, 2, 3]);
+            for dst in orders {
+                // Y1 is uppercase, to tell it apart from Y0.
+                let mut pair = Yuyv.swizzle_to(src).map(|i| b"yuYv"[i]);
+                crate::arch::swizzle_bytes(&mut pair, src.swizzle_to(dst));
+                assert_eq!(pair.to_ascii_lowercase(), dst.as_str().as_bytes());
+                assert_eq!(
+                    pair.iter().position(|&b| b == b'y'),
+                    dst.as_str().find('y'),
+                    "{src:?} to {dst:?}"
+                );
+            }
+        }
+
+        // A 3x2 image padded to pairs of pixels, with 2 bytes of padding at the end of each row.
+        let mut pixels = [
+            1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 0, 0,
+        ];
+        reorder_yuv422(&mut pixels, 3, 2, 10, Yuyv, Vyuy);
+        assert_eq!(
+            pixels,
+            [
+                4, 1, 2, 3, 8, 5, 6, 7, 0, 0, 12, 9, 10, 11, 16, 13, 14, 15, 0, 0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reorder_frames() {
+        let mut stereo = [1.0_f32, -1.0, 2.0, -2.0];
+        reorder_frames(&mut stereo, Permutation::new([1, 0]).unwrap());
+        assert_eq!(stereo, [-1.0, 1.0, -2.0, 2.0]);
+
+        let smpte = [1, 2, 3, 4, 5, 6];
+        let mut samples = [1, 2, 3, 4, 5, 6, 1, 2, 3, 4, 5, 6];
+        reorder_frames(
+            &mut samples,
+            Surround51::Smpte.permutation_to(Surround51::Vorbis),
+        );
+        assert_eq!(samples[..6], [1, 3, 2, 5, 6, 4]);
+        assert_eq!(samples[6..], [1, 3, 2, 5, 6, 4]);
+        reorder_frames(
+            &mut samples,
+            Surround51::Vorbis.permutation_to(Surround51::Aac),
+        );
+        assert_eq!(samples[..6], [3, 1, 2, 5, 6, 4]);
+        reorder_frames(
+            &mut samples,
+            Surround51::Aac.permutation_to(Surround51::Smpte),
+        );
+        assert_eq!(samples[..6], smpte);
+
+        assert_eq!(
+            Surround51::Vorbis.permutation_to(Surround51::Aac),
+            Surround51::Vorbis
+                .permutation_to(Surround51::Smpte)
+                .then(Surround51::Smpte.permutation_to(Surround51::Aac))
+        );
+    }
+
+    #[test]
+    #[should_panic = "sample count is not a multiple of the frame size"]
+    fn test_reorder_frames_partial_frame() {
+        reorder_frames(&mut [0.0; 5], Permutation::<2>::IDENTITY);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::prebuilt::{prebuilt, prefix_view};
+use crate::swizzle;
+
+prebuilt! {
+    /// An RGB color, with every channel swizzle to `Rgb`.
+    ///
+    /// ```rust
+    /// use swizzle::{Rgb, Rgba};
+    ///
+    /// let rgb = Rgb::new(255u8, 128, 0);
+    /// assert_eq!(rgb.bgr(), Rgb::new(0, 128, 255));
+    /// assert_eq!(rgb.with_alpha(255), Rgba::new(255, 128, 0, 255));
+    /// ```
+    Rgb(3) { r: 0, g: 1, b: 2 }
+}
+
+prebuilt! {
+    /// An RGBA color, with every channel swizzle to `Rgba` and `Rgb`.
+    ///
+    /// ```rust
+    /// use swizzle::{Rgb, Rgba};
+    ///
+    /// let rgba = Rgba::new(255u8, 128, 0, 64);
+    /// assert_eq!(rgba.bgra(), Rgba::new(0, 128, 255, 64));
+    /// assert_eq!(rgba.rgb(), Rgb::new(255, 128, 0));
+    /// ```
+    Rgba(4) { r: 0, g: 1, b: 2, a: 3 }
+}
+
+prebuilt! {
+    /// An HSV color, with every channel swizzle to `Hsv`.
+    Hsv(3) { h: 0, s: 1, v: 2 }
+}
+
+prebuilt! {
+    /// An HSL color, with every channel swizzle to `Hsl`.
+    Hsl(3) { h: 0, s: 1, l: 2 }
+}
+
+impl<T: Copy> Rgb<T> {
+    swizzle!(Rgb<T> { r, g, b });
+
+    /// Create an RGBA color with the channels of this color and the given alpha.
+    #[must_use]
+    #[inline]
+    pub const fn with_alpha(&self, a: T) -> Rgba<T> {
+        Rgba {
+            r: self.r,
+            g: self.g,
+            b: self.b,
+            a,
+        }
+    }
+}
+
+impl<T: Copy> Rgba<T> {
+    swizzle!(Rgba<T> { r, g, b, a });
+    swizzle!(Rgb<T> { r: (r, g, b, a), g: (r, g, b, a), b: (r, g, b, a) });
+}
+
+prefix_view!(Rgba => Rgb { r, g, b });
+
+impl<T: Copy> Hsv<T> {
+    swizzle!(Hsv<T> { h, s, v });
+}
+
+impl<T: Copy> Hsl<T> {
+    swizzle!(Hsl<T> { h, s, l });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_swizzles() {
+        let rgba = Rgba::new(1, 2, 3, 4);
+        assert_eq!(rgba.argb(), Rgba::new(4, 1, 2, 3));
+        assert_eq!(rgba.bgr(), Rgb::new(3, 2, 1));
+        assert_eq!(rgba.rgb().with_alpha(4), rgba);
+        assert_eq!(rgba.rgb_view(), &rgba.rgb());
+        assert_eq!(Rgb::new(1, 2, 3).gbr(), Rgb::new(2, 3, 1));
+        assert_eq!(Hsv::new(1, 2, 3).vsh(), Hsv::new(3, 2, 1));
+        assert_eq!(Hsl::new(1, 2, 3).lhs(), Hsl::new(3, 1, 2));
+
+        const BGRA: Rgba<u8> = Rgba::new(10, 20, 30, 40).bgra();
+        assert_eq!(BGRA.to_array(), [30, 20, 10, 40]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn test_bytemuck() {
+        // Reinterpret a row of RGBA bytes as pixels without copying.
+        let row = [10u8, 20, 30, 40, 50, 60, 70, 80];
+        let pixels: &[Rgba<u8>] = bytemuck::cast_slice(&row);
+        assert_eq!(pixels[1].bgra(), Rgba::new(70, 60, 50, 80));
+    }
+
+    #[test]
+    #[cfg(feature = "defmt")]
+    fn test_defmt() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<Rgb<u8>>();
+        assert_format::<Rgba<u8>>();
+        assert_format::<Hsv<f32>>();
+        assert_format::<Hsl<f32>>();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &Rgb::new(1u8, 2, 3),
+            &[
+                Token::Struct {
+                    name: "Rgb",
+                    len: 3,
+                },
+                Token::Str("r"),
+                Token::U8(1),
+                Token::Str("g"),
+                Token::U8(2),
+                Token::Str("b"),
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
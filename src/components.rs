@@ -0,0 +1,69 @@
+/// Positional access to the fields of a struct, implemented by `swizzle_dyn!` for structs with `N`
+/// fields of the same type.
+///
+/// The fields are indexed in the order they are declared to the macro. This allows code to be
+/// written once for any struct, e.g. generic reordering of the fields.
+///
+/// ```rust
+/// use swizzle::{Components, swizzle_dyn};
+///
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// fn sum<T: Components<N, Component = f32>, const N: usize>(value: &T) -> f32 {
+///     (0..T::LEN).map(|i| value.get(i)).sum()
+/// }
+///
+/// let v = Vec3::from_fn(|i| i as f32);
+/// assert_eq!(sum(&v), 3.0);
+/// ```
+///
+/// The trait can also be implemented by hand, for types the macro can't be used with, which then
+/// get the runtime swizzle APIs built on it, such as [`SwizzleDyn`](crate::SwizzleDyn).
+///
+/// ```rust
+/// use swizzle::{Components, Permutation};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Pair(u8, u8);
+///
+/// impl Components<2> for Pair {
+///     type Component = u8;
+///
+///     fn get(&self, i: usize) -> u8 {
+///         [self.0, self.1][i]
+///     }
+///
+///     fn from_fn(mut f: impl FnMut(usize) -> u8) -> Self {
+///         Pair(f(0), f(1))
+///     }
+/// }
+///
+/// let swap = Permutation::new([1, 0]).unwrap();
+/// assert_eq!(swap.apply(&Pair(1, 2)), Pair(2, 1));
+/// ```
+pub trait Components<const N: usize>: Sized {
+    /// The type of the fields.
+    type Component: Copy;
+
+    /// The number of fields.
+    const LEN: usize = N;
+
+    /// Returns the value of the field at the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    #[must_use]
+    fn get(&self, i: usize) -> Self::Component;
+
+    /// Create an instance where the value of each field is returned by `f` with its index. `f` is
+    /// called for each field in order.
+    #[must_use]
+    fn from_fn(f: impl FnMut(usize) -> Self::Component) -> Self;
+}
@@ -0,0 +1,126 @@
+/// Macro for generating `const` items holding swizzles of a constant.
+///
+/// Given a constant and its type, then items written as `NAME = pattern`, the macro emits a
+/// `const` for each, initialized with the function generated by `swizzle!` with the name of the
+/// pattern, so tables of reordered constants needn't be written out by hand. The items are of the
+/// same type as the constant unless another type is given, as in `NAME: Vec2 = xy`, for swizzles
+/// to another type, and may have a visibility.
+///
+/// ```rust
+/// use swizzle::{swizzle, swizzle_const};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec2 { x: f32, y: f32 }
+///
+/// impl Vec3 {
+///     swizzle!(Vec3 { x, y, z });
+///     swizzle!(Vec2 { x: (x, y, z), y: (x, y, z) });
+/// }
+///
+/// const BASIS: Vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+///
+/// swizzle_const!(BASIS: Vec3 => X_FIRST = xzy, pub REVERSED = zyx, PLANE: Vec2 = xy);
+///
+/// assert_eq!(X_FIRST, Vec3 { x: 1.0, y: 3.0, z: 2.0 });
+/// assert_eq!(REVERSED, Vec3 { x: 3.0, y: 2.0, z: 1.0 });
+/// assert_eq!(PLANE, Vec2 { x: 1.0, y: 2.0 });
+/// ```
+#[macro_export]
+macro_rules! swizzle_const {
+    (
+        $value:path: $type:ty =>
+        $( $vis:vis $name:ident $( : $item_type:ty )? = $pattern:ident ),+
+        $(,)?
+    ) => {
+        $(
+            $crate::swizzle_const!(@item $vis $name [$( $item_type )?] $type = $pattern($value));
+        )+
+    };
+    (@item $vis:vis $name:ident [] $type:ty = $pattern:ident($value:path)) => {
+        $vis const $name: $type = $value.$pattern();
+    };
+    (@item $vis:vis $name:ident [$item_type:ty] $type:ty = $pattern:ident($value:path)) => {
+        $vis const $name: $item_type = $value.$pattern();
+    };
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use crate::swizzle;
+
+    #[derive(Debug, PartialEq)]
+    struct Rgba {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Rgb {
+        r: u8,
+        g: u8,
+        b: u8,
+    }
+
+    impl Rgba {
+        swizzle!(Rgba { r, g, b, a });
+        swizzle!(Rgb {
+            r: (r, g, b, a),
+            g: (r, g, b, a),
+            b: (r, g, b, a)
+        });
+    }
+
+    mod palette {
+        use super::Rgba;
+
+        pub const ORANGE: Rgba = Rgba {
+            r: 255,
+            g: 128,
+            b: 0,
+            a: 255,
+        };
+    }
+
+    swizzle_const!(
+        palette::ORANGE: Rgba =>
+        ORANGE_BGRA = bgra,
+        ORANGE_ARGB = argb,
+        ORANGE_BGR: Rgb = bgr,
+    );
+
+    #[test]
+    fn test_swizzle_const() {
+        assert_eq!(
+            ORANGE_BGRA,
+            Rgba {
+                r: 0,
+                g: 128,
+                b: 255,
+                a: 255
+            }
+        );
+        assert_eq!(
+            ORANGE_ARGB,
+            Rgba {
+                r: 255,
+                g: 255,
+                b: 128,
+                a: 0
+            }
+        );
+        assert_eq!(
+            ORANGE_BGR,
+            Rgb {
+                r: 0,
+                g: 128,
+                b: 255
+            }
+        );
+    }
+}
@@ -0,0 +1,1925 @@
+/// Macro for generating runtime swizzle functions of structs.
+///
+/// The `swizzle!` macro can only generate swizzles that are known at compile time. The
+/// `swizzle_dyn!` macro generates functions that take the pattern at runtime instead, as the
+/// indices of the fields in the order they are declared to the macro. This is useful when the
+/// order of the components is only known at runtime, e.g. when it is read from a config file.
+///
+/// All fields must be of the same type, which is declared after the struct name. The macro must be
+/// invoked at module level, as it generates its own `impl` block and an axis enum naming the
+/// fields. The optional visibility applies to the axis enum and pattern type.
+///
+/// # Basic Usage
+///
+/// ```rust
+/// use swizzle::swizzle_dyn;
+///
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let v_reverse = v.swizzle_dyn([2, 1, 0]); // Vec3 { x: 3.0, y: 2.0, z: 1.0 }
+/// let v_repeated = v.swizzle_dyn([0, 0, 0]); // Vec3 { x: 1.0, y: 1.0, z: 1.0 }
+/// ```
+///
+/// # Parameters
+///
+/// - `simd`: Also generate `permute_simd`, with the `portable-simd` feature (optional)
+/// - `sse`: Also generate `permute_sse` and `swizzle_sse`, for 4 fields of 32-bit lanes (optional)
+/// - `pod`: Also generate `as_array` and `view` reinterpreting references, with the `bytemuck`
+///   feature (optional)
+/// - `indexed`: Also implement `Index<usize>` and `IndexMut<usize>` (optional)
+/// - `view`: Also generate a reference view for a conversion to a `#[repr(C)]` struct (optional)
+/// - `vis`: The visibility of the generated axis enum (optional)
+/// - `StructName`: The name of the struct to implement the runtime swizzle functions for
+/// - `Type`: The type of all of the fields
+/// - `field1, field2, ...`: The field names, in the order they are indexed
+///
+/// # Generated Functions
+///
+/// - `<StructName>Axis` → enum with a variant for each field, named after the field in camel case,
+///   e.g. `Vec3Axis::X`, with `index()` and `name()` returning the field's index and name, and `ALL`
+///   listing every field. It
+///   implements `FromStr` and `Display` using the field name
+/// - `<StructName>Pattern` → validated swizzle pattern implementing `FromStr` and `Display`, with
+///   `apply(&StructName) -> StructName` to swizzle an instance by it, `to_spirv_indices()` returning
+///   its indices as the components of a SPIR-V `OpVectorShuffle`, `to_shader_swizzle()` returning
+///   it as a WGSL or GLSL swizzle such as `.zyx`, `inverse()` returning the
+///   pattern that undoes it if it is a pure permutation, `is_identity()` returning whether it
+///   leaves every field in place, `sign()` returning its sign, and `rank()`
+///   and `unrank()` converting to and from its index in lexicographic order. `all()` and
+///   `permutations()` iterate over every pattern, or only the pure permutations. Also implements
+///   the `SwizzlePattern` trait
+/// - `component(&self, <StructName>Axis) -> Type` → returns the value of the field
+/// - `set_component(&mut self, <StructName>Axis, Type)` → sets the value of the field
+/// - `to_array(&self) -> [Type; N]` and `from_array([Type; N]) -> Self` → convert to and from an
+///   array of the field values, in the order they are declared to the macro
+/// - `swizzle_dyn(&self, [usize; N]) -> Self` → returns struct with the field at each position set
+///   to the value of the field at the given index. Panics if any index is out of range. The
+///   struct also gets the `SwizzleDyn<N>` trait through its `Components<N>` implementation, so it
+///   can be swizzled by a `Permutation<N>`, and implements the object-safe `DynSwizzle` trait, so it can be swizzled through `dyn DynSwizzle`.
+/// - `permute::<I0, I1, ...>(&self) -> Self` → the same as `swizzle_dyn`, with the indices given as
+///   const generic parameters, so they are checked at compile time
+/// - `try_swizzle(&self, &[usize]) -> Result<Self, SwizzleError>` → checked version of
+///   `swizzle_dyn` for untrusted input. Returns an error if any index is out of range, or if the
+///   number of indices doesn't match the number of fields.
+/// - `unsafe swizzle_dyn_unchecked(&self, [usize; N]) -> Self` → version of `swizzle_dyn` without
+///   bounds checks, for indices that have already been validated. Out of range indices are caught
+///   by debug assertions, and are undefined behavior in release builds.
+/// - `swizzle_str(&self, &str) -> Option<Self>` → returns struct swizzled by a pattern of field
+///   names, e.g. `"zyx"`. Returns `None` if the pattern doesn't name exactly one field per position.
+/// - `Components<N>` → implementation of the positional accessor trait, with `get(&self, usize)`,
+///   `from_fn(impl FnMut(usize) -> Type)`, and `LEN`
+/// - `SwizzleN<StructName>` → implementation of the per-arity trait for structs of up to 4 fields,
+///   e.g. `Swizzle3<Vec3>` for `Vec3`, with `swizzle3(&self, [usize; 3]) -> Vec3`
+/// - `swizzle_by(&self, [<StructName>Axis; N]) -> Self` → returns struct with the field at each
+///   position set to the value of the given field. Unlike `swizzle_dyn` it can't be out of range.
+///
+/// # Indexing
+///
+/// Prefixing the invocation with `indexed` also implements `Index<usize>` and `IndexMut<usize>`,
+/// mapping each index to the field in the order they are declared to the macro, for interop with
+/// code that uses positional access.
+///
+/// ```rust
+/// use swizzle::swizzle_dyn;
+///
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// swizzle_dyn!(indexed Vec3: f32 { x, y, z });
+///
+/// let mut v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// v[2] += v[0];
+/// assert_eq!(v.z, 4.0);
+/// ```
+///
+/// # SSE
+///
+/// Prefixing the invocation of a struct with 4 fields of type `f32`, `i32`, or `u32` with `sse`
+/// also generates `permute_sse::<I0, I1, I2, I3>()` and `swizzle_sse([usize; 4])`, which do the
+/// swizzle with a single SSE shuffle instruction on x86 targets, and fall back to scalar code on
+/// other targets. The `sse` prefix comes after `simd` and before `pod` and `indexed` if they are used.
+///
+/// ```rust
+/// use swizzle::swizzle_dyn;
+///
+/// struct Vec4 { x: f32, y: f32, z: f32, w: f32 }
+///
+/// swizzle_dyn!(sse Vec4: f32 { x, y, z, w });
+///
+/// let v = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+/// let v_reverse = v.permute_sse::<3, 2, 1, 0>(); // Vec4 { x: 4.0, y: 3.0, z: 2.0, w: 1.0 }
+/// let v_splat = v.swizzle_sse([1, 1, 1, 1]); // Vec4 { x: 2.0, y: 2.0, z: 2.0, w: 2.0 }
+/// ```
+///
+/// # Views
+///
+/// With the `bytemuck` feature enabled, prefixing the invocation of a struct implementing
+/// `bytemuck::Pod` with `pod` also generates `as_array()` and `as_array_mut()`, which return the
+/// fields as an array reference without copying, and `view::<V>()` and `view_mut::<V>()`, which
+/// reinterpret the struct as another `Pod` type of the same size, e.g. one with the same fields in
+/// another order. The struct must be `#[repr(C)]` with the fields declared to the macro in the
+/// order they are declared in the struct, which is checked at compile time. The `pod` prefix
+/// comes after `simd` and `sse` and before `indexed` if they are used.
+///
+/// ```rust
+/// # #[cfg(feature = "bytemuck")] {
+/// use bytemuck::{Pod, Zeroable};
+/// use swizzle::swizzle_dyn;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Pod, Zeroable)]
+/// struct Rgba { r: u8, g: u8, b: u8, a: u8 }
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Pod, Zeroable)]
+/// struct Bgra { b: u8, g: u8, r: u8, a: u8 }
+///
+/// swizzle_dyn!(pod Rgba: u8 { r, g, b, a });
+///
+/// let mut pixel = Rgba { r: 10, g: 20, b: 30, a: 255 };
+/// assert_eq!(pixel.as_array(), &[10, 20, 30, 255]);
+/// pixel.as_array_mut()[3] = 128;
+/// assert_eq!(pixel.a, 128);
+///
+/// // Read the bytes of a BGRA pixel through an RGBA struct without copying.
+/// let bgra: &Bgra = pixel.view();
+/// assert_eq!((bgra.b, bgra.r), (10, 30));
+/// # }
+/// ```
+///
+/// # SIMD
+///
+/// With the nightly-only `portable-simd` feature enabled, prefixing the invocation with `simd`
+/// also generates `permute_simd::<I0, I1, ...>()`, which does the same swizzle as `permute` as a
+/// `core::simd` shuffle, so it compiles to shuffle instructions even in debug builds. The field
+/// type must be a SIMD element type, such as `f32` or `u8`, and the invoking crate must enable
+/// `#![feature(portable_simd)]`. The `simd` prefix comes before `indexed` if both are used.
+///
+/// ```rust,ignore
+/// #![feature(portable_simd)]
+///
+/// use swizzle::swizzle_dyn;
+///
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// swizzle_dyn!(simd Vec3: f32 { x, y, z });
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let v_rotated = v.permute_simd::<1, 2, 0>(); // Vec3 { x: 2.0, y: 3.0, z: 1.0 }
+/// ```
+///
+/// # Serde
+///
+/// With the `serde` feature enabled, the axis and pattern types implement `Serialize` and
+/// `Deserialize` using their string forms, e.g. `"zyx"`, so patterns can be read straight from
+/// config files.
+///
+/// # Rand
+///
+/// With the `rand` feature enabled, the axis and pattern types can be sampled uniformly from the
+/// `StandardUniform` distribution, and patterns with `SwizzlePattern::random`.
+///
+/// # Conversions
+///
+/// Swizzles to other types are generated by a second invocation naming the destination type and
+/// its fields, which generates `swizzle_by_<destination>` taking an axis for each destination
+/// field, and implements the per-arity trait for the destination, e.g. `Swizzle2<Vec2>`. The source
+/// type must have been declared to the macro first.
+///
+/// ```rust
+/// use swizzle::swizzle_dyn;
+///
+/// struct Vec2 { x: f32, y: f32 }
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+/// swizzle_dyn!(Vec3 => Vec2 { x, y });
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let v2 = v.swizzle_by_vec2([Vec3Axis::Z, Vec3Axis::X]); // Vec2 { x: 3.0, y: 1.0 }
+/// ```
+///
+/// # Prefix Views
+///
+/// Prefixing a conversion with `view` also generates a `<fields>_view()` method, e.g. `xyz_view()`,
+/// returning a reference to the destination fields inside the source struct without copying, and
+/// a `<fields>_view_mut()` method returning a mutable reference. Both structs should be
+/// `#[repr(C)]`, with the destination fields at the same offsets as in the source, usually its
+/// leading fields, which is checked at compile time.
+///
+/// ```rust
+/// use swizzle::swizzle_dyn;
+///
+/// #[repr(C)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+/// #[repr(C)]
+/// struct Vec4 { x: f32, y: f32, z: f32, w: f32 }
+///
+/// swizzle_dyn!(Vec4: f32 { x, y, z, w });
+/// swizzle_dyn!(view Vec4 => Vec3 { x, y, z });
+///
+/// let mut v = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 };
+/// let position: &Vec3 = v.xyz_view();
+/// assert_eq!(position.z, 3.0);
+/// v.xyz_view_mut().x = 5.0;
+/// assert_eq!(v.x, 5.0);
+/// ```
+#[macro_export]
+macro_rules! swizzle_dyn {
+    (@arity $type:ident => $dst_type:ident { $( $dst_attr:ident ),+ }) => {
+        $crate::swizzle_dyn!(@arity [ $( $dst_attr )+ ] $type => $dst_type { $( $dst_attr ),+ });
+    };
+    (@arity [ $a:ident ] $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@arity_impl Swizzle1 swizzle1 $( $rest )+);
+    };
+    (@arity [ $a:ident $b:ident ] $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@arity_impl Swizzle2 swizzle2 $( $rest )+);
+    };
+    (@arity [ $a:ident $b:ident $c:ident ] $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@arity_impl Swizzle3 swizzle3 $( $rest )+);
+    };
+    (@arity [ $a:ident $b:ident $c:ident $d:ident ] $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@arity_impl Swizzle4 swizzle4 $( $rest )+);
+    };
+    (@arity [ $( $other:ident )+ ] $( $rest:tt )+) => {};
+    (
+        @arity_impl $trait:ident $method:ident
+        $type:ident => $dst_type:ident { $( $dst_attr:ident ),+ }
+    ) => { $crate::__private::paste! {
+        impl $crate::$trait<$dst_type> for $type {
+            #[inline]
+            fn $method(&self, idx: [usize; [$( stringify!($dst_attr) ),+].len()]) -> $dst_type {
+                let [ $( $dst_attr ),+ ] = idx;
+                $dst_type { $( $dst_attr: self.component([<$type Axis>]::ALL[$dst_attr]) ),+ }
+            }
+        }
+    }};
+    (@serde $name:ident) => {
+        $crate::__swizzle_serde! {
+            impl $crate::__private::serde::Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: $crate::__private::serde::Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: $crate::__private::serde::Deserializer<'de>,
+                {
+                    $crate::__private::deserialize_from_str(deserializer)
+                }
+            }
+        }
+    };
+    (simd $( $rest:tt )+) => {
+        $crate::swizzle_dyn!($( $rest )+);
+        $crate::swizzle_dyn!(@simd $( $rest )+);
+    };
+    (@simd sse $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@simd $( $rest )+);
+    };
+    (@simd pod $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@simd $( $rest )+);
+    };
+    (@simd indexed $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@simd $( $rest )+);
+    };
+    (sse $( $rest:tt )+) => {
+        $crate::swizzle_dyn!($( $rest )+);
+        $crate::swizzle_dyn!(@sse $( $rest )+);
+    };
+    (@sse pod $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@sse $( $rest )+);
+    };
+    (@sse indexed $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@sse $( $rest )+);
+    };
+    (
+        @sse $vis:vis $type:ident: $component:ty {
+            $a:ident, $b:ident, $c:ident, $d:ident
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        impl $type {
+            /// The same as `permute`, with the swizzle done by an SSE shuffle on x86, and a scalar
+            /// fallback elsewhere. Out of range indices fail to compile.
+            #[must_use]
+            #[inline]
+            pub fn permute_sse<
+                const [<I_ $a:upper>]: usize,
+                const [<I_ $b:upper>]: usize,
+                const [<I_ $c:upper>]: usize,
+                const [<I_ $d:upper>]: usize,
+            >(&self) -> Self {
+                const {
+                    assert!([<I_ $a:upper>] < 4, "swizzle index out of range");
+                    assert!([<I_ $b:upper>] < 4, "swizzle index out of range");
+                    assert!([<I_ $c:upper>] < 4, "swizzle index out of range");
+                    assert!([<I_ $d:upper>] < 4, "swizzle index out of range");
+                }
+                self.swizzle_sse([ [<I_ $a:upper>], [<I_ $b:upper>], [<I_ $c:upper>], [<I_ $d:upper>] ])
+            }
+
+            /// The same as `swizzle_dyn`, with the swizzle done by an SSE shuffle on x86, and a
+            /// scalar fallback elsewhere.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the indices is out of range.
+            #[must_use]
+            #[inline]
+            pub fn swizzle_sse(&self, idx: [usize; 4]) -> Self {
+                Self::from_array(<$component as $crate::__private::Shuffle4>::shuffle4(self.to_array(), idx))
+            }
+        }
+    }};
+    (@sse $( $rest:tt )+) => {
+        compile_error!("the `sse` prefix of `swizzle_dyn!` requires exactly 4 fields");
+    };
+    (
+        @simd $vis:vis $type:ident: $component:ty {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        $crate::__swizzle_simd! {
+            #[doc(hidden)]
+            struct [<$type SimdPermute>]<$( const [<I_ $attr:upper>]: usize ),+>;
+
+            impl<$( const [<I_ $attr:upper>]: usize ),+> ::core::simd::Swizzle<{ [$( stringify!($attr) ),+].len() }>
+                for [<$type SimdPermute>]<$( [<I_ $attr:upper>] ),+>
+            {
+                const INDEX: [usize; [$( stringify!($attr) ),+].len()] = [ $( [<I_ $attr:upper>] ),+ ];
+            }
+
+            impl $type {
+                /// The same as `permute`, with the swizzle done as a SIMD shuffle, which is
+                /// guaranteed even in debug builds. Out of range indices fail to compile.
+                #[must_use]
+                #[inline]
+                pub fn permute_simd<$( const [<I_ $attr:upper>]: usize ),+>(&self) -> Self {
+                    let values = ::core::simd::Simd::from_array(self.to_array());
+                    let values = <[<$type SimdPermute>]<$( [<I_ $attr:upper>] ),+> as ::core::simd::Swizzle<
+                        { [$( stringify!($attr) ),+].len() },
+                    >>::swizzle(values);
+                    Self::from_array(values.to_array())
+                }
+            }
+        }
+    }};
+    (pod $( $rest:tt )+) => {
+        $crate::swizzle_dyn!($( $rest )+);
+        $crate::swizzle_dyn!(@pod $( $rest )+);
+    };
+    (@pod indexed $( $rest:tt )+) => {
+        $crate::swizzle_dyn!(@pod $( $rest )+);
+    };
+    (
+        @pod $vis:vis $type:ident: $component:ty {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        $crate::__swizzle_bytemuck! {
+            const _: () = {
+                $(
+                    assert!(
+                        ::core::mem::offset_of!($type, $attr)
+                            == [<$type Axis>]::[<$attr:camel>].index() * ::core::mem::size_of::<$component>(),
+                        "the fields of a `pod` struct must be laid out in the order they are declared to `swizzle_dyn!`"
+                    );
+                )+
+                assert!(
+                    ::core::mem::size_of::<$type>()
+                        == [$( stringify!($attr) ),+].len() * ::core::mem::size_of::<$component>(),
+                    "a `pod` struct must have no fields other than those declared to `swizzle_dyn!`"
+                );
+            };
+
+            impl $type {
+                /// Returns the fields as an array reference, in the order they are declared to the
+                /// macro, without copying.
+                #[must_use]
+                #[inline]
+                pub fn as_array(&self) -> &[$component; [$( stringify!($attr) ),+].len()] {
+                    $crate::__private::bytemuck::cast_ref(self)
+                }
+
+                /// Returns the fields as a mutable array reference, in the order they are declared
+                /// to the macro, without copying.
+                #[must_use]
+                #[inline]
+                pub fn as_array_mut(&mut self) -> &mut [$component; [$( stringify!($attr) ),+].len()] {
+                    $crate::__private::bytemuck::cast_mut(self)
+                }
+
+                /// Reinterprets the struct as another `Pod` type of the same size, without
+                /// copying, e.g. a struct with the same fields in another order. A different size
+                /// or a larger alignment fails to compile.
+                #[must_use]
+                #[inline]
+                pub fn view<V: $crate::__private::bytemuck::Pod>(&self) -> &V {
+                    const {
+                        assert!(::core::mem::size_of::<V>() == ::core::mem::size_of::<Self>(), "view size mismatch");
+                        assert!(::core::mem::align_of::<V>() <= ::core::mem::align_of::<Self>(), "view alignment mismatch");
+                    }
+                    $crate::__private::bytemuck::cast_ref(self)
+                }
+
+                /// Reinterprets the struct as another mutable `Pod` type of the same size, without
+                /// copying. A different size or a larger alignment fails to compile.
+                #[must_use]
+                #[inline]
+                pub fn view_mut<V: $crate::__private::bytemuck::Pod>(&mut self) -> &mut V {
+                    const {
+                        assert!(::core::mem::size_of::<V>() == ::core::mem::size_of::<Self>(), "view size mismatch");
+                        assert!(::core::mem::align_of::<V>() <= ::core::mem::align_of::<Self>(), "view alignment mismatch");
+                    }
+                    $crate::__private::bytemuck::cast_mut(self)
+                }
+            }
+        }
+    }};
+    (
+        indexed $vis:vis $type:ident: $component:ty {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        $crate::swizzle_dyn!($vis $type: $component { $( $attr ),+ });
+
+        impl ::core::ops::Index<usize> for $type {
+            type Output = $component;
+
+            #[inline]
+            fn index(&self, i: usize) -> &$component {
+                match [<$type Axis>]::ALL.get(i) {
+                    $( ::core::option::Option::Some([<$type Axis>]::[<$attr:camel>]) => &self.$attr, )+
+                    ::core::option::Option::None => panic!("swizzle index out of range"),
+                }
+            }
+        }
+
+        impl ::core::ops::IndexMut<usize> for $type {
+            #[inline]
+            fn index_mut(&mut self, i: usize) -> &mut $component {
+                match [<$type Axis>]::ALL.get(i) {
+                    $( ::core::option::Option::Some([<$type Axis>]::[<$attr:camel>]) => &mut self.$attr, )+
+                    ::core::option::Option::None => panic!("swizzle index out of range"),
+                }
+            }
+        }
+    }};
+    (
+        view $type:ident => $dst_type:ident {
+            $( $dst_attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        $crate::swizzle_dyn!($type => $dst_type { $( $dst_attr ),+ });
+
+        const _: () = {
+            type Component = <$type as $crate::Components<{ [<$type Axis>]::ALL.len() }>>::Component;
+
+            // The fields of the view must be of the component type, which fails to compile otherwise.
+            #[allow(dead_code)]
+            fn fields(view: &$dst_type) -> [Component; [$( stringify!($dst_attr) ),+].len()] {
+                [ $( view.$dst_attr ),+ ]
+            }
+
+            $(
+                assert!(
+                    ::core::mem::offset_of!($type, $dst_attr) == ::core::mem::offset_of!($dst_type, $dst_attr),
+                    "the fields of a view must be at the same offsets as in the viewed struct"
+                );
+            )+
+            assert!(
+                ::core::mem::size_of::<$dst_type>()
+                    == [$( stringify!($dst_attr) ),+].len() * ::core::mem::size_of::<Component>(),
+                "a view must have no fields other than those declared to `swizzle_dyn!`"
+            );
+            assert!(
+                ::core::mem::align_of::<$dst_type>() <= ::core::mem::align_of::<$type>(),
+                "a view must not be more aligned than the viewed struct"
+            );
+        };
+
+        impl $type {
+            #[doc = "Returns a reference to the fields as a `" $dst_type "`, without copying."]
+            #[must_use]
+            #[inline]
+            pub const fn [<$( $dst_attr )+ _view>](&self) -> &$dst_type {
+                // SAFETY: the fields of the view are checked at compile time to be of the component
+                // type and at the same offsets as in the struct, with nothing else in the view.
+                unsafe { &*(self as *const Self).cast::<$dst_type>() }
+            }
+
+            #[doc = "Returns a mutable reference to the fields as a `" $dst_type "`, without copying."]
+            #[must_use]
+            #[inline]
+            pub const fn [<$( $dst_attr )+ _view_mut>](&mut self) -> &mut $dst_type {
+                // SAFETY: as above.
+                unsafe { &mut *(self as *mut Self).cast::<$dst_type>() }
+            }
+        }
+    }};
+    (
+        $type:ident => $dst_type:ident {
+            $( $dst_attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        impl $type {
+            #[doc = "Create an instance of `" $dst_type "` with each field set to the value of the given field."]
+            #[must_use]
+            #[inline]
+            pub const fn [<swizzle_by_ $dst_type:snake>](&self, order: [[<$type Axis>]; [$( stringify!($dst_attr) ),+].len()]) -> $dst_type {
+                let [ $( $dst_attr ),+ ] = order;
+                $dst_type { $( $dst_attr: self.component($dst_attr) ),+ }
+            }
+        }
+
+        $crate::swizzle_dyn!(@arity $type => $dst_type { $( $dst_attr ),+ });
+    }};
+    (
+        $vis:vis $type:ident: $component:ty {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        #[doc = "The fields of `" $type "`, for use with runtime swizzles."]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $vis enum [<$type Axis>] {
+            $(
+                #[doc = "The `" $attr "` field."]
+                [<$attr:camel>],
+            )+
+        }
+
+        impl [<$type Axis>] {
+            /// All of the fields, in the order they are declared to the macro.
+            pub const ALL: [Self; [$( stringify!($attr) ),+].len()] = [ $( Self::[<$attr:camel>] ),+ ];
+
+            /// The index of the field, in the order the fields are declared to the macro.
+            #[must_use]
+            #[inline]
+            pub const fn index(self) -> usize {
+                self as usize
+            }
+
+            /// The name of the field.
+            #[must_use]
+            #[inline]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $( Self::[<$attr:camel>] => stringify!($attr), )+
+                }
+            }
+
+            /// Splits the longest name of a field of at most `max` bytes off the start of the
+            /// string, so that a field whose name starts with the name of another is still found.
+            const fn split_prefix(s: &[u8], max: usize) -> ::core::option::Option<(Self, &[u8])> {
+                let mut longest: ::core::option::Option<(Self, &[u8])> = ::core::option::Option::None;
+                let mut i = 0;
+                while i < Self::ALL.len() {
+                    let name = Self::ALL[i].name().as_bytes();
+                    let longer = match longest {
+                        ::core::option::Option::Some((axis, _)) => name.len() > axis.name().len(),
+                        ::core::option::Option::None => true,
+                    };
+                    if longer && name.len() <= max {
+                        if let ::core::option::Option::Some(rest) = $crate::__private::strip_prefix(s, name) {
+                            longest = ::core::option::Option::Some((Self::ALL[i], rest));
+                        }
+                    }
+                    i += 1;
+                }
+                longest
+            }
+        }
+
+        impl ::core::str::FromStr for [<$type Axis>] {
+            type Err = $crate::SwizzleError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match [<$type Axis>]::split_prefix(s.as_bytes(), s.len()) {
+                    ::core::option::Option::Some((axis, [])) => ::core::result::Result::Ok(axis),
+                    _ => ::core::result::Result::Err($crate::SwizzleError::InvalidAxis),
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for [<$type Axis>] {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        #[doc = "A validated swizzle pattern for `" $type "`, parsed from a string of field names such as `\"" $($attr)+ "\"`."]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct [<$type Pattern>]([[<$type Axis>]; [$( stringify!($attr) ),+].len()]);
+
+        impl [<$type Pattern>] {
+            /// Create a pattern taking the value at each position from the given field.
+            #[must_use]
+            #[inline]
+            pub const fn new(order: [[<$type Axis>]; [$( stringify!($attr) ),+].len()]) -> Self {
+                Self(order)
+            }
+
+            /// Parse a pattern from a string of field names, in const contexts. The same as the
+            /// `FromStr` implementation.
+            ///
+            /// # Errors
+            ///
+            /// Returns `SwizzleError::InvalidPattern` if the string doesn't name exactly one field
+            /// for each position.
+            pub const fn parse(s: &str) -> ::core::result::Result<Self, $crate::SwizzleError> {
+                let mut order = [<$type Axis>]::ALL;
+                if Self::parse_axes(s.as_bytes(), &mut order, 0) {
+                    ::core::result::Result::Ok(Self(order))
+                } else {
+                    ::core::result::Result::Err($crate::SwizzleError::InvalidPattern)
+                }
+            }
+
+            /// Parse the fields from position `i` on, trying the longest name that starts the
+            /// string first, and backtracking to shorter names if the rest can't be parsed, e.g.
+            /// `"xyz"` as `x` and `yz` for the fields `x`, `xy`, and `yz`. Where several splits
+            /// name the right number of fields, the one with the longest names first is chosen.
+            const fn parse_axes(
+                s: &[u8],
+                order: &mut [[<$type Axis>]; [$( stringify!($attr) ),+].len()],
+                i: usize,
+            ) -> bool {
+                if i == order.len() {
+                    return s.is_empty();
+                }
+                let mut max = s.len();
+                while let ::core::option::Option::Some((axis, rest)) = [<$type Axis>]::split_prefix(s, max) {
+                    if Self::parse_axes(rest, order, i + 1) {
+                        order[i] = axis;
+                        return true;
+                    }
+                    max = axis.name().len() - 1;
+                }
+                false
+            }
+
+            /// The field that the value at each position is taken from.
+            #[must_use]
+            #[inline]
+            pub const fn axes(&self) -> [[<$type Axis>]; [$( stringify!($attr) ),+].len()] {
+                self.0
+            }
+
+            /// The indices of the pattern as the literal components of a SPIR-V `OpVectorShuffle`
+            /// of a vector of the fields, in the order they are declared to the macro, with the
+            /// same vector passed as both operands.
+            #[must_use]
+            pub const fn to_spirv_indices(self) -> [u32; [$( stringify!($attr) ),+].len()] {
+                let mut indices = [0; [$( stringify!($attr) ),+].len()];
+                let mut i = 0;
+                while i < indices.len() {
+                    indices[i] = self.0[i].index() as u32;
+                    i += 1;
+                }
+                indices
+            }
+
+            /// The pattern as a swizzle of a shader vector of the fields, in the order they are
+            /// declared to the macro, to spell it identically in WGSL or GLSL source. Returns
+            /// `None` if there are more than 4 fields.
+            #[must_use]
+            pub const fn to_shader_swizzle(self) -> ::core::option::Option<$crate::shader::ShaderSwizzle<{ [$( stringify!($attr) ),+].len() }>> {
+                let mut indices = [0; [$( stringify!($attr) ),+].len()];
+                let mut i = 0;
+                while i < indices.len() {
+                    indices[i] = self.0[i].index();
+                    i += 1;
+                }
+                $crate::shader::ShaderSwizzle::new(indices)
+            }
+
+            /// The number of distinct patterns, `n^n` for `n` fields, or `None` if it doesn't fit
+            /// in a `usize`, from 16 fields on 64-bit targets.
+            pub const COUNT: ::core::option::Option<usize> = {
+                let len: usize = [$( stringify!($attr) ),+].len();
+                len.checked_pow(len as u32)
+            };
+
+            /// The index of the pattern in lexicographic order, from `0` to `COUNT - 1`, or `None`
+            /// if it doesn't fit in a `usize`. The first position is the most significant, so the
+            /// index is the same as the index of the pattern's function in `SWIZZLE_FNS` of a
+            /// `table` self-swizzle declaring the fields in the same order.
+            #[must_use]
+            pub const fn rank(&self) -> ::core::option::Option<usize> {
+                let mut rank: usize = 0;
+                let mut i = 0;
+                while i < self.0.len() {
+                    rank = match rank.checked_mul(self.0.len()) {
+                        ::core::option::Option::Some(rank) => match rank.checked_add(self.0[i].index()) {
+                            ::core::option::Option::Some(rank) => rank,
+                            ::core::option::Option::None => return ::core::option::Option::None,
+                        },
+                        ::core::option::Option::None => return ::core::option::Option::None,
+                    };
+                    i += 1;
+                }
+                ::core::option::Option::Some(rank)
+            }
+
+            /// The pattern with the given index in lexicographic order, or `None` if the index is
+            /// not less than `COUNT`. The inverse of `rank`.
+            #[must_use]
+            pub const fn unrank(rank: usize) -> ::core::option::Option<Self> {
+                if let ::core::option::Option::Some(count) = Self::COUNT {
+                    if rank >= count {
+                        return ::core::option::Option::None;
+                    }
+                }
+                let mut order = [<$type Axis>]::ALL;
+                let mut rest = rank;
+                let mut i = order.len();
+                while i > 0 {
+                    i -= 1;
+                    order[i] = [<$type Axis>]::ALL[rest % order.len()];
+                    rest /= order.len();
+                }
+                ::core::option::Option::Some(Self(order))
+            }
+
+            /// Iterate over every pattern, in lexicographic order.
+            #[must_use]
+            pub const fn all() -> $crate::PermutationIter<Self> {
+                $crate::PermutationIter::all()
+            }
+
+            /// Iterate over the patterns that are pure permutations, in lexicographic order.
+            #[must_use]
+            pub const fn permutations() -> $crate::PermutationIter<Self> {
+                $crate::PermutationIter::permutations()
+            }
+
+            /// The pattern equivalent to applying this one followed by `next`.
+            #[must_use]
+            pub const fn then(&self, next: Self) -> Self {
+                let mut order = [<$type Axis>]::ALL;
+                let mut i = 0;
+                while i < order.len() {
+                    order[i] = self.0[next.0[i].index()];
+                    i += 1;
+                }
+                Self(order)
+            }
+
+            /// Whether the pattern leaves every field in place.
+            #[must_use]
+            pub const fn is_identity(&self) -> bool {
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i].index() != i {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            /// The sign of the pattern: `1` if it is an even permutation, `-1` if it is an odd
+            /// permutation, and `0` if it isn't a pure permutation.
+            #[must_use]
+            pub const fn sign(&self) -> i8 {
+                let mut sign = 1;
+                let mut i = 0;
+                while i < self.0.len() {
+                    let mut j = i + 1;
+                    while j < self.0.len() {
+                        let (a, b) = (self.0[i].index(), self.0[j].index());
+                        if a == b {
+                            return 0;
+                        } else if a > b {
+                            sign = -sign;
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                sign
+            }
+
+            /// The pattern that undoes this one, or `None` if it isn't a pure permutation.
+            #[must_use]
+            pub const fn inverse(&self) -> ::core::option::Option<Self> {
+                let mut order = [<$type Axis>]::ALL;
+                let mut seen = [false; [$( stringify!($attr) ),+].len()];
+                let mut i = 0;
+                while i < order.len() {
+                    let index = self.0[i].index();
+                    if seen[index] {
+                        return ::core::option::Option::None;
+                    }
+                    seen[index] = true;
+                    order[index] = [<$type Axis>]::ALL[i];
+                    i += 1;
+                }
+                ::core::option::Option::Some(Self(order))
+            }
+
+            #[doc = "Create an instance of `" $type "` with the values swizzled according to the pattern."]
+            #[must_use]
+            #[inline]
+            pub const fn apply(&self, value: &$type) -> $type {
+                value.swizzle_by(self.0)
+            }
+        }
+
+        impl ::core::str::FromStr for [<$type Pattern>] {
+            type Err = $crate::SwizzleError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl ::core::fmt::Display for [<$type Pattern>] {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                for axis in self.0 {
+                    f.write_str(axis.name())?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl $crate::SwizzlePattern for [<$type Pattern>] {
+            const COUNT: ::core::option::Option<usize> = Self::COUNT;
+
+            #[inline]
+            fn rank(&self) -> ::core::option::Option<usize> {
+                Self::rank(self)
+            }
+
+            #[inline]
+            fn unrank(rank: usize) -> ::core::option::Option<Self> {
+                Self::unrank(rank)
+            }
+
+            #[inline]
+            fn sign(&self) -> i8 {
+                Self::sign(self)
+            }
+
+            $crate::__swizzle_rand! {
+                fn random<R: $crate::__private::rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                    // Each axis independently, as the rank may not fit in a `usize`.
+                    Self(::core::array::from_fn(|_| rng.random()))
+                }
+            }
+        }
+
+        $crate::__swizzle_rand! {
+            impl $crate::__private::rand::distr::Distribution<[<$type Axis>]>
+                for $crate::__private::rand::distr::StandardUniform
+            {
+                fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> [<$type Axis>] {
+                    [<$type Axis>]::ALL[rng.random_range(0..[<$type Axis>]::ALL.len())]
+                }
+            }
+
+            impl $crate::__private::rand::distr::Distribution<[<$type Pattern>]>
+                for $crate::__private::rand::distr::StandardUniform
+            {
+                fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> [<$type Pattern>] {
+                    <[<$type Pattern>] as $crate::SwizzlePattern>::random(rng)
+                }
+            }
+        }
+
+        impl $crate::DynSwizzle for $type {
+            #[inline]
+            fn swizzle_arity(&self) -> usize {
+                [$( stringify!($attr) ),+].len()
+            }
+
+            #[inline]
+            fn try_swizzle_in_place(&mut self, idx: &[usize]) -> ::core::result::Result<(), $crate::SwizzleError> {
+                $crate::algo::try_swizzle_in_place(self, idx)
+            }
+        }
+
+        impl ::core::convert::From<[<$type Pattern>]> for $crate::SwizzlePlan<{ [$( stringify!($attr) ),+].len() }> {
+            fn from(pattern: [<$type Pattern>]) -> Self {
+                match $crate::SwizzlePlan::new(pattern.axes().map(|axis| axis.index())) {
+                    ::core::result::Result::Ok(plan) => plan,
+                    ::core::result::Result::Err(_) => unreachable!("every axis index is in range"),
+                }
+            }
+        }
+
+        impl $crate::Components<{ [$( stringify!($attr) ),+].len() }> for $type {
+            type Component = $component;
+
+            #[inline]
+            fn get(&self, i: usize) -> $component {
+                self.component([<$type Axis>]::ALL[i])
+            }
+
+            #[inline]
+            fn from_fn(mut f: impl FnMut(usize) -> $component) -> Self {
+                $( let $attr = f([<$type Axis>]::[<$attr:camel>].index()); )+
+                Self { $( $attr ),+ }
+            }
+        }
+
+        $crate::swizzle_dyn!(@arity $type => $type { $( $attr ),+ });
+        $crate::swizzle_dyn!(@serde [<$type Axis>]);
+        $crate::swizzle_dyn!(@serde [<$type Pattern>]);
+
+        impl $type {
+            /// Returns the value of the given field.
+            #[must_use]
+            #[inline]
+            pub const fn component(&self, axis: [<$type Axis>]) -> $component {
+                match axis {
+                    $( [<$type Axis>]::[<$attr:camel>] => self.$attr, )+
+                }
+            }
+
+            /// Sets the value of the given field.
+            #[inline]
+            pub const fn set_component(&mut self, axis: [<$type Axis>], value: $component) {
+                match axis {
+                    $( [<$type Axis>]::[<$attr:camel>] => self.$attr = value, )+
+                }
+            }
+
+            /// Returns the values of the fields as an array, in the order they are declared to the
+            /// macro.
+            #[must_use]
+            #[inline]
+            pub const fn to_array(&self) -> [$component; [$( stringify!($attr) ),+].len()] {
+                [ $( self.$attr ),+ ]
+            }
+
+            /// Create an instance from an array of values, in the order the fields are declared
+            /// to the macro.
+            #[must_use]
+            #[inline]
+            pub const fn from_array(array: [$component; [$( stringify!($attr) ),+].len()]) -> Self {
+                let [ $( $attr ),+ ] = array;
+                Self { $( $attr ),+ }
+            }
+
+            /// Create an instance with the values swizzled according to a pattern of field names,
+            /// such as the name of a generated swizzle function. Returns `None` if the pattern
+            /// doesn't name exactly one field for each position.
+            #[must_use]
+            pub fn swizzle_str(&self, pattern: &str) -> ::core::option::Option<Self> {
+                match pattern.parse::<[<$type Pattern>]>() {
+                    ::core::result::Result::Ok(pattern) => ::core::option::Option::Some(pattern.apply(self)),
+                    ::core::result::Result::Err(_) => ::core::option::Option::None,
+                }
+            }
+
+            /// Create an instance with the values swizzled according to the given fields, where the
+            /// value at each position is taken from the field.
+            #[must_use]
+            #[inline]
+            pub const fn swizzle_by(&self, order: [[<$type Axis>]; [$( stringify!($attr) ),+].len()]) -> Self {
+                let [ $( $attr ),+ ] = order;
+                Self { $( $attr: self.component($attr) ),+ }
+            }
+
+            /// Create an instance with the values swizzled according to the field indices given as
+            /// const generic parameters, where the value at each position is taken from the field
+            /// at the index. Out of range indices fail to compile.
+            #[must_use]
+            #[inline]
+            pub const fn permute<$( const [<I_ $attr:upper>]: usize ),+>(&self) -> Self {
+                const {
+                    let len = [$( stringify!($attr) ),+].len();
+                    $( assert!([<I_ $attr:upper>] < len, "swizzle index out of range"); )+
+                }
+                self.swizzle_dyn([ $( [<I_ $attr:upper>] ),+ ])
+            }
+
+            /// Create an instance with the values swizzled according to the given field indices,
+            /// where the value at each position is taken from the field at the index.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the indices is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn swizzle_dyn(&self, idx: [usize; [$( stringify!($attr) ),+].len()]) -> Self {
+                let values: [$component; [$( stringify!($attr) ),+].len()] = [ $( self.$attr ),+ ];
+                let [ $( $attr ),+ ] = idx;
+                Self { $( $attr: values[$attr] ),+ }
+            }
+
+            /// Create an instance with the values swizzled according to the given field indices,
+            /// without checking that the indices are in range.
+            ///
+            /// # Safety
+            ///
+            /// All of the indices must be less than the number of fields. This is checked by debug
+            /// assertions, but calling it with an out of range index in a release build is
+            /// undefined behavior.
+            #[must_use]
+            #[inline]
+            pub const unsafe fn swizzle_dyn_unchecked(&self, idx: [usize; [$( stringify!($attr) ),+].len()]) -> Self {
+                let values: [$component; [$( stringify!($attr) ),+].len()] = [ $( self.$attr ),+ ];
+                let [ $( $attr ),+ ] = idx;
+                $(
+                    debug_assert!($attr < values.len(), "swizzle index out of range");
+                )+
+                // SAFETY: the caller guarantees that all of the indices are in range.
+                unsafe { Self { $( $attr: *values.as_ptr().add($attr) ),+ } }
+            }
+
+            /// Create an instance with the values swizzled according to the given field indices,
+            /// checking that there is exactly one index per field and that each is in range.
+            ///
+            /// # Errors
+            ///
+            /// Returns `SwizzleError::WrongArity` if the number of indices doesn't match the
+            /// number of fields, or `SwizzleError::IndexOutOfRange` if any index is out of range.
+            #[inline]
+            pub const fn try_swizzle(&self, idx: &[usize]) -> ::core::result::Result<Self, $crate::SwizzleError> {
+                const LEN: usize = [$( stringify!($attr) ),+].len();
+                let &[ $( $attr ),+ ] = idx else {
+                    return ::core::result::Result::Err($crate::SwizzleError::WrongArity {
+                        expected: LEN,
+                        found: idx.len(),
+                    });
+                };
+                $(
+                    if $attr >= LEN {
+                        return ::core::result::Result::Err($crate::SwizzleError::IndexOutOfRange {
+                            index: $attr,
+                            len: LEN,
+                        });
+                    }
+                )+
+                ::core::result::Result::Ok(self.swizzle_dyn([ $( $attr ),+ ]))
+            }
+        }
+    }};
+}
+
+/// Macro for composing swizzle patterns at compile time.
+///
+/// Takes the pattern type generated by `swizzle_dyn!` and two or more patterns, written as the
+/// names of the swizzle functions, and expands to the single pattern equivalent to applying them in
+/// order. The composition is evaluated in a const block, so invalid patterns fail to compile. Given
+/// a value before the pattern type, it expands to the single swizzle of the value instead.
+///
+/// ```rust
+/// use swizzle::{swizzle_compose, swizzle_dyn};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// const ROTATE_TWICE: Vec3Pattern = swizzle_compose!(Vec3Pattern: yzx, yzx);
+/// assert_eq!(ROTATE_TWICE.to_string(), "zxy");
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let v_swapped = swizzle_compose!(v, Vec3Pattern: yzx, yxz); // Vec3 { x: 3.0, y: 2.0, z: 1.0 }
+/// assert_eq!(v_swapped, Vec3 { x: 3.0, y: 2.0, z: 1.0 });
+/// ```
+#[macro_export]
+macro_rules! swizzle_compose {
+    ( $pattern:ident: $first:ident $( , $rest:ident )+ $(,)? ) => {
+        const {
+            let pattern = $crate::swizzle_compose!(@parse $pattern $first);
+            $( let pattern = pattern.then($crate::swizzle_compose!(@parse $pattern $rest)); )+
+            pattern
+        }
+    };
+    ( $value:expr, $pattern:ident: $( $patterns:ident ),+ $(,)? ) => {
+        $crate::swizzle_compose!($pattern: $( $patterns ),+).apply(&$value)
+    };
+    ( @parse $pattern:ident $name:ident ) => {
+        match $pattern::parse(stringify!($name)) {
+            ::core::result::Result::Ok(pattern) => pattern,
+            ::core::result::Result::Err(_) => {
+                panic!(concat!("swizzle_compose!: invalid pattern `", stringify!($name), "`"))
+            }
+        }
+    };
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use crate::{SwizzleError, swizzle};
+
+    #[test]
+    fn test_swizzle_dyn() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.swizzle_dyn([0, 1, 2]), TestStruct { a: 1, b: 2, c: 3 });
+        assert_eq!(s.swizzle_dyn([2, 1, 0]), TestStruct { a: 3, b: 2, c: 1 });
+        assert_eq!(s.swizzle_dyn([1, 1, 1]), TestStruct { a: 2, b: 2, c: 2 });
+        assert_eq!(s.swizzle_dyn([2, 0, 2]), TestStruct { a: 3, b: 1, c: 3 });
+    }
+
+    #[test]
+    fn test_swizzle_dyn_axis() {
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.component(TestStructAxis::A), 1);
+        assert_eq!(s.component(TestStructAxis::B), 2);
+        assert_eq!(s.component(TestStructAxis::C), 3);
+
+        let mut s = s;
+        s.set_component(TestStructAxis::B, 5);
+        assert_eq!((s.a, s.b, s.c), (1, 5, 3));
+        s.set_component(TestStructAxis::C, 6);
+        assert_eq!((s.a, s.b, s.c), (1, 5, 6));
+
+        assert_eq!(TestStructAxis::A.index(), 0);
+        assert_eq!(TestStructAxis::B.index(), 1);
+        assert_eq!(TestStructAxis::C.index(), 2);
+    }
+
+    #[test]
+    fn test_swizzle_str() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.swizzle_str("cba"), Some(TestStruct { a: 3, b: 2, c: 1 }));
+        assert_eq!(s.swizzle_str("aac"), Some(s.swizzle_dyn([0, 0, 2])));
+        assert_eq!(s.swizzle_str(""), None);
+        assert_eq!(s.swizzle_str("ab"), None);
+        assert_eq!(s.swizzle_str("abca"), None);
+        assert_eq!(s.swizzle_str("abd"), None);
+        assert_eq!(s.swizzle_str("ABC"), None);
+    }
+
+    #[test]
+    fn test_swizzle_str_multi_character_fields() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            lo: u8,
+            hi: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { lo, hi });
+
+        let s = TestStruct { lo: 1, hi: 2 };
+
+        assert_eq!(s.swizzle_str("hilo"), Some(TestStruct { lo: 2, hi: 1 }));
+        assert_eq!(s.swizzle_str("hihi"), Some(TestStruct { lo: 2, hi: 2 }));
+        assert_eq!(s.swizzle_str("hil"), None);
+    }
+
+    #[test]
+    fn test_swizzle_pattern() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        use TestStructAxis::{A, B, C};
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        let pattern: TestStructPattern = "cab".parse().unwrap();
+        assert_eq!(pattern, TestStructPattern::new([C, A, B]));
+        assert_eq!(pattern.axes(), [C, A, B]);
+        assert_eq!(pattern.to_string(), "cab");
+        assert_eq!(pattern.apply(&s), TestStruct { a: 3, b: 1, c: 2 });
+
+        assert_eq!(TestStructPattern::new([A, A, C]).to_string(), "aac");
+
+        const SHUFFLE: [u32; 3] = TestStructPattern::new([C, A, C]).to_spirv_indices();
+        assert_eq!(SHUFFLE, [2, 0, 2]);
+        assert_eq!(
+            TestStructPattern::new([C, A, C])
+                .to_shader_swizzle()
+                .unwrap()
+                .to_string(),
+            ".zxz"
+        );
+
+        assert_eq!(TestStructAxis::ALL, [A, B, C]);
+        assert_eq!(pattern.inverse(), Some(TestStructPattern::new([B, C, A])));
+        assert_eq!(pattern.inverse().unwrap().apply(&pattern.apply(&s)), s);
+        assert_eq!(TestStructPattern::new([A, A, C]).inverse(), None);
+        assert!(!pattern.is_identity());
+        assert!(pattern.then(pattern.inverse().unwrap()).is_identity());
+        assert!(TestStructPattern::new([A, B, C]).is_identity());
+        assert_eq!(pattern.sign(), 1);
+        assert_eq!(TestStructPattern::new([B, A, C]).sign(), -1);
+        assert_eq!(TestStructPattern::new([A, A, C]).sign(), 0);
+
+        assert_eq!(
+            TestStructPattern::parse("bca"),
+            Ok(TestStructPattern::new([B, C, A]))
+        );
+        assert_eq!(
+            pattern.then(TestStructPattern::new([B, A, C])).apply(&s),
+            TestStructPattern::new([B, A, C]).apply(&pattern.apply(&s))
+        );
+
+        assert_eq!(
+            "ca".parse::<TestStructPattern>(),
+            Err(SwizzleError::InvalidPattern)
+        );
+        assert_eq!(
+            "cabb".parse::<TestStructPattern>(),
+            Err(SwizzleError::InvalidPattern)
+        );
+        assert_eq!(
+            "cax".parse::<TestStructPattern>(),
+            Err(SwizzleError::InvalidPattern)
+        );
+    }
+
+    #[test]
+    fn test_swizzle_axis_str() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        struct TestStruct {
+            lo: u8,
+            hi: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { lo, hi });
+
+        assert_eq!("hi".parse(), Ok(TestStructAxis::Hi));
+        assert_eq!("lo".parse(), Ok(TestStructAxis::Lo));
+        assert_eq!(
+            "lohi".parse::<TestStructAxis>(),
+            Err(SwizzleError::InvalidAxis)
+        );
+        assert_eq!("".parse::<TestStructAxis>(), Err(SwizzleError::InvalidAxis));
+        assert_eq!(TestStructAxis::Hi.to_string(), "hi");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_swizzle_serde() {
+        use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        use TestStructAxis::{A, B, C};
+
+        assert_tokens(&B, &[Token::Str("b")]);
+        assert_tokens(&TestStructPattern::new([C, A, B]), &[Token::Str("cab")]);
+        assert_de_tokens_error::<TestStructPattern>(
+            &[Token::Str("cabx")],
+            "invalid swizzle pattern",
+        );
+        assert_de_tokens_error::<TestStructAxis>(&[Token::Str("d")], "invalid swizzle field name");
+    }
+
+    #[test]
+    fn test_swizzle_pattern_rank() {
+        use crate::swizzle;
+
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(table TestStruct { a, b, c });
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        use TestStructAxis::{A, B, C};
+
+        assert_eq!(TestStructPattern::COUNT, Some(27));
+        assert_eq!(TestStructPattern::new([A, A, A]).rank(), Some(0));
+        assert_eq!(TestStructPattern::new([A, B, C]).rank(), Some(5));
+        assert_eq!(TestStructPattern::new([C, C, C]).rank(), Some(26));
+        assert_eq!(
+            TestStructPattern::unrank(5),
+            Some(TestStructPattern::new([A, B, C]))
+        );
+        assert_eq!(TestStructPattern::unrank(27), None);
+
+        for (rank, name) in TestStruct::SWIZZLE_NAMES.iter().enumerate() {
+            let pattern = TestStructPattern::unrank(rank).unwrap();
+            assert_eq!(pattern.rank(), Some(rank));
+            assert_eq!(pattern, name.parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_swizzle_pattern_prefix_names() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        struct TestStruct {
+            x: u8,
+            xy: u8,
+            yz: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { x, xy, yz });
+
+        use TestStructAxis::{X, Xy, Yz};
+
+        assert_eq!("xy".parse(), Ok(Xy));
+        assert_eq!("x".parse(), Ok(X));
+        assert_eq!(
+            "xyx".parse::<TestStructAxis>(),
+            Err(SwizzleError::InvalidAxis)
+        );
+        assert_eq!(
+            TestStructPattern::parse("xyxyz"),
+            Ok(TestStructPattern::new([Xy, X, Yz]))
+        );
+        assert_eq!(
+            TestStructPattern::parse("xyzxy"),
+            Ok(TestStructPattern::new([X, Yz, Xy]))
+        );
+        assert_eq!(
+            TestStructPattern::parse("xyxy"),
+            Err(SwizzleError::InvalidPattern)
+        );
+
+        for pattern in TestStructPattern::all() {
+            assert_eq!(pattern.to_string().parse(), Ok(pattern));
+        }
+    }
+
+    #[test]
+    fn test_swizzle_pattern_rank_wide() {
+        struct Wide {
+            a: u8,
+            b: u8,
+            c: u8,
+            d: u8,
+            e: u8,
+            f: u8,
+            g: u8,
+            h: u8,
+            i: u8,
+            j: u8,
+            k: u8,
+            l: u8,
+            m: u8,
+            n: u8,
+            o: u8,
+            p: u8,
+            q: u8,
+        }
+
+        swizzle_dyn!(Wide: u8 { a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q });
+
+        use WideAxis::{A, B, Q};
+
+        assert_eq!(WidePattern::COUNT, None);
+        let identity: WidePattern = "abcdefghijklmnopq".parse().unwrap();
+        assert_eq!(
+            WidePattern::unrank(identity.rank().unwrap()),
+            Some(identity)
+        );
+        assert_eq!(WidePattern::new([A; 17]).rank(), Some(0));
+        assert_eq!(WidePattern::new([Q; 17]).rank(), None);
+        assert_eq!(
+            WidePattern::unrank(usize::MAX).map(|p| p.rank()),
+            Some(Some(usize::MAX))
+        );
+        assert_eq!(WidePattern::unrank(1).map(|p| p.axes()[16]), Some(B));
+
+        // One past `usize::MAX`, overflowing on the last addition rather than a multiplication.
+        let mut axes = WidePattern::unrank(usize::MAX).unwrap().axes();
+        axes[16] = WideAxis::ALL[axes[16].index() + 1];
+        assert_eq!(WidePattern::new(axes).rank(), None);
+    }
+
+    #[test]
+    fn test_swizzle_compose() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        use TestStructAxis::{A, B, C};
+
+        const P: TestStructPattern = swizzle_compose!(TestStructPattern: bca, bca);
+        assert_eq!(P, TestStructPattern::new([C, A, B]));
+        assert_eq!(
+            swizzle_compose!(TestStructPattern: bca, bca, bca),
+            TestStructPattern::new([A, B, C])
+        );
+        assert_eq!(
+            swizzle_compose!(TestStructPattern: aab, cba),
+            TestStructPattern::new([B, A, A])
+        );
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+        assert_eq!(
+            swizzle_compose!(s, TestStructPattern: bca, bac),
+            TestStructPattern::new([B, A, C]).apply(&TestStructPattern::new([B, C, A]).apply(&s))
+        );
+    }
+
+    #[test]
+    fn test_swizzle_by() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        use TestStructAxis::{A, B, C};
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.swizzle_by([C, B, A]), TestStruct { a: 3, b: 2, c: 1 });
+        assert_eq!(s.swizzle_by([B, B, A]), s.swizzle_dyn([1, 1, 0]));
+    }
+
+    #[test]
+    fn test_swizzle_by_conversion() {
+        struct Source {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Pair {
+            x: u8,
+            y: u8,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Quad {
+            w: u8,
+            x: u8,
+            y: u8,
+            z: u8,
+        }
+
+        swizzle_dyn!(Source: u8 { a, b, c });
+        swizzle_dyn!(Source => Pair { x, y });
+        swizzle_dyn!(Source => Quad { w, x, y, z });
+
+        use SourceAxis::{A, B, C};
+
+        let s = Source { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.swizzle_by_pair([C, A]), Pair { x: 3, y: 1 });
+        assert_eq!(
+            s.swizzle_by_quad([A, B, C, C]),
+            Quad {
+                w: 1,
+                x: 2,
+                y: 3,
+                z: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_prefix_view() {
+        #[repr(C)]
+        struct Source {
+            a: u16,
+            b: u16,
+            c: u16,
+        }
+
+        #[repr(C)]
+        #[derive(Debug, PartialEq)]
+        struct Pair {
+            a: u16,
+            b: u16,
+        }
+
+        swizzle_dyn!(Source: u16 { a, b, c });
+        swizzle_dyn!(view Source => Pair { a, b });
+
+        let mut s = Source { a: 1, b: 2, c: 3 };
+        assert_eq!(s.ab_view(), &Pair { a: 1, b: 2 });
+        s.ab_view_mut().b = 10;
+        assert_eq!((s.a, s.b, s.c), (1, 10, 3));
+        assert_eq!(
+            s.swizzle_by_pair([SourceAxis::C, SourceAxis::B]),
+            Pair { a: 3, b: 10 }
+        );
+
+        const PAIR: &Pair = Source { a: 4, b: 5, c: 6 }.ab_view();
+        assert_eq!(PAIR, &Pair { a: 4, b: 5 });
+    }
+
+    #[test]
+    fn test_to_from_array() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { c, a, b });
+
+        const S: TestStruct = TestStruct::from_array([1, 2, 3]);
+        assert_eq!(S, TestStruct { c: 1, a: 2, b: 3 });
+        assert_eq!(S.to_array(), [1, 2, 3]);
+        assert_eq!(
+            TestStruct::from_array(S.swizzle_dyn([2, 1, 0]).to_array()),
+            TestStruct { c: 3, a: 2, b: 1 }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "portable-simd")]
+    fn test_permute_simd() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(simd indexed TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+        assert_eq!(s.permute_simd::<2, 0, 1>(), s.permute::<2, 0, 1>());
+        assert_eq!(s.permute_simd::<1, 1, 1>(), TestStruct { a: 2, b: 2, c: 2 });
+        assert_eq!(s[2], 3);
+    }
+
+    #[test]
+    fn test_swizzle_sse() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: f32,
+            b: f32,
+            c: f32,
+            d: f32,
+        }
+
+        swizzle_dyn!(sse indexed TestStruct: f32 { a, b, c, d });
+
+        let s = TestStruct::from_array([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(s.permute_sse::<3, 0, 0, 1>(), s.permute::<3, 0, 0, 1>());
+        assert_eq!(s.swizzle_sse([2, 3, 0, 1]).to_array(), [3.0, 4.0, 1.0, 2.0]);
+        assert_eq!(s[3], 4.0);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    // `Pod` requires `Copy`, which the generated `to_array(&self)` doesn't expect.
+    #[allow(clippy::wrong_self_convention)]
+    fn test_pod() {
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+        struct TestStruct {
+            a: f32,
+            b: f32,
+            c: f32,
+            d: f32,
+        }
+
+        #[repr(C)]
+        #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+        struct Reversed {
+            d: f32,
+            c: f32,
+            b: f32,
+            a: f32,
+        }
+
+        swizzle_dyn!(sse pod indexed TestStruct: f32 { a, b, c, d });
+
+        let mut s = TestStruct::from_array([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(s.as_array(), &[1.0, 2.0, 3.0, 4.0]);
+        s.as_array_mut()[1] = 5.0;
+        assert_eq!(s.b, 5.0);
+
+        let reversed: &Reversed = s.view();
+        assert_eq!((reversed.d, reversed.a), (1.0, 4.0));
+        s.view_mut::<[u32; 4]>()[3] = 0;
+        assert_eq!(s.d, 0.0);
+        assert_eq!(s.swizzle_sse([3, 2, 1, 0])[3], 1.0);
+    }
+
+    #[test]
+    fn test_indexed() {
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(indexed TestStruct: u8 { c, a, b });
+
+        let mut s = TestStruct { a: 1, b: 2, c: 3 };
+        assert_eq!([s[0], s[1], s[2]], [3, 1, 2]);
+
+        s[1] = 10;
+        assert_eq!(s.a, 10);
+        assert_eq!(s.swizzle_dyn([1, 1, 1]).c, 10);
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_indexed_out_of_range() {
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        swizzle_dyn!(indexed TestStruct: u8 { a, b });
+
+        let _ = TestStruct { a: 1, b: 2 }[2];
+    }
+
+    #[test]
+    fn test_components() {
+        use crate::Components;
+
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct::from_fn(|i| i as u8 * 10);
+        assert_eq!((s.a, s.b, s.c), (0, 10, 20));
+        assert_eq!(TestStruct::LEN, 3);
+        assert_eq!([s.get(0), s.get(1), s.get(2)], [0, 10, 20]);
+
+        let mut calls = [0; 3];
+        let mut n = 0;
+        let _ = TestStruct::from_fn(|i| {
+            calls[n] = i;
+            n += 1;
+            0
+        });
+        assert_eq!(calls, [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_components_get_out_of_range() {
+        use crate::Components;
+
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let _ = TestStruct { a: 1, b: 2, c: 3 }.get(3);
+    }
+
+    #[test]
+    fn test_swizzle_arity_traits() {
+        use crate::{Swizzle2, Swizzle3};
+
+        #[derive(Debug, PartialEq)]
+        struct Pair {
+            x: u8,
+            y: u8,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Triple {
+            x: u8,
+            y: u8,
+            z: u8,
+        }
+
+        swizzle_dyn!(Pair: u8 { x, y });
+        swizzle_dyn!(Triple: u8 { x, y, z });
+        swizzle_dyn!(Triple => Pair { x, y });
+
+        fn first_two<T: Swizzle2<Pair>>(value: &T) -> Pair {
+            value.swizzle2([0, 1])
+        }
+
+        let p = Pair { x: 1, y: 2 };
+        let t = Triple { x: 1, y: 2, z: 3 };
+
+        assert_eq!(first_two(&p), Pair { x: 1, y: 2 });
+        assert_eq!(first_two(&t), Pair { x: 1, y: 2 });
+        assert_eq!(t.swizzle2([2, 0]), Pair { x: 3, y: 1 });
+        assert_eq!(p.swizzle2([1, 1]), Pair { x: 2, y: 2 });
+        assert_eq!(t.swizzle3([2, 1, 0]), t.swizzle_dyn([2, 1, 0]));
+    }
+
+    #[test]
+    fn test_swizzle_dyn_matches_swizzle() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(TestStruct { a, b });
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b });
+
+        let s = TestStruct { a: 1, b: 2 };
+
+        assert_eq!(s.swizzle_dyn([0, 0]), s.aa());
+        assert_eq!(s.swizzle_dyn([0, 1]), s.ab());
+        assert_eq!(s.swizzle_dyn([1, 0]), s.ba());
+        assert_eq!(s.swizzle_dyn([1, 1]), s.bb());
+    }
+
+    #[test]
+    fn test_swizzle_dyn_permute() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.permute::<2, 1, 0>(), TestStruct { a: 3, b: 2, c: 1 });
+        assert_eq!(s.permute::<0, 0, 1>(), s.swizzle_dyn([0, 0, 1]));
+
+        const P: TestStruct = TestStruct { a: 1, b: 2, c: 3 }.permute::<1, 2, 0>();
+        assert_eq!(P, TestStruct { a: 2, b: 3, c: 1 });
+    }
+
+    #[test]
+    fn test_swizzle_dyn_const_context() {
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b });
+
+        const S: TestStruct = TestStruct { a: 1, b: 2 }.swizzle_dyn([1, 0]);
+        assert_eq!((S.a, S.b), (2, 1));
+    }
+
+    #[test]
+    fn test_swizzle_dyn_unchecked() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        // SAFETY: all of the indices are in range.
+        unsafe {
+            assert_eq!(s.swizzle_dyn_unchecked([2, 1, 0]), s.swizzle_dyn([2, 1, 0]));
+            assert_eq!(s.swizzle_dyn_unchecked([0, 2, 2]), s.swizzle_dyn([0, 2, 2]));
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "swizzle index out of range")]
+    fn test_swizzle_dyn_unchecked_out_of_range() {
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b });
+
+        let s = TestStruct { a: 1, b: 2 };
+        // SAFETY: not safe, but caught by the debug assertion before any out of range read.
+        let _ = unsafe { s.swizzle_dyn_unchecked([0, 2]) };
+    }
+
+    #[test]
+    fn test_try_swizzle() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(
+            s.try_swizzle(&[2, 1, 0]),
+            Ok(TestStruct { a: 3, b: 2, c: 1 })
+        );
+        assert_eq!(
+            s.try_swizzle(&[0, 0, 2]),
+            Ok(TestStruct { a: 1, b: 1, c: 3 })
+        );
+        assert_eq!(
+            s.try_swizzle(&[0, 3, 1]),
+            Err(SwizzleError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        assert_eq!(
+            s.try_swizzle(&[0, 1]),
+            Err(SwizzleError::WrongArity {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            s.try_swizzle(&[0, 1, 2, 0]),
+            Err(SwizzleError::WrongArity {
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swizzle_dyn_out_of_range() {
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        swizzle_dyn!(TestStruct: u8 { a, b });
+
+        let s = TestStruct { a: 1, b: 2 };
+        let _ = s.swizzle_dyn([0, 2]);
+    }
+}
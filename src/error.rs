@@ -0,0 +1,65 @@
+use core::fmt;
+
+/// Error returned by the checked runtime swizzles and pattern parsing generated by `swizzle_dyn!`,
+/// and by the validation of a [`Permutation`](crate::Permutation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SwizzleError {
+    /// A field index was not less than the number of fields.
+    IndexOutOfRange {
+        /// The index that was out of range.
+        index: usize,
+        /// The number of fields.
+        len: usize,
+    },
+    /// The number of indices didn't match the number of fields.
+    WrongArity {
+        /// The number of fields.
+        expected: usize,
+        /// The number of indices given.
+        found: usize,
+    },
+    /// A field index appeared more than once in a permutation.
+    DuplicateIndex {
+        /// The index that appeared more than once.
+        index: usize,
+    },
+    /// A swizzle pattern string didn't name exactly one field for each position.
+    InvalidPattern,
+    /// A string wasn't the name of a field.
+    InvalidAxis,
+    /// A component of a graphics API swizzle wasn't taken from a field, such as a constant zero or
+    /// one, or wasn't a valid value.
+    UnsupportedComponent,
+    /// Two pixel formats didn't differ only by the order of their channels.
+    IncompatibleFormats,
+}
+
+impl fmt::Display for SwizzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndexOutOfRange { index, len } => {
+                write!(f, "swizzle index {index} is out of range for {len} fields")
+            }
+            Self::WrongArity { expected, found } => {
+                write!(f, "swizzle expected {expected} indices, found {found}")
+            }
+            Self::DuplicateIndex { index } => {
+                write!(
+                    f,
+                    "swizzle index {index} appears more than once in a permutation"
+                )
+            }
+            Self::InvalidPattern => f.write_str("invalid swizzle pattern"),
+            Self::InvalidAxis => f.write_str("invalid swizzle field name"),
+            Self::UnsupportedComponent => {
+                f.write_str("swizzle component is not taken from a field")
+            }
+            Self::IncompatibleFormats => {
+                f.write_str("pixel formats differ by more than the order of their channels")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SwizzleError {}
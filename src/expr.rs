@@ -0,0 +1,90 @@
+/// Macro for swizzling a value by a pattern written as a string literal, as in a shader.
+///
+/// Expands to the call of the function generated by `swizzle!` with the name of the pattern, so
+/// `swizzle_expr!(v, "xzy")` is `v.xzy()`, and GLSL such as `v.xzy` can be ported by quoting the
+/// pattern rather than rewriting it. The pattern is checked at compile time: a pattern naming a
+/// field the type doesn't have, or a swizzle that wasn't generated for it, fails to compile.
+///
+/// Chained swizzles such as GLSL's `v.wzyx.xyz.yx` are written with the patterns separated by
+/// `.`, either each quoted, `swizzle_expr!(v, "wzyx"."xyz"."yx")`, or unquoted,
+/// `swizzle_expr!(v, wzyx.xyz.yx)`, and expand to the chained calls `v.wzyx().xyz().yx()`. A
+/// single literal such as `"wzyx.xyz.yx"` can't be split by a declarative macro, so it fails to
+/// compile.
+///
+/// ```rust
+/// use swizzle::{swizzle, swizzle_expr};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// impl Vec3 {
+///     swizzle!(Vec3 { x, y, z });
+/// }
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// assert_eq!(swizzle_expr!(v, "xzy"), Vec3 { x: 1.0, y: 3.0, z: 2.0 });
+/// assert_eq!(swizzle_expr!(v, "zyx"."yzx"), Vec3 { x: 2.0, y: 1.0, z: 3.0 });
+/// assert_eq!(swizzle_expr!(v, zyx.yzx), Vec3 { x: 2.0, y: 1.0, z: 3.0 });
+/// ```
+///
+/// ```compile_fail
+/// # use swizzle::{swizzle, swizzle_expr};
+/// # struct Vec3 { x: f32, y: f32, z: f32 }
+/// # impl Vec3 { swizzle!(Vec3 { x, y, z }); }
+/// # let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// // `Vec3` has no `w` field.
+/// let _ = swizzle_expr!(v, "xyw");
+/// ```
+#[macro_export]
+macro_rules! swizzle_expr {
+    ($value:expr, $( $pattern:literal ).+ $(,)?) => {
+        $crate::__private::paste! { ($value) $( .[<$pattern>]() )+ }
+    };
+    ($value:expr, $( $pattern:ident ).+ $(,)?) => {
+        ($value) $( .$pattern() )+
+    };
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use crate::swizzle;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Vec3 {
+        x: i32,
+        y: i32,
+        z: i32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Vec2 {
+        x: i32,
+        y: i32,
+    }
+
+    impl Vec3 {
+        swizzle!(Vec3 { x, y, z });
+        swizzle!(Vec2 {
+            x: (x, y, z),
+            y: (x, y, z)
+        });
+    }
+
+    #[test]
+    fn test_swizzle_expr() {
+        let v = Vec3 { x: 1, y: 2, z: 3 };
+        assert_eq!(swizzle_expr!(v, "zyx"), Vec3 { x: 3, y: 2, z: 1 });
+        assert_eq!(swizzle_expr!(v, "zx"), Vec2 { x: 3, y: 1 });
+        assert_eq!(swizzle_expr!(v.yzx(), "yy"), Vec2 { x: 3, y: 3 });
+    }
+
+    #[test]
+    fn test_swizzle_expr_chained() {
+        let v = Vec3 { x: 1, y: 2, z: 3 };
+        assert_eq!(swizzle_expr!(v, "zyx"."zx"), Vec2 { x: 1, y: 3 });
+        assert_eq!(swizzle_expr!(v, "yzx"."zyx"."xz"), Vec2 { x: 1, y: 2 });
+        assert_eq!(swizzle_expr!(v, yzx.zyx.xz), Vec2 { x: 1, y: 2 });
+        assert_eq!(swizzle_expr!(v, zyx), Vec3 { x: 3, y: 2, z: 1 });
+    }
+}
@@ -0,0 +1,178 @@
+//! Adapters between `#[repr(C)]` structs that hold the same data with the fields in a different
+//! order, such as a crate's `Vec3` and a C library's `{ z, y, x }` struct.
+//!
+//! [`swizzle_ffi!`](crate::swizzle_ffi) generates the conversions both ways, and
+//! [`convert_slice`] converts whole buffers of them, e.g. the vertices handed to or returned by
+//! the library.
+//!
+//! ```rust
+//! use swizzle::ffi::convert_slice;
+//! use swizzle::swizzle_ffi;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Vec3 { x: f32, y: f32, z: f32 }
+//!
+//! // As declared by the C library.
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct CVec3 { z: f32, y: f32, x: f32 }
+//!
+//! swizzle_ffi!(Vec3 <=> CVec3 { z, y, x });
+//!
+//! let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+//! assert_eq!(CVec3::from(v), CVec3 { z: 3.0, y: 2.0, x: 1.0 });
+//!
+//! let mut c_vertices = [CVec3 { z: 0.0, y: 0.0, x: 0.0 }; 2];
+//! convert_slice(&[v; 2], &mut c_vertices);
+//! assert_eq!(c_vertices[1].x, 1.0);
+//! ```
+
+/// Convert every item of `src` into the item of `dst` at the same position, e.g. with the `From`
+/// implementations generated by [`swizzle_ffi!`](crate::swizzle_ffi).
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn convert_slice<T: Copy, U: From<T>>(src: &[T], dst: &mut [U]) {
+    assert_eq!(src.len(), dst.len(), "swizzle slice lengths differ");
+    for (dst, &src) in dst.iter_mut().zip(src) {
+        *dst = U::from(src);
+    }
+}
+
+/// Macro for generating conversions between two `#[repr(C)]` structs holding the same data with
+/// the fields in a different order.
+///
+/// Implements `From` both ways, with the fields of the second struct listed in any order, each
+/// taken from the field of the first struct with the same name, or with the name after a colon,
+/// like the conversions of `swizzle!`, e.g. `{ z: depth, y, x }`. Every field of both structs must
+/// be listed exactly once with the same type, which is checked by the compiler, and the structs
+/// must be the same size, which is checked at compile time, so an adapter can't silently drop or
+/// duplicate a field when either struct changes.
+///
+/// ```compile_fail
+/// use swizzle::swizzle_ffi;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct CVec4 { w: f32, z: f32, y: f32, x: f32 }
+///
+/// // `CVec4` has a `w` field that isn't taken from `Vec3`.
+/// swizzle_ffi!(Vec3 <=> CVec4 { z, y, x });
+/// ```
+///
+/// Both structs must be `Copy`. Slices of them are converted by [`convert_slice`].
+#[macro_export]
+macro_rules! swizzle_ffi {
+    ( $type:ident <=> $dst_type:ident { $( $dst_attr:ident $( : $attr:ident )? ),+ $(,)? } ) => {
+        $crate::swizzle_ffi!(@impl $type <=> $dst_type { $( $dst_attr: ($( $attr )? $dst_attr) ),+ });
+    };
+    ( @impl $type:ident <=> $dst_type:ident { $( $dst_attr:ident: ($attr:ident $( $_default:ident )?) ),+ } ) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$type>() == ::core::mem::size_of::<$dst_type>(),
+            concat!("`", stringify!($type), "` and `", stringify!($dst_type), "` differ in size")
+        );
+
+        impl ::core::convert::From<$type> for $dst_type {
+            #[inline]
+            fn from(value: $type) -> Self {
+                Self { $( $dst_attr: value.$attr ),+ }
+            }
+        }
+
+        impl ::core::convert::From<$dst_type> for $type {
+            #[inline]
+            fn from(value: $dst_type) -> Self {
+                Self { $( $attr: value.$dst_attr ),+ }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Pixel {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct CPixel {
+        blue: u8,
+        green: u8,
+        red: u8,
+        alpha: u8,
+    }
+
+    swizzle_ffi!(Pixel <=> CPixel { blue: b, green: g, red: r, alpha: a });
+
+    #[test]
+    fn test_swizzle_ffi() {
+        let pixel = Pixel {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 4,
+        };
+        let c_pixel = CPixel::from(pixel);
+        assert_eq!(
+            c_pixel,
+            CPixel {
+                blue: 3,
+                green: 2,
+                red: 1,
+                alpha: 4
+            }
+        );
+        assert_eq!(Pixel::from(c_pixel), pixel);
+    }
+
+    #[test]
+    fn test_convert_slice() {
+        let pixels = [
+            Pixel {
+                r: 1,
+                g: 2,
+                b: 3,
+                a: 4,
+            },
+            Pixel {
+                r: 5,
+                g: 6,
+                b: 7,
+                a: 8,
+            },
+        ];
+        let mut c_pixels = [CPixel::from(pixels[0]); 2];
+        convert_slice(&pixels, &mut c_pixels);
+        assert_eq!(c_pixels[1].red, 5);
+
+        let mut round_trip = [pixels[0]; 2];
+        convert_slice(&c_pixels, &mut round_trip);
+        assert_eq!(round_trip, pixels);
+    }
+
+    #[test]
+    #[should_panic = "swizzle slice lengths differ"]
+    fn test_convert_slice_lengths_differ() {
+        let pixel = Pixel {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0,
+        };
+        convert_slice(&[pixel; 2], &mut [CPixel::from(pixel); 3]);
+    }
+}
@@ -0,0 +1,269 @@
+//! The channel orders of common texture formats, keyed by their Vulkan and DXGI names, to look up
+//! the swizzle that converts between two formats.
+//!
+//! The channels of each format are listed from its least significant bits, which is the order of
+//! the bytes in memory of the formats with 8-bit channels on little-endian targets, and the order
+//! of the DXGI names. Vulkan names list the channels of packed formats, such as
+//! `VK_FORMAT_A2R10G10B10_UNORM_PACK32`, from the most significant bits, so their channel order is
+//! the reverse of their names.
+//!
+//! ```rust
+//! use swizzle::formats::Format;
+//!
+//! let bgra = Format::from_vulkan_name("VK_FORMAT_B8G8R8A8_UNORM").unwrap();
+//! let rgba = Format::from_dxgi_name("DXGI_FORMAT_R8G8B8A8_UNORM").unwrap();
+//! assert_eq!(bgra.channels(), "bgra");
+//!
+//! // The swizzle converting a BGRA pixel to RGBA.
+//! let swizzle = bgra.swizzle_to::<4>(&rgba).unwrap();
+//! assert_eq!(swizzle.indices(), [2, 1, 0, 3]);
+//!
+//! // And the format of the pixels after swizzling.
+//! assert_eq!(bgra.swizzled(&swizzle), Some(rgba));
+//! ```
+
+use crate::Permutation;
+
+/// How the values of the channels of a format are interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Numeric {
+    /// Unsigned integers normalized to `[0, 1]`.
+    Unorm,
+    /// Signed integers normalized to `[-1, 1]`.
+    Snorm,
+    /// Unsigned integers.
+    Uint,
+    /// Signed integers.
+    Sint,
+    /// Unsigned integers normalized to `[0, 1]`, with the color channels in the sRGB color space.
+    Srgb,
+    /// Signed floating point numbers.
+    Sfloat,
+}
+
+/// Generates the table of formats, from their channels, the bits of each channel, how they are
+/// interpreted, and their Vulkan and DXGI names without the `VK_FORMAT_` and `DXGI_FORMAT_`
+/// prefixes, or `_` if there is no format of that API.
+macro_rules! formats {
+    ($( $channels:literal $bits:tt $numeric:ident: $vulkan:tt, $dxgi:tt; )+) => {
+        [ $(
+            Format::new(
+                $channels,
+                &$bits,
+                Numeric::$numeric,
+                formats!(@name "VK_FORMAT_" $vulkan),
+                formats!(@name "DXGI_FORMAT_" $dxgi),
+            ),
+        )+ ]
+    };
+    (@name $prefix:literal _) => {
+        None
+    };
+    (@name $prefix:literal $name:ident) => {
+        Some(concat!($prefix, stringify!($name)))
+    };
+}
+
+/// A texture format, with the order and size of its channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Format {
+    channels: &'static str,
+    bits: &'static [u8],
+    numeric: Numeric,
+    vulkan: Option<&'static str>,
+    dxgi: Option<&'static str>,
+}
+
+impl Format {
+    /// All of the formats in the table.
+    pub const ALL: &'static [Self] = &formats! {
+        "rgba" [8; 4] Unorm: R8G8B8A8_UNORM, R8G8B8A8_UNORM;
+        "rgba" [8; 4] Srgb: R8G8B8A8_SRGB, R8G8B8A8_UNORM_SRGB;
+        "rgba" [8; 4] Snorm: R8G8B8A8_SNORM, R8G8B8A8_SNORM;
+        "rgba" [8; 4] Uint: R8G8B8A8_UINT, R8G8B8A8_UINT;
+        "rgba" [8; 4] Sint: R8G8B8A8_SINT, R8G8B8A8_SINT;
+        "bgra" [8; 4] Unorm: B8G8R8A8_UNORM, B8G8R8A8_UNORM;
+        "bgra" [8; 4] Srgb: B8G8R8A8_SRGB, B8G8R8A8_UNORM_SRGB;
+        "bgra" [8; 4] Snorm: B8G8R8A8_SNORM, _;
+        "bgra" [8; 4] Uint: B8G8R8A8_UINT, _;
+        "bgra" [8; 4] Sint: B8G8R8A8_SINT, _;
+        "rgba" [8; 4] Unorm: A8B8G8R8_UNORM_PACK32, _;
+        "rgba" [8; 4] Srgb: A8B8G8R8_SRGB_PACK32, _;
+        "rgb" [8; 3] Unorm: R8G8B8_UNORM, _;
+        "rgb" [8; 3] Srgb: R8G8B8_SRGB, _;
+        "bgr" [8; 3] Unorm: B8G8R8_UNORM, _;
+        "bgr" [8; 3] Srgb: B8G8R8_SRGB, _;
+        "rgba" [10, 10, 10, 2] Unorm: A2B10G10R10_UNORM_PACK32, R10G10B10A2_UNORM;
+        "rgba" [10, 10, 10, 2] Uint: A2B10G10R10_UINT_PACK32, R10G10B10A2_UINT;
+        "bgra" [10, 10, 10, 2] Unorm: A2R10G10B10_UNORM_PACK32, _;
+        "bgra" [10, 10, 10, 2] Uint: A2R10G10B10_UINT_PACK32, _;
+        "bgr" [5, 6, 5] Unorm: R5G6B5_UNORM_PACK16, B5G6R5_UNORM;
+        "rgb" [5, 6, 5] Unorm: B5G6R5_UNORM_PACK16, _;
+        "bgra" [5, 5, 5, 1] Unorm: A1R5G5B5_UNORM_PACK16, B5G5R5A1_UNORM;
+        "abgr" [1, 5, 5, 5] Unorm: R5G5B5A1_UNORM_PACK16, _;
+        "argb" [1, 5, 5, 5] Unorm: B5G5R5A1_UNORM_PACK16, _;
+        "bgra" [4; 4] Unorm: A4R4G4B4_UNORM_PACK16, B4G4R4A4_UNORM;
+        "rgba" [4; 4] Unorm: A4B4G4R4_UNORM_PACK16, _;
+        "abgr" [4; 4] Unorm: R4G4B4A4_UNORM_PACK16, _;
+        "argb" [4; 4] Unorm: B4G4R4A4_UNORM_PACK16, _;
+        "rgba" [16; 4] Sfloat: R16G16B16A16_SFLOAT, R16G16B16A16_FLOAT;
+        "rgba" [32; 4] Sfloat: R32G32B32A32_SFLOAT, R32G32B32A32_FLOAT;
+        "rgb" [32; 3] Sfloat: R32G32B32_SFLOAT, R32G32B32_FLOAT;
+    };
+
+    const fn new(
+        channels: &'static str,
+        bits: &'static [u8],
+        numeric: Numeric,
+        vulkan: Option<&'static str>,
+        dxgi: Option<&'static str>,
+    ) -> Self {
+        Self {
+            channels,
+            bits,
+            numeric,
+            vulkan,
+            dxgi,
+        }
+    }
+
+    /// Returns the format with the Vulkan name, e.g. `"VK_FORMAT_B8G8R8A8_UNORM"`, or `None` if
+    /// it isn't in the table.
+    #[must_use]
+    pub fn from_vulkan_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|format| format.vulkan == Some(name))
+    }
+
+    /// Returns the format with the DXGI name, e.g. `"DXGI_FORMAT_B8G8R8A8_UNORM"`, or `None` if
+    /// it isn't in the table.
+    #[must_use]
+    pub fn from_dxgi_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|format| format.dxgi == Some(name))
+    }
+
+    /// The name of the `VkFormat`, if it has one.
+    #[must_use]
+    pub const fn vulkan_name(&self) -> Option<&'static str> {
+        self.vulkan
+    }
+
+    /// The name of the `DXGI_FORMAT`, if it has one.
+    #[must_use]
+    pub const fn dxgi_name(&self) -> Option<&'static str> {
+        self.dxgi
+    }
+
+    /// The channels, from the least significant bits, as the letters `r`, `g`, `b`, and `a`.
+    #[must_use]
+    pub const fn channels(&self) -> &'static str {
+        self.channels
+    }
+
+    /// The number of bits of each channel, in the same order as [`channels`](Self::channels).
+    #[must_use]
+    pub const fn bits(&self) -> &'static [u8] {
+        self.bits
+    }
+
+    /// How the values of the channels are interpreted.
+    #[must_use]
+    pub const fn numeric(&self) -> Numeric {
+        self.numeric
+    }
+
+    /// The number of bits of the channel, if the format has it.
+    fn channel_bits(&self, channel: u8) -> Option<(usize, u8)> {
+        let i = self.channels.bytes().position(|c| c == channel)?;
+        Some((i, self.bits[i]))
+    }
+
+    /// Returns the swizzle converting the pixels of this format to `dst`, taking the channel at
+    /// each position of `dst` from the channel of this format at the index. Returns `None` if the
+    /// formats don't have the same `N` channels of the same sizes, or interpret them differently.
+    #[must_use]
+    pub fn swizzle_to<const N: usize>(&self, dst: &Self) -> Option<Permutation<N>> {
+        if self.numeric != dst.numeric || self.channels.len() != N || dst.channels.len() != N {
+            return None;
+        }
+        let mut idx = [0; N];
+        for (i, channel) in dst.channels.bytes().enumerate() {
+            let (index, bits) = self.channel_bits(channel)?;
+            if bits != dst.bits[i] {
+                return None;
+            }
+            idx[i] = index;
+        }
+        Permutation::new(idx).ok()
+    }
+
+    /// Returns the format of the pixels of this format swizzled by `permutation`, or `None` if it
+    /// isn't in the table. Formats with the same channel order, such as `VK_FORMAT_R8G8B8A8_UNORM`
+    /// and `VK_FORMAT_A8B8G8R8_UNORM_PACK32`, return the first in the table.
+    #[must_use]
+    pub fn swizzled<const N: usize>(&self, permutation: &Permutation<N>) -> Option<Self> {
+        if self.channels.len() != N {
+            return None;
+        }
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|format| self.swizzle_to(format).as_ref() == Some(permutation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        for format in Format::ALL {
+            if let Some(name) = format.vulkan_name() {
+                assert_eq!(Format::from_vulkan_name(name), Some(*format));
+            }
+            if let Some(name) = format.dxgi_name() {
+                assert_eq!(Format::from_dxgi_name(name), Some(*format));
+            }
+            assert_eq!(format.channels().len(), format.bits().len());
+        }
+        assert_eq!(Format::from_vulkan_name("VK_FORMAT_UNDEFINED"), None);
+        assert_eq!(
+            Format::from_dxgi_name("DXGI_FORMAT_R10G10B10A2_UNORM")
+                .unwrap()
+                .vulkan_name(),
+            Some("VK_FORMAT_A2B10G10R10_UNORM_PACK32")
+        );
+    }
+
+    #[test]
+    fn test_swizzle_to() {
+        let rgb565 = Format::from_vulkan_name("VK_FORMAT_R5G6B5_UNORM_PACK16").unwrap();
+        let bgr565 = Format::from_vulkan_name("VK_FORMAT_B5G6R5_UNORM_PACK16").unwrap();
+        let swizzle = rgb565.swizzle_to::<3>(&bgr565).unwrap();
+        assert_eq!(swizzle.indices(), [2, 1, 0]);
+        assert_eq!(rgb565.swizzled(&swizzle), Some(bgr565));
+
+        let argb = Format::from_vulkan_name("VK_FORMAT_B4G4R4A4_UNORM_PACK16").unwrap();
+        let bgra = Format::from_dxgi_name("DXGI_FORMAT_B4G4R4A4_UNORM").unwrap();
+        assert_eq!(argb.swizzle_to::<4>(&bgra).unwrap().indices(), [3, 2, 1, 0]);
+
+        // Different channel sizes, interpretations, and numbers of channels.
+        let rgba8 = Format::from_vulkan_name("VK_FORMAT_R8G8B8A8_UNORM").unwrap();
+        let rgba8_srgb = Format::from_vulkan_name("VK_FORMAT_R8G8B8A8_SRGB").unwrap();
+        let rgb8 = Format::from_vulkan_name("VK_FORMAT_R8G8B8_UNORM").unwrap();
+        assert_eq!(rgba8.swizzle_to::<4>(&argb), None);
+        assert_eq!(rgba8.swizzle_to::<4>(&rgba8_srgb), None);
+        assert_eq!(rgba8.swizzle_to::<4>(&rgb8), None);
+        assert_eq!(rgba8.swizzle_to::<3>(&rgba8), None);
+
+        // No 8-bit ARGB format.
+        let argb_swizzle: Permutation<4> = "wxyz".parse().unwrap();
+        assert_eq!(rgba8.swizzled(&argb_swizzle), None);
+    }
+}
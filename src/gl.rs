@@ -0,0 +1,96 @@
+//! Conversions between swizzles of 4 fields and OpenGL texture swizzle parameters.
+//!
+//! The parameters are the `GLint` arrays of `GL_RED`, `GL_GREEN`, `GL_BLUE`, and `GL_ALPHA` set
+//! with `glTexParameteriv(target, GL_TEXTURE_SWIZZLE_RGBA, ...)`, so they work with any OpenGL
+//! binding.
+//!
+//! ```rust
+//! use swizzle::gl::{from_texture_swizzle, to_texture_swizzle};
+//!
+//! // Sample a BGRA texture as RGBA.
+//! let params = to_texture_swizzle([2, 1, 0, 3]);
+//! assert_eq!(params, [0x1905, 0x1904, 0x1903, 0x1906]); // GL_BLUE, GL_GREEN, GL_RED, GL_ALPHA
+//! assert_eq!(from_texture_swizzle(params), Ok([2, 1, 0, 3]));
+//! ```
+
+use crate::SwizzleError;
+
+/// `GL_RED`, `GL_GREEN`, `GL_BLUE`, and `GL_ALPHA`, in component order.
+const CHANNELS: [i32; 4] = [0x1903, 0x1904, 0x1905, 0x1906];
+
+/// Returns the texture swizzle parameters taking the component at each position from the
+/// component at the index, as `GL_RED`, `GL_GREEN`, `GL_BLUE`, or `GL_ALPHA`.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+#[must_use]
+pub const fn to_texture_swizzle(idx: [usize; 4]) -> [i32; 4] {
+    let mut params = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        assert!(idx[i] < 4, "swizzle index out of range");
+        params[i] = CHANNELS[idx[i]];
+        i += 1;
+    }
+    params
+}
+
+/// Returns the index of the component that each position of the texture swizzle parameters is
+/// taken from.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::UnsupportedComponent`] if any parameter is `GL_ZERO` or `GL_ONE`, as
+/// they aren't taken from a component, or isn't a valid parameter.
+pub const fn from_texture_swizzle(params: [i32; 4]) -> Result<[usize; 4], SwizzleError> {
+    let mut idx = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        let mut channel = 0;
+        while channel < 4 && CHANNELS[channel] != params[i] {
+            channel += 1;
+        }
+        if channel == 4 {
+            return Err(SwizzleError::UnsupportedComponent);
+        }
+        idx[i] = channel;
+        i += 1;
+    }
+    Ok(idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Permutation;
+
+    #[test]
+    fn test_texture_swizzle() {
+        const RRRA: [i32; 4] = to_texture_swizzle([0, 0, 0, 3]);
+        assert_eq!(RRRA, [0x1903, 0x1903, 0x1903, 0x1906]);
+        assert_eq!(from_texture_swizzle(RRRA), Ok([0, 0, 0, 3]));
+
+        for permutation in ["xyzw", "wzyx", "yzwx"] {
+            let idx = permutation.parse::<Permutation<4>>().unwrap().indices();
+            assert_eq!(from_texture_swizzle(to_texture_swizzle(idx)), Ok(idx));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_component() {
+        // GL_ZERO, GL_ONE, and a value that isn't a parameter.
+        for value in [0, 1, 0x1907] {
+            assert_eq!(
+                from_texture_swizzle([0x1903, 0x1904, 0x1905, value]),
+                Err(SwizzleError::UnsupportedComponent)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_to_texture_swizzle_out_of_range() {
+        let _ = to_texture_swizzle([0, 1, 2, 4]);
+    }
+}
@@ -0,0 +1,198 @@
+//! Swizzles of the pixels of the [`image`](https://crates.io/crates/image) crate.
+//!
+//! The `Rgb`, `Rgba`, and `Luma` pixels implement [`Components`], so they can be swizzled at
+//! runtime like any struct declared to `swizzle_dyn!`, and get named swizzles from the
+//! [`RgbSwizzle`], [`RgbaSwizzle`], and [`LumaSwizzle`] extension traits. [`swizzle_pixels`] and
+//! [`swizzle_pixels_to`] apply a channel swizzle to a whole image.
+//!
+//! ```rust
+//! use image::{Rgb, RgbImage, Rgba, RgbaImage};
+//! use swizzle::image::{RgbaSwizzle, swizzle_pixels, swizzle_pixels_to};
+//!
+//! assert_eq!(Rgba([10u8, 20, 30, 255]).bgra(), Rgba([30, 20, 10, 255]));
+//!
+//! // Convert a loaded RGBA image to BGRA in place.
+//! let mut image = RgbaImage::from_pixel(2, 2, Rgba([10, 20, 30, 255]));
+//! swizzle_pixels(&mut image, [2, 1, 0, 3]);
+//! assert_eq!(image.get_pixel(1, 1), &Rgba([30, 20, 10, 255]));
+//!
+//! // Drop the alpha channel while swapping back to RGB.
+//! let rgb: RgbImage = swizzle_pixels_to(&image, [2, 1, 0]);
+//! assert_eq!(rgb.get_pixel(0, 0), &Rgb([10, 20, 30]));
+//! ```
+
+use crate::Components;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+use image::{ImageBuffer, Luma, Pixel, Primitive, Rgb, Rgba};
+
+macro_rules! impl_components {
+    ($( $pixel:ident($len:literal) ),+) => {
+        $(
+            impl<T: Primitive> Components<$len> for $pixel<T> {
+                type Component = T;
+
+                #[inline]
+                fn get(&self, i: usize) -> T {
+                    assert!(i < $len, "swizzle index out of range");
+                    self.0[i]
+                }
+
+                #[inline]
+                fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+                    $pixel(core::array::from_fn(f))
+                }
+            }
+        )+
+    };
+}
+
+impl_components!(Rgb(3), Rgba(4), Luma(1));
+
+/// Generates a named swizzle to `$pixel` for every combination of the given channels, of the
+/// length of the list of placeholders.
+macro_rules! pixel_swizzles {
+    ($pixel:ident; $channels:tt; $len:tt) => {
+        pixel_swizzles!(@extend $pixel; $channels $channels; $len; []);
+    };
+    (@extend $pixel:ident; [ $( $channel:tt )+ ] $channels:tt; $len:tt; $prefix:tt) => {
+        $( pixel_swizzles!(@step $pixel; $channels; $len; $prefix $channel); )+
+    };
+    (
+        @step $pixel:ident; $channels:tt; [ $n:tt ];
+        [ $( ($p:ident $pi:literal) )* ] ($a:ident $ai:literal)
+    ) => {
+        $crate::__private::paste! {
+            #[doc = concat!(
+                "Returns the `", stringify!($pixel), "` pixel `(",
+                stringify!($( $p, )* $a), ")`."
+            )]
+            #[must_use]
+            #[inline]
+            fn [<$( $p )* $a>](&self) -> $pixel<T> {
+                $pixel([ $( self.get($pi), )* self.get($ai) ])
+            }
+        }
+    };
+    (@step $pixel:ident; $channels:tt; [ $n:tt $( $rest:tt )+ ]; [ $( $p:tt )* ] $a:tt) => {
+        pixel_swizzles!(@extend $pixel; $channels $channels; [ $( $rest )+ ]; [ $( $p )* $a ]);
+    };
+}
+
+/// Named swizzles of an `Rgb` pixel, e.g. `pixel.bgr()`.
+pub trait RgbSwizzle<T: Primitive>: Components<3, Component = T> {
+    pixel_swizzles!(Rgb; [(r 0) (g 1) (b 2)]; [_ _ _]);
+}
+
+impl<T: Primitive> RgbSwizzle<T> for Rgb<T> {}
+
+/// Named swizzles of an `Rgba` pixel, e.g. `pixel.bgra()`, and to an `Rgb` pixel, e.g.
+/// `pixel.bgr()`.
+pub trait RgbaSwizzle<T: Primitive>: Components<4, Component = T> {
+    pixel_swizzles!(Rgba; [(r 0) (g 1) (b 2) (a 3)]; [_ _ _ _]);
+    pixel_swizzles!(Rgb; [(r 0) (g 1) (b 2) (a 3)]; [_ _ _]);
+}
+
+impl<T: Primitive> RgbaSwizzle<T> for Rgba<T> {}
+
+/// Named swizzles of a `Luma` pixel, e.g. `pixel.lll()` to the `Rgb` pixel of the same gray.
+pub trait LumaSwizzle<T: Primitive>: Components<1, Component = T> {
+    pixel_swizzles!(Rgb; [(l 0)]; [_ _ _]);
+}
+
+impl<T: Primitive> LumaSwizzle<T> for Luma<T> {}
+
+/// Swizzle every pixel of `image` in place, where the channel at each position is taken from the
+/// channel of the pixel at the index, e.g. `[2, 1, 0, 3]` to convert between RGBA and BGRA.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+pub fn swizzle_pixels<P, C, const N: usize>(image: &mut ImageBuffer<P, C>, idx: [usize; N])
+where
+    P: Pixel + Components<N>,
+    C: Deref<Target = [P::Subpixel]> + DerefMut,
+{
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    for pixel in image.pixels_mut() {
+        *pixel = P::from_fn(|i| pixel.get(idx[i]));
+    }
+}
+
+/// Create an image of another pixel type with the channel at each position of each pixel taken
+/// from the channel of the pixel of `image` at the index, e.g. `[2, 1, 0]` to convert from RGBA
+/// to BGR.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+#[must_use]
+pub fn swizzle_pixels_to<P, Q, C, const N: usize, const M: usize>(
+    image: &ImageBuffer<P, C>,
+    idx: [usize; M],
+) -> ImageBuffer<Q, Vec<Q::Subpixel>>
+where
+    P: Pixel + Components<N>,
+    Q: Pixel<Subpixel = P::Subpixel> + Components<M, Component = P::Component>,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    assert!(idx.iter().all(|&i| i < N), "swizzle index out of range");
+    let (width, height) = image.dimensions();
+    ImageBuffer::from_fn(width, height, |x, y| {
+        let pixel = image.get_pixel(x, y);
+        Q::from_fn(|i| pixel.get(idx[i]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Permutation, SwizzleDyn};
+    use image::{GrayImage, RgbImage};
+
+    #[test]
+    fn test_named_swizzles() {
+        let rgb = Rgb([1u8, 2, 3]);
+        assert_eq!(rgb.bgr(), Rgb([3, 2, 1]));
+        assert_eq!(rgb.rrg(), Rgb([1, 1, 2]));
+
+        let rgba = Rgba([1.0f32, 2.0, 3.0, 4.0]);
+        assert_eq!(rgba.argb(), Rgba([4.0, 1.0, 2.0, 3.0]));
+        assert_eq!(rgba.bgr(), Rgb([3.0, 2.0, 1.0]));
+
+        assert_eq!(Luma([7u16]).lll(), Rgb([7, 7, 7]));
+    }
+
+    #[test]
+    fn test_components() {
+        let rgb = Rgb([1u8, 2, 3]);
+        assert_eq!(rgb.swizzle_dyn([2, 0, 1]), Rgb([3, 1, 2]));
+        assert_eq!(
+            Permutation::new([1, 0, 2]).unwrap().apply(&rgb),
+            Rgb([2, 1, 3])
+        );
+        assert_eq!(
+            crate::algo::reverse(&Rgba([1u8, 2, 3, 4])),
+            Rgba([4, 3, 2, 1])
+        );
+    }
+
+    #[test]
+    fn test_swizzle_pixels() {
+        let mut image = RgbImage::from_fn(3, 2, |x, y| Rgb([x as u8, y as u8, 9]));
+        swizzle_pixels(&mut image, [2, 0, 1]);
+        assert_eq!(image.get_pixel(2, 1), &Rgb([9, 2, 1]));
+
+        let gray: GrayImage = swizzle_pixels_to(&image, [1]);
+        assert_eq!(gray.get_pixel(2, 0), &Luma([2]));
+        let rgba: ImageBuffer<Rgba<u8>, _> = swizzle_pixels_to(&gray, [0, 0, 0, 0]);
+        assert_eq!(rgba.get_pixel(1, 1), &Rgba([1, 1, 1, 1]));
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_swizzle_pixels_out_of_range() {
+        let mut image = RgbImage::new(1, 1);
+        swizzle_pixels(&mut image, [0, 1, 3]);
+    }
+}
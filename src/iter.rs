@@ -0,0 +1,144 @@
+use crate::{Components, SwizzlePlan};
+use core::iter::FusedIterator;
+
+/// Iterator adapter swizzling the items of an iterator over references, e.g. `pixels.iter()`, so
+/// the reorder can be fused into an iterator chain rather than written to an intermediate buffer.
+///
+/// Implemented for every iterator. The swizzle is given as a [`SwizzlePlan`], or anything that
+/// converts into one, such as a [`Permutation`](crate::Permutation) or a pattern type generated
+/// by `swizzle_dyn!`, and is validated once rather than for every item.
+///
+/// ```rust
+/// use swizzle::{IterSwizzle, swizzle_dyn};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// swizzle_dyn!(Rgba: u8 { r, g, b, a });
+///
+/// let pixels = [Rgba { r: 1, g: 2, b: 3, a: 4 }, Rgba { r: 5, g: 6, b: 7, a: 255 }];
+/// let to_bgra = RgbaPattern::parse("bgra").unwrap();
+///
+/// let opaque_bgra: Vec<Rgba> = pixels.iter().swizzled(to_bgra).filter(|p| p.a == 255).collect();
+/// assert_eq!(opaque_bgra, [Rgba { r: 7, g: 6, b: 5, a: 255 }]);
+/// ```
+pub trait IterSwizzle: Iterator + Sized {
+    /// Returns an iterator yielding each item swizzled by `plan`, as it is reached.
+    fn swizzled<P, const N: usize>(self, plan: P) -> Swizzled<Self, N>
+    where
+        P: Into<SwizzlePlan<N>>,
+    {
+        Swizzled {
+            iter: self,
+            plan: plan.into(),
+        }
+    }
+}
+
+impl<I: Iterator> IterSwizzle for I {}
+
+/// Iterator yielding the items of another iterator swizzled, returned by
+/// [`IterSwizzle::swizzled`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Swizzled<I, const N: usize> {
+    iter: I,
+    plan: SwizzlePlan<N>,
+}
+
+impl<'a, I, T, const N: usize> Iterator for Swizzled<I, N>
+where
+    I: Iterator<Item = &'a T>,
+    T: Components<N> + 'a,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|item| self.plan.apply(item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, T, const N: usize> DoubleEndedIterator for Swizzled<I, N>
+where
+    I: DoubleEndedIterator<Item = &'a T>,
+    T: Components<N> + 'a,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|item| self.plan.apply(item))
+    }
+}
+
+impl<'a, I, T, const N: usize> ExactSizeIterator for Swizzled<I, N>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+    T: Components<N> + 'a,
+{
+}
+
+impl<'a, I, T, const N: usize> FusedIterator for Swizzled<I, N>
+where
+    I: FusedIterator<Item = &'a T>,
+    T: Components<N> + 'a,
+{
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Permutation, swizzle_dyn};
+
+    #[derive(Debug, PartialEq)]
+    struct Vec3 {
+        x: i32,
+        y: i32,
+        z: i32,
+    }
+
+    swizzle_dyn!(Vec3: i32 { x, y, z });
+
+    const ITEMS: [Vec3; 3] = [
+        Vec3 { x: 1, y: 2, z: 3 },
+        Vec3 { x: 4, y: 5, z: 6 },
+        Vec3 { x: 7, y: 8, z: 9 },
+    ];
+
+    #[test]
+    fn test_swizzled() {
+        let plan: SwizzlePlan<3> = "zzx".parse().unwrap();
+        let mut iter = ITEMS.iter().swizzled(plan);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Vec3 { x: 3, y: 3, z: 1 }));
+        assert_eq!(iter.next_back(), Some(Vec3 { x: 9, y: 9, z: 7 }));
+        assert_eq!(iter.next(), Some(Vec3 { x: 6, y: 6, z: 4 }));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_swizzled_conversions() {
+        let rotate = Permutation::new([1, 2, 0]).unwrap();
+        let pattern = Vec3Pattern::parse("yzx").unwrap();
+        assert!(
+            ITEMS
+                .iter()
+                .swizzled(rotate)
+                .eq(ITEMS.iter().swizzled(pattern))
+        );
+        assert_eq!(
+            ITEMS.iter().swizzled(rotate).map(|v| v.x).sum::<i32>(),
+            2 + 5 + 8
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Named swizzles of the 2D points and vectors of `kurbo`.
+
+use crate::Components;
+use kurbo::{Point, Vec2};
+
+macro_rules! impl_components {
+    ($( $type:ident ),+) => {
+        $(
+            impl Components<2> for $type {
+                type Component = f64;
+
+                #[inline]
+                fn get(&self, i: usize) -> f64 {
+                    match i {
+                        0 => self.x,
+                        1 => self.y,
+                        _ => panic!("swizzle index out of range"),
+                    }
+                }
+
+                #[inline]
+                fn from_fn(mut f: impl FnMut(usize) -> f64) -> Self {
+                    Self::new(f(0), f(1))
+                }
+            }
+
+            impl KurboSwizzle for $type {}
+        )+
+    };
+}
+
+/// Named swizzles of a [`kurbo`](https://crates.io/crates/kurbo) `Point` or `Vec2`, e.g. `p.yx()`
+/// to transpose it, and its coordinates by name.
+///
+/// ```rust
+/// use kurbo::{Point, Vec2};
+/// use swizzle::KurboSwizzle;
+///
+/// assert_eq!(Point::new(1.0, 2.0).yx(), Point::new(2.0, 1.0));
+/// assert_eq!(Vec2::new(1.0, 2.0).xx(), Vec2::new(1.0, 1.0));
+/// assert_eq!(Point::new(1.0, 2.0).y(), 2.0);
+/// ```
+pub trait KurboSwizzle: Components<2, Component = f64> {
+    /// Returns the `x` coordinate.
+    #[must_use]
+    #[inline]
+    fn x(&self) -> f64 {
+        self.get(0)
+    }
+
+    /// Returns the `y` coordinate.
+    #[must_use]
+    #[inline]
+    fn y(&self) -> f64 {
+        self.get(1)
+    }
+
+    /// Returns the value `(x, x)`.
+    #[must_use]
+    #[inline]
+    fn xx(&self) -> Self {
+        Self::from_fn(|_| self.get(0))
+    }
+
+    /// Returns the value `(x, y)`.
+    #[must_use]
+    #[inline]
+    fn xy(&self) -> Self {
+        Self::from_fn(|i| self.get(i))
+    }
+
+    /// Returns the value `(y, x)`, with the axes swapped.
+    #[must_use]
+    #[inline]
+    fn yx(&self) -> Self {
+        Self::from_fn(|i| self.get(1 - i))
+    }
+
+    /// Returns the value `(y, y)`.
+    #[must_use]
+    #[inline]
+    fn yy(&self) -> Self {
+        Self::from_fn(|_| self.get(1))
+    }
+}
+
+impl_components!(Point, Vec2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SwizzleDyn;
+
+    #[test]
+    fn test_named_swizzles() {
+        let p = Point::new(1.0, 2.0);
+        assert_eq!((p.x(), p.y()), (1.0, 2.0));
+        assert_eq!(p.xy(), p);
+        assert_eq!(p.yx(), Point::new(2.0, 1.0));
+        assert_eq!(p.yy(), Point::new(2.0, 2.0));
+
+        let v = Vec2::new(3.0, 4.0);
+        assert_eq!(v.yx(), Vec2::new(4.0, 3.0));
+        assert_eq!(v.xx(), Vec2::new(3.0, 3.0));
+        assert_eq!(v.swizzle_dyn([1, 1]), v.yy());
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_get_out_of_range() {
+        let _ = Point::ZERO.get(2);
+    }
+}
@@ -0,0 +1,295 @@
+/// Macro for generating property tests of the laws of the swizzles of a struct, with `proptest`.
+///
+/// Requires the `proptest` feature. Given the same spec as a `swizzle_dyn!` invocation, the macro
+/// generates a `#[cfg(test)]` module of property tests checking, for random values of the struct:
+///
+/// - `inverse_undoes_permutation` → applying any permutation and then its `inverse()` returns the
+///   original value
+/// - `then_composes` → the pattern `a.then(b)`, which `swizzle_compose!` evaluates, swizzles a value
+///   the same as applying `a` and then `b`
+/// - `named_swizzles_match_swizzle_dyn` → each function generated by `swizzle!` returns the same
+///   value as the runtime swizzle by its name
+///
+/// The struct must be declared to both `swizzle_dyn!` and a `table` self-swizzle of `swizzle!`,
+/// and be `PartialEq` and `Debug`. The fields are generated by `any`, or by the strategy given
+/// after the fields. Values are compared with `==`, so give float fields a strategy without `NaN`.
+///
+/// ```rust
+/// # #[cfg(feature = "proptest")] {
+/// use swizzle::{swizzle, swizzle_dyn, swizzle_proptest};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// impl Vec3 {
+///     swizzle!(table Vec3 { x, y, z });
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// swizzle_proptest!(mod vec3_laws, Vec3: f32 { x, y, z }, -1.0e6f32..1.0e6);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! swizzle_proptest {
+    (
+        mod $name:ident, $type:ident: $component:ty {
+            $( $attr:ident ),+
+            $(,)?
+        }
+        $( , $strategy:expr )?
+        $(,)?
+    ) => { $crate::__private::paste! {
+        $crate::__swizzle_proptest! {
+            #[cfg(test)]
+            mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $crate::__private::proptest::proptest! {
+                    #[test]
+                    fn inverse_undoes_permutation(
+                        value in $crate::swizzle_proptest!(@value $type: $component $(, $strategy )?),
+                        pattern in $crate::__private::select_patterns([<$type Pattern>]::permutations()),
+                    ) {
+                        let inverse = pattern.inverse().unwrap();
+                        $crate::__private::proptest::prop_assert_eq!(
+                            inverse.apply(&pattern.apply(&value)),
+                            value
+                        );
+                    }
+
+                    #[test]
+                    fn then_composes(
+                        value in $crate::swizzle_proptest!(@value $type: $component $(, $strategy )?),
+                        first in $crate::__private::select_patterns([<$type Pattern>]::all()),
+                        second in $crate::__private::select_patterns([<$type Pattern>]::all()),
+                    ) {
+                        $crate::__private::proptest::prop_assert_eq!(
+                            first.then(second).apply(&value),
+                            second.apply(&first.apply(&value))
+                        );
+                    }
+
+                    #[test]
+                    fn named_swizzles_match_swizzle_dyn(
+                        value in $crate::swizzle_proptest!(@value $type: $component $(, $strategy )?),
+                    ) {
+                        for (name, swizzle) in $type::SWIZZLE_FNS {
+                            $crate::__private::proptest::prop_assert_eq!(
+                                ::core::option::Option::Some(swizzle(&value)),
+                                value.swizzle_str(name),
+                                "`{}` differs from `swizzle_str`",
+                                name
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }};
+    (@value $type:ident: $component:ty) => {
+        $crate::swizzle_proptest!(
+            @value $type: $component, $crate::__private::proptest::arbitrary::any::<$component>()
+        )
+    };
+    (@value $type:ident: $component:ty, $strategy:expr) => {
+        $crate::__private::proptest::strategy::Strategy::prop_map(
+            $crate::__private::proptest::array::uniform($strategy),
+            $type::from_array,
+        )
+    };
+}
+
+/// Macro for checking that a swizzle function undoes itself, such as `yx` of a 2D vector.
+///
+/// Given the function as `Type::name`, the check is done at compile time with the pattern type
+/// generated by `swizzle_dyn!` for the type, which must be declared to it, so applying a swizzle
+/// that isn't its own inverse twice fails to compile. Given a value as well, the check is done at
+/// runtime instead, by applying the function twice, for types that aren't declared to
+/// `swizzle_dyn!`, e.g. in a test. The value must be `PartialEq` and `Debug`.
+///
+/// ```rust
+/// use swizzle::{assert_involution, swizzle, swizzle_dyn};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// impl Vec3 {
+///     swizzle!(Vec3 { x, y, z });
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// assert_involution!(Vec3::zyx);
+/// assert_involution!(Vec3::xzy, Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+/// ```
+///
+/// ```compile_fail
+/// # use swizzle::{assert_involution, swizzle, swizzle_dyn};
+/// # struct Vec3 { x: f32, y: f32, z: f32 }
+/// # impl Vec3 { swizzle!(Vec3 { x, y, z }); }
+/// # swizzle_dyn!(Vec3: f32 { x, y, z });
+/// // Rotating twice doesn't return the original order.
+/// assert_involution!(Vec3::yzx);
+/// ```
+#[macro_export]
+macro_rules! assert_involution {
+    ($type:ident :: $name:ident $(,)?) => {
+        $crate::assert_inverse!($type::$name, $type::$name);
+    };
+    ($type:ident :: $name:ident, $value:expr $(,)?) => {
+        $crate::assert_inverse!($type::$name, $type::$name, $value);
+    };
+}
+
+/// Macro for checking that two swizzle functions undo each other, such as `zxy` and `yzx` of a 3D
+/// vector.
+///
+/// Given the functions as `Type::name`, the check is done at compile time with the pattern type
+/// generated by `swizzle_dyn!` for the type, which must be declared to it, so a wrong inverse
+/// fails to compile. Given a value as well, the check is done at runtime instead, by applying the
+/// functions one after the other in both orders, for types that aren't declared to
+/// `swizzle_dyn!`, e.g. in a test. The value must be `PartialEq` and `Debug`.
+///
+/// ```rust
+/// use swizzle::{assert_inverse, swizzle, swizzle_dyn};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// impl Vec3 {
+///     swizzle!(Vec3 { x, y, z });
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// assert_inverse!(Vec3::zxy, Vec3::yzx);
+/// assert_inverse!(Vec3::zxy, Vec3::yzx, Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+/// ```
+///
+/// ```compile_fail
+/// # use swizzle::{assert_inverse, swizzle, swizzle_dyn};
+/// # struct Vec3 { x: f32, y: f32, z: f32 }
+/// # impl Vec3 { swizzle!(Vec3 { x, y, z }); }
+/// # swizzle_dyn!(Vec3: f32 { x, y, z });
+/// // A rotation isn't undone by itself.
+/// assert_inverse!(Vec3::zxy, Vec3::zxy);
+/// ```
+#[macro_export]
+macro_rules! assert_inverse {
+    ($type:ident :: $first:ident, $other:ident :: $second:ident $(,)?) => {
+        const _: () = $crate::__private::paste! {{
+            let _: fn(&$type) -> $type = $type::$first;
+            let _: fn(&$other) -> $other = $other::$second;
+            let first = $crate::swizzle_compose!(@parse [<$type Pattern>] $first);
+            let second = $crate::swizzle_compose!(@parse [<$other Pattern>] $second);
+            assert!(
+                first.then(second).is_identity() && second.then(first).is_identity(),
+                concat!(
+                    "`", stringify!($type), "::", stringify!($first), "` and `",
+                    stringify!($other), "::", stringify!($second), "` are not inverses"
+                )
+            );
+        }};
+    };
+    ($type:ident :: $first:ident, $other:ident :: $second:ident, $value:expr $(,)?) => {{
+        let value: &$type = &$value;
+        assert_eq!(
+            &$other::$second(&$type::$first(value)),
+            value,
+            concat!(
+                "`",
+                stringify!($second),
+                "` doesn't undo `",
+                stringify!($first),
+                "`"
+            )
+        );
+        assert_eq!(
+            &$type::$first(&$other::$second(value)),
+            value,
+            concat!(
+                "`",
+                stringify!($first),
+                "` doesn't undo `",
+                stringify!($second),
+                "`"
+            )
+        );
+    }};
+}
+
+/// The strategy choosing one of `patterns`, for `swizzle_proptest!`.
+#[cfg(feature = "proptest")]
+pub fn select_patterns<P: Clone + core::fmt::Debug + 'static>(
+    patterns: impl Iterator<Item = P>,
+) -> proptest::sample::Select<P> {
+    proptest::sample::select(patterns.collect::<alloc::vec::Vec<_>>())
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use crate::{swizzle, swizzle_dyn};
+
+    #[derive(Debug, PartialEq)]
+    struct Rgba {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    impl Rgba {
+        swizzle!(table Rgba { r, g, b, a });
+    }
+
+    swizzle_dyn!(Rgba: u8 { r, g, b, a });
+
+    #[derive(Debug, PartialEq)]
+    struct Vec2 {
+        x: f64,
+        y: f64,
+    }
+
+    impl Vec2 {
+        swizzle!(table Vec2 { x, y });
+    }
+
+    swizzle_dyn!(Vec2: f64 { x, y });
+
+    #[cfg(feature = "proptest")]
+    swizzle_proptest!(mod rgba_laws, Rgba: u8 { r, g, b, a });
+    #[cfg(feature = "proptest")]
+    swizzle_proptest!(mod vec2_laws, Vec2: f64 { x, y }, -1.0f64..1.0);
+
+    assert_involution!(Vec2::yx);
+    assert_involution!(Rgba::bgra);
+    assert_inverse!(Rgba::gbra, Rgba::brga);
+
+    #[test]
+    fn test_assert_inverse() {
+        let pixel = Rgba {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 4,
+        };
+        assert_involution!(Rgba::abgr, pixel);
+        assert_inverse!(Rgba::argb, Rgba::gbar, pixel);
+        assert_inverse!(Rgba::argb, Rgba::gbar);
+    }
+
+    #[test]
+    #[should_panic = "`bgra` doesn't undo `argb`"]
+    fn test_assert_inverse_wrong() {
+        let pixel = Rgba {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 4,
+        };
+        assert_inverse!(Rgba::argb, Rgba::bgra, pixel);
+    }
+}
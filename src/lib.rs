@@ -1,4 +1,6 @@
 #![no_std]
+// The SIMD swizzles are expanded in the invoking crate, which enables the feature itself.
+#![cfg_attr(all(test, feature = "portable-simd"), feature(portable_simd))]
 
 //! # Swizzle
 //!
@@ -221,7 +223,7 @@
 //!
 //! - Field names must be valid Rust identifiers
 //! - All fields must be of types that can be copied
-//! - The macro generates a lot of functions for structs with many fields
+//! - The macro generates a lot of functions for structs with many fields. Self-swizzles of more than 5 fields are rejected unless prefixed with `max_fields = N`, for `N` from 1 to 6, to set another limit, or `unbounded` to remove it
 //! - Field order in the struct definition matters for the generated function names
 //! - Cross-type swizzling requires compatible field types that are either the same or that can be converted implicitly
 //!
@@ -233,4 +235,266 @@
 //!
 //! This project is licensed under the MIT License
 
+#[cfg(any(feature = "image", feature = "proptest"))]
+extern crate alloc;
+
+pub mod algo;
+pub mod arch;
+mod arity;
+pub mod bits;
+mod bytes;
+pub mod channels;
+#[cfg(feature = "colors")]
+mod colors;
+mod components;
+mod consts;
+mod dynamic;
+mod error;
+mod expr;
+pub mod ffi;
+#[cfg(feature = "formats")]
+pub mod formats;
+#[cfg(feature = "gl")]
+pub mod gl;
+#[cfg(feature = "image")]
+pub mod image;
+mod iter;
+#[cfg(feature = "kurbo")]
+mod kurbo;
+mod laws;
+mod narrow;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+mod normalize;
+pub mod packed;
+mod pattern;
+mod permutation;
+mod plan;
+#[cfg(any(feature = "vectors", feature = "colors"))]
+mod prebuilt;
+mod presets;
+pub mod reorder;
+#[cfg(feature = "sdl2")]
+pub mod sdl2;
+pub mod shader;
+mod soa;
 mod swizzle;
+mod testing;
+pub mod tiled;
+#[cfg(feature = "vector")]
+mod vector;
+#[cfg(feature = "vectors")]
+mod vectors;
+#[allow(dead_code)]
+#[cfg(all(kani, verify))]
+mod verify;
+#[cfg(feature = "vulkan")]
+pub mod vulkan;
+#[cfg(feature = "wide")]
+mod wide;
+
+pub use arity::{Swizzle1, Swizzle2, Swizzle3, Swizzle4};
+#[cfg(feature = "colors")]
+pub use colors::{Hsl, Hsv, Rgb, Rgba};
+pub use components::Components;
+pub use error::SwizzleError;
+pub use iter::{IterSwizzle, Swizzled};
+#[cfg(feature = "kurbo")]
+pub use kurbo::KurboSwizzle;
+pub use narrow::Narrow;
+pub use normalize::{Normalize, Rounding};
+pub use pattern::{PermutationIter, SwizzlePattern};
+pub use permutation::{DynSwizzle, Permutation, SwizzleDyn};
+pub use plan::SwizzlePlan;
+#[cfg(feature = "vector")]
+pub use vector::Vector;
+#[cfg(feature = "vectors")]
+pub use vectors::{Vec2, Vec3, Vec4};
+#[cfg(feature = "wide")]
+pub use wide::WideSwizzle;
+
+// Re-exports used by the macro expansions, so that they don't require the caller to depend on
+// the same crates.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::arch::Shuffle4;
+    pub use crate::bytes::byte_pattern;
+    #[cfg(feature = "proptest")]
+    pub use crate::laws::select_patterns;
+    pub use crate::permutation::{inverse_table, sign_table};
+    pub use paste::paste;
+
+    /// Strips `prefix` from the start of `s`, in const contexts.
+    #[must_use]
+    pub const fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+        if s.len() < prefix.len() {
+            return None;
+        }
+        let (head, tail) = s.split_at(prefix.len());
+        let mut i = 0;
+        while i < head.len() {
+            if head[i] != prefix[i] {
+                return None;
+            }
+            i += 1;
+        }
+        Some(tail)
+    }
+
+    /// Clamps `value` to `range` and converts it to `U`, for the `clamped` conversions of
+    /// `swizzle_color_orders!`. Unordered values, such as `NaN`, are passed through.
+    #[must_use]
+    #[inline]
+    pub fn clamp<T: PartialOrd + Copy, U: From<T>>(
+        value: T,
+        range: &core::ops::RangeInclusive<T>,
+    ) -> U {
+        if value < *range.start() {
+            U::from(*range.start())
+        } else if value > *range.end() {
+            U::from(*range.end())
+        } else {
+            U::from(value)
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "wasm-bindgen")]
+    pub use wasm_bindgen;
+
+    /// Deserializes a type from its string form, using its `FromStr` implementation.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: core::str::FromStr<Err = crate::SwizzleError>,
+        D: serde::Deserializer<'de>,
+    {
+        struct FromStrVisitor<T>(core::marker::PhantomData<T>);
+
+        impl<T: core::str::FromStr<Err = crate::SwizzleError>> serde::de::Visitor<'_>
+            for FromStrVisitor<T>
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a string of swizzle field names")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(FromStrVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Expands to its input when the `rand` feature is enabled, and to nothing otherwise.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_rand {
+    ($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_rand {
+    ($($item:item)*) => {};
+}
+
+/// Expands to its input when the `serde` feature is enabled, and to nothing otherwise. The
+/// feature must be checked here, as a `cfg` in a macro expansion checks the caller's features.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_serde {
+    ($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_serde {
+    ($($item:item)*) => {};
+}
+
+/// Expands to its input when the `bytemuck` feature is enabled, and to an error otherwise, as it is
+/// only used when views are requested explicitly.
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_bytemuck {
+    ($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_bytemuck {
+    ($($item:item)*) => {
+        compile_error!("the `pod` prefix of `swizzle_dyn!` requires the `bytemuck` feature");
+    };
+}
+
+/// Expands to its input when the `wasm-bindgen` feature is enabled, and to an error otherwise, as it
+/// is only used when JavaScript bindings are requested explicitly.
+#[cfg(feature = "wasm-bindgen")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_wasm_bindgen {
+    ($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "wasm-bindgen"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_wasm_bindgen {
+    ($($item:item)*) => {
+        compile_error!("the `wasm` prefix of `swizzle!` requires the `wasm-bindgen` feature");
+    };
+}
+
+/// Expands to its input when the `proptest` feature is enabled, and to an error otherwise, as it is
+/// only used when property tests are requested explicitly.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_proptest {
+    ($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_proptest {
+    ($($item:item)*) => {
+        compile_error!("`swizzle_proptest!` requires the `proptest` feature");
+    };
+}
+
+/// Expands to its input when the `portable-simd` feature is enabled, and to an error otherwise, as
+/// it is only used when SIMD swizzles are requested explicitly.
+#[cfg(feature = "portable-simd")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_simd {
+    ($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "portable-simd"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __swizzle_simd {
+    ($($item:item)*) => {
+        compile_error!("the `simd` prefix of `swizzle_dyn!` requires the `portable-simd` feature");
+    };
+}
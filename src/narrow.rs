@@ -0,0 +1,252 @@
+/// Explicit narrowing between integer types, used by the `saturating` and `wrapping` conversions
+/// of `swizzle_color_orders!`.
+///
+/// An `as` cast silently wraps values that don't fit, e.g. `300u16 as u8` is `44`, which in an
+/// image shows up as dark speckles in bright areas. `saturate` instead clamps to the range of the
+/// narrower type, which is what image pipelines almost always want, and `wrap` keeps the bits that
+/// fit, like `as`, for when that is intended.
+///
+/// With the `half` feature enabled, `f32` and `f64` also narrow to `half::f16` with
+/// `f16::from_f32` and `f16::from_f64`, and `f16` widens back with `to_f32` and `to_f64`.
+/// `saturate` clamps values beyond the range of `f16` to `f16::MAX` or `f16::MIN`, and `wrap`
+/// rounds them to infinity, like `as` between floats.
+///
+/// With the `fixed` feature enabled, the integers, `f32`, and `f64` also convert to and from the
+/// fixed-point numbers of the `fixed` crate, such as `I16F16`, with `saturating_from_num` and
+/// `saturating_to_num`, or `wrapping_from_num` and `wrapping_to_num`, which never panic on values
+/// out of range, unlike `from_num` and `to_num`. Floats that have no fixed-point value are
+/// handled first, as they would panic: `NaN` converts to zero, and infinities to the maximum or
+/// minimum with both `saturate` and `wrap`.
+///
+/// ```rust
+/// use swizzle::Narrow;
+///
+/// assert_eq!(Narrow::<u8>::saturate(300u16), 255);
+/// assert_eq!(Narrow::<u8>::saturate(-5i16), 0);
+/// assert_eq!(Narrow::<i8>::saturate(-300i32), -128);
+/// assert_eq!(Narrow::<u8>::wrap(300u16), 44);
+/// ```
+pub trait Narrow<T>: Copy {
+    /// Convert the value to `T`, clamping it to the range of `T`.
+    #[must_use]
+    fn saturate(self) -> T;
+
+    /// Convert the value to `T`, keeping the low bits that fit, like `as`.
+    #[must_use]
+    fn wrap(self) -> T;
+}
+
+macro_rules! impl_narrow {
+    ($( $src:ty ),+) => {
+        $( impl_narrow!(@src $src: u8, u16, u32, u64, i8, i16, i32, i64); )+
+    };
+    (@src $src:ty: $( $dst:ty ),+) => {
+        $(
+            impl Narrow<$dst> for $src {
+                #[inline]
+                fn saturate(self) -> $dst {
+                    <$dst>::try_from(self).unwrap_or(if self > 0 { <$dst>::MAX } else { <$dst>::MIN })
+                }
+
+                #[inline]
+                fn wrap(self) -> $dst {
+                    self as $dst
+                }
+            }
+        )+
+    };
+}
+
+impl_narrow!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+#[cfg(feature = "half")]
+macro_rules! impl_narrow_f16 {
+    ($( $float:ty: $from:ident, $to:ident; )+) => {
+        $(
+            impl Narrow<half::f16> for $float {
+                #[inline]
+                fn saturate(self) -> half::f16 {
+                    let max = half::f16::MAX.$to();
+                    half::f16::$from(if self > max {
+                        max
+                    } else if self < -max {
+                        -max
+                    } else {
+                        self
+                    })
+                }
+
+                #[inline]
+                fn wrap(self) -> half::f16 {
+                    half::f16::$from(self)
+                }
+            }
+
+            impl Narrow<$float> for half::f16 {
+                #[inline]
+                fn saturate(self) -> $float {
+                    self.$to()
+                }
+
+                #[inline]
+                fn wrap(self) -> $float {
+                    self.$to()
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "fixed")]
+macro_rules! impl_narrow_fixed {
+    ($( $fixed:ident: $frac:ident ),+) => {
+        $(
+            impl_narrow_fixed!(@int $fixed: $frac; u8, u16, u32, u64, i8, i16, i32, i64);
+            impl_narrow_fixed!(@float $fixed: $frac; f32, f64);
+        )+
+    };
+    (@int $fixed:ident: $frac:ident; $( $num:ty ),+) => {
+        $(
+            impl<F: fixed::types::extra::$frac> Narrow<fixed::$fixed<F>> for $num {
+                #[inline]
+                fn saturate(self) -> fixed::$fixed<F> {
+                    fixed::$fixed::saturating_from_num(self)
+                }
+
+                #[inline]
+                fn wrap(self) -> fixed::$fixed<F> {
+                    fixed::$fixed::wrapping_from_num(self)
+                }
+            }
+
+            impl_narrow_fixed!(@to $fixed: $frac; $num);
+        )+
+    };
+    (@float $fixed:ident: $frac:ident; $( $num:ty ),+) => {
+        $(
+            impl<F: fixed::types::extra::$frac> Narrow<fixed::$fixed<F>> for $num {
+                #[inline]
+                fn saturate(self) -> fixed::$fixed<F> {
+                    if self.is_nan() {
+                        fixed::$fixed::ZERO
+                    } else {
+                        fixed::$fixed::saturating_from_num(self)
+                    }
+                }
+
+                #[inline]
+                fn wrap(self) -> fixed::$fixed<F> {
+                    if self.is_nan() {
+                        fixed::$fixed::ZERO
+                    } else if self.is_infinite() {
+                        fixed::$fixed::saturating_from_num(self)
+                    } else {
+                        fixed::$fixed::wrapping_from_num(self)
+                    }
+                }
+            }
+
+            impl_narrow_fixed!(@to $fixed: $frac; $num);
+        )+
+    };
+    (@to $fixed:ident: $frac:ident; $num:ty) => {
+        impl<F: fixed::types::extra::$frac> Narrow<$num> for fixed::$fixed<F> {
+            #[inline]
+            fn saturate(self) -> $num {
+                self.saturating_to_num()
+            }
+
+            #[inline]
+            fn wrap(self) -> $num {
+                self.wrapping_to_num()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_narrow_fixed!(
+    FixedU8: LeEqU8,
+    FixedU16: LeEqU16,
+    FixedU32: LeEqU32,
+    FixedU64: LeEqU64,
+    FixedI8: LeEqU8,
+    FixedI16: LeEqU16,
+    FixedI32: LeEqU32,
+    FixedI64: LeEqU64
+);
+
+#[cfg(feature = "half")]
+impl_narrow_f16! {
+    f32: from_f32, to_f32;
+    f64: from_f64, to_f64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturate() {
+        assert_eq!(Narrow::<u8>::saturate(255u16), 255);
+        assert_eq!(Narrow::<u8>::saturate(256u16), 255);
+        assert_eq!(Narrow::<u8>::saturate(i32::MIN), 0);
+        assert_eq!(Narrow::<i8>::saturate(200u8), 127);
+        assert_eq!(Narrow::<i16>::saturate(-40_000i64), i16::MIN);
+        assert_eq!(Narrow::<u16>::saturate(-1i8), 0);
+        assert_eq!(Narrow::<u64>::saturate(7u8), 7);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(Narrow::<u8>::wrap(0x1234u16), 0x34);
+        assert_eq!(Narrow::<u8>::wrap(-1i16), 255);
+        assert_eq!(Narrow::<i8>::wrap(200u8), -56);
+        assert_eq!(Narrow::<u32>::wrap(-1i8), u32::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_narrow_f16() {
+        use half::f16;
+
+        assert_eq!(Narrow::<f16>::saturate(0.5f32), f16::from_f32(0.5));
+        assert_eq!(Narrow::<f16>::saturate(1e6f32), f16::MAX);
+        assert_eq!(Narrow::<f16>::saturate(-1e6f64), f16::MIN);
+        assert!(Narrow::<f16>::saturate(f32::NAN).is_nan());
+        assert_eq!(Narrow::<f16>::wrap(1e6f32), f16::INFINITY);
+        assert_eq!(Narrow::<f16>::wrap(-1e6f64), f16::NEG_INFINITY);
+        assert_eq!(Narrow::<f32>::saturate(f16::from_f32(0.25)), 0.25);
+        assert_eq!(Narrow::<f64>::wrap(f16::MAX), 65504.0);
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn test_narrow_fixed() {
+        use fixed::types::{I8F8, I16F16, U0F8};
+
+        assert_eq!(Narrow::<I16F16>::saturate(1.5f32), I16F16::from_num(1.5));
+        assert_eq!(Narrow::<I8F8>::saturate(1000.0f64), I8F8::MAX);
+        assert_eq!(Narrow::<I8F8>::saturate(-200i32), I8F8::MIN);
+        assert_eq!(Narrow::<U0F8>::saturate(-0.5f32), U0F8::ZERO);
+        assert_eq!(Narrow::<I8F8>::wrap(129u8), I8F8::from_num(-127));
+        assert_eq!(Narrow::<f32>::saturate(I16F16::from_num(-2.25)), -2.25);
+        assert_eq!(Narrow::<u8>::saturate(I16F16::from_num(300)), 255);
+        assert_eq!(Narrow::<u8>::wrap(I16F16::from_num(300)), 44);
+        assert_eq!(Narrow::<i8>::saturate(I8F8::from_num(-1.5)), -2);
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn test_narrow_fixed_non_finite() {
+        use fixed::types::{I16F16, U0F8};
+
+        assert_eq!(Narrow::<I16F16>::saturate(f32::NAN), I16F16::ZERO);
+        assert_eq!(Narrow::<I16F16>::wrap(f64::NAN), I16F16::ZERO);
+        assert_eq!(Narrow::<I16F16>::saturate(f32::INFINITY), I16F16::MAX);
+        assert_eq!(Narrow::<I16F16>::saturate(f64::NEG_INFINITY), I16F16::MIN);
+        assert_eq!(Narrow::<I16F16>::wrap(f32::INFINITY), I16F16::MAX);
+        assert_eq!(Narrow::<I16F16>::wrap(f64::NEG_INFINITY), I16F16::MIN);
+        assert_eq!(Narrow::<U0F8>::wrap(f32::NEG_INFINITY), U0F8::ZERO);
+    }
+}
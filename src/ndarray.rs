@@ -0,0 +1,53 @@
+//! Permutations of the axes of [`ndarray`](https://crates.io/crates/ndarray) arrays.
+//!
+//! ```rust
+//! use ndarray::Array3;
+//! use swizzle::Permutation;
+//! use swizzle::ndarray::permute_axes;
+//!
+//! let volume = Array3::<f32>::zeros((2, 3, 4));
+//! let zyx: Permutation<3> = "zyx".parse().unwrap();
+//! assert_eq!(permute_axes(volume, zyx).shape(), &[4, 3, 2]);
+//! ```
+
+use crate::Permutation;
+use ndarray::{ArrayBase, Dim, Dimension, IntoDimension, Ix, RawData};
+
+/// Permute the axes of `array`, where the axis at each position is taken from the axis of `array`
+/// at the permutation's index, e.g. `"zyx"` to reverse the axes of a 3D array. Like
+/// `permuted_axes`, this only adjusts the shape and strides of the array, without moving the data.
+///
+/// As the permutation is validated, and has as many indices as the array has axes, this can't
+/// panic.
+#[must_use]
+pub fn permute_axes<S, const N: usize>(
+    array: ArrayBase<S, Dim<[Ix; N]>>,
+    permutation: Permutation<N>,
+) -> ArrayBase<S, Dim<[Ix; N]>>
+where
+    S: RawData,
+    Dim<[Ix; N]>: Dimension,
+    [Ix; N]: IntoDimension<Dim = Dim<[Ix; N]>>,
+{
+    array.permuted_axes(permutation.indices())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array2, Array3};
+
+    #[test]
+    fn test_permute_axes() {
+        let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+        let p = permute_axes(a.view(), "yzx".parse().unwrap());
+        assert_eq!(p.shape(), &[3, 4, 2]);
+        assert_eq!(p[[2, 3, 1]], 123);
+
+        let round_trip = permute_axes(p, Permutation::new([2, 0, 1]).unwrap());
+        assert_eq!(round_trip, a);
+
+        let m = Array2::from_shape_fn((2, 3), |(i, j)| i * 10 + j);
+        assert_eq!(permute_axes(m.view(), "yx".parse().unwrap()), m.t());
+    }
+}
@@ -0,0 +1,243 @@
+/// How floats are rounded when they are converted to integers.
+///
+/// Graphics APIs specify different quantization: Vulkan and Direct3D round `UNORM` conversions to
+/// the nearest integer, while some hardware and codecs truncate, so the rounding is chosen by each
+/// conversion rather than fixed by the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Round to the nearest integer, with halfway values rounded away from zero, like
+    /// `f32::round`.
+    #[default]
+    Round,
+    /// Round towards negative infinity, like `f32::floor`.
+    Floor,
+    /// Round towards positive infinity, like `f32::ceil`.
+    Ceil,
+    /// Round towards zero, like `f32::trunc` and `as` casts.
+    Trunc,
+}
+
+impl Rounding {
+    /// Round `value` to an integer, without the float functions of `std`. Truncation is exact, so
+    /// the fraction is too. Values of magnitude `2^52` or more have no fraction, so they are
+    /// returned unchanged rather than truncated through an `i64`, which would saturate.
+    #[must_use]
+    pub fn apply(self, value: f64) -> f64 {
+        const INTEGRAL: f64 = (1u64 << 52) as f64;
+        if value >= INTEGRAL || value <= -INTEGRAL {
+            return value;
+        }
+        let truncated = value as i64 as f64;
+        let fraction = value - truncated;
+        let step = match self {
+            Self::Round if fraction >= 0.5 => 1.0,
+            Self::Round if fraction <= -0.5 => -1.0,
+            Self::Floor if fraction < 0.0 => -1.0,
+            Self::Ceil if fraction > 0.0 => 1.0,
+            _ => 0.0,
+        };
+        truncated + step
+    }
+}
+
+/// Conversion between normalized integers and floats, used by the `normalized` conversions of
+/// `swizzle_color_orders!`.
+///
+/// Unsigned integers map their whole range to `[0, 1]`, e.g. `255u8` to `1.0`, and signed integers
+/// map `[-MAX, MAX]` to `[-1, 1]`, with `MIN` also mapping to `-1.0`, like the `UNORM` and `SNORM`
+/// formats of graphics APIs. Floats are clamped to that range and rounded to an integer by the
+/// [`Rounding`], to the nearest by default, and `NaN` converts to `0`. The rounding doesn't affect
+/// conversions to floats. With the `half` feature enabled, `half::f16` converts like `f32`, for
+/// half-precision vertex colors.
+///
+/// ```rust
+/// use swizzle::{Normalize, Rounding};
+///
+/// assert_eq!(Normalize::<f32>::normalize(255u8), 1.0);
+/// assert_eq!(Normalize::<f32>::normalize(-127i8), -1.0);
+/// assert_eq!(Normalize::<u8>::normalize(0.5f32), 128);
+/// assert_eq!(Normalize::<u8>::normalize_rounded(0.5f32, Rounding::Floor), 127);
+/// assert_eq!(Normalize::<u16>::normalize(2.0f32), u16::MAX);
+/// ```
+pub trait Normalize<T>: Copy {
+    /// Convert the value to the normalized range of `T`, rounding to the nearest integer.
+    #[must_use]
+    fn normalize(self) -> T {
+        self.normalize_rounded(Rounding::Round)
+    }
+
+    /// Convert the value to the normalized range of `T`, rounding to an integer by `rounding`.
+    #[must_use]
+    fn normalize_rounded(self, rounding: Rounding) -> T;
+}
+
+macro_rules! impl_normalize {
+    ($float:ty: $( $int:ty ),+) => {
+        $(
+            impl Normalize<$float> for $int {
+                #[inline]
+                fn normalize_rounded(self, _: Rounding) -> $float {
+                    (self as $float / <$int>::MAX as $float).max(-1.0)
+                }
+            }
+
+            impl Normalize<$int> for $float {
+                #[inline]
+                fn normalize_rounded(self, rounding: Rounding) -> $int {
+                    let min = if <$int>::MIN == 0 { 0.0 } else { -1.0 };
+                    rounding.apply(f64::from(self.clamp(min, 1.0)) * <$int>::MAX as f64) as $int
+                }
+            }
+        )+
+    };
+}
+
+impl_normalize!(f32: u8, u16, i8, i16);
+impl_normalize!(f64: u8, u16, i8, i16);
+
+#[cfg(feature = "half")]
+macro_rules! impl_normalize_f16 {
+    ($( $int:ty ),+) => {
+        $(
+            impl Normalize<half::f16> for $int {
+                #[inline]
+                fn normalize_rounded(self, rounding: Rounding) -> half::f16 {
+                    half::f16::from_f32(Normalize::<f32>::normalize_rounded(self, rounding))
+                }
+            }
+
+            impl Normalize<$int> for half::f16 {
+                #[inline]
+                fn normalize_rounded(self, rounding: Rounding) -> $int {
+                    self.to_f32().normalize_rounded(rounding)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "half")]
+impl_normalize_f16!(u8, u16, i8, i16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_unsigned() {
+        assert_eq!(Normalize::<f32>::normalize(0u8), 0.0);
+        assert_eq!(Normalize::<f32>::normalize(51u8), 0.2);
+        assert_eq!(Normalize::<f64>::normalize(u16::MAX), 1.0);
+
+        assert_eq!(Normalize::<u8>::normalize(0.2f32), 51);
+        assert_eq!(Normalize::<u8>::normalize(-1.0f32), 0);
+        assert_eq!(Normalize::<u8>::normalize(f32::NAN), 0);
+        assert_eq!(Normalize::<u16>::normalize(1.0f64), u16::MAX);
+
+        for value in 0..=u8::MAX {
+            let float: f32 = value.normalize();
+            assert_eq!(Normalize::<u8>::normalize(float), value);
+        }
+    }
+
+    #[test]
+    fn test_normalize_signed() {
+        assert_eq!(Normalize::<f32>::normalize(i8::MIN), -1.0);
+        assert_eq!(Normalize::<f32>::normalize(i16::MAX), 1.0);
+
+        assert_eq!(Normalize::<i8>::normalize(-1.0f32), -127);
+        assert_eq!(Normalize::<i8>::normalize(-0.5f32), -64);
+        assert_eq!(Normalize::<i16>::normalize(-2.0f64), -i16::MAX);
+
+        for value in -i8::MAX..=i8::MAX {
+            let float: f64 = value.normalize();
+            assert_eq!(Normalize::<i8>::normalize(float), value);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_normalize_f16() {
+        use half::f16;
+
+        assert_eq!(Normalize::<f16>::normalize(255u8), f16::ONE);
+        assert_eq!(Normalize::<f16>::normalize(i16::MIN), f16::NEG_ONE);
+        assert_eq!(Normalize::<u8>::normalize(f16::from_f32(0.5)), 128);
+        assert_eq!(
+            Normalize::<u8>::normalize_rounded(f16::from_f32(0.5), Rounding::Floor),
+            127
+        );
+        assert_eq!(Normalize::<i8>::normalize(f16::NAN), 0);
+
+        for value in 0..=u8::MAX {
+            let half: f16 = value.normalize();
+            assert_eq!(Normalize::<u8>::normalize(half), value);
+        }
+    }
+
+    #[test]
+    fn test_normalize_rounded() {
+        assert_eq!(
+            Normalize::<u8>::normalize_rounded(0.5f32, Rounding::Round),
+            128
+        );
+        assert_eq!(
+            Normalize::<u8>::normalize_rounded(0.5f32, Rounding::Floor),
+            127
+        );
+        assert_eq!(
+            Normalize::<u8>::normalize_rounded(0.5f32, Rounding::Ceil),
+            128
+        );
+        assert_eq!(
+            Normalize::<u8>::normalize_rounded(0.5f32, Rounding::Trunc),
+            127
+        );
+        assert_eq!(
+            Normalize::<i8>::normalize_rounded(-0.5f32, Rounding::Floor),
+            -64
+        );
+        assert_eq!(
+            Normalize::<i8>::normalize_rounded(-0.5f32, Rounding::Trunc),
+            -63
+        );
+        assert_eq!(
+            Normalize::<f32>::normalize_rounded(51u8, Rounding::Floor),
+            0.2
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(Rounding::default(), Rounding::Round);
+        for (value, round, floor, ceil, trunc) in [
+            (0.5, 1.0, 0.0, 1.0, 0.0),
+            (-0.5, -1.0, -1.0, -0.0, -0.0),
+            (0.499_999_999_999_999_94, 0.0, 0.0, 1.0, 0.0),
+            (2.4, 2.0, 2.0, 3.0, 2.0),
+            (-2.6, -3.0, -3.0, -2.0, -2.0),
+            (3.0, 3.0, 3.0, 3.0, 3.0),
+            (1e20, 1e20, 1e20, 1e20, 1e20),
+            (-1e20, -1e20, -1e20, -1e20, -1e20),
+            (
+                4_503_599_627_370_495.5,
+                4_503_599_627_370_496.0,
+                4_503_599_627_370_495.0,
+                4_503_599_627_370_496.0,
+                4_503_599_627_370_495.0,
+            ),
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::INFINITY,
+            ),
+        ] {
+            assert_eq!(Rounding::Round.apply(value), round);
+            assert_eq!(Rounding::Floor.apply(value), floor);
+            assert_eq!(Rounding::Ceil.apply(value), ceil);
+            assert_eq!(Rounding::Trunc.apply(value), trunc);
+        }
+    }
+}
@@ -0,0 +1,688 @@
+//! Swizzles of the channels of pixels packed into integers, with shifts and masks.
+//!
+//! The channels of pixels packed into a `u32` with 8 bits per channel are named from the least
+//! significant byte, so an `rgba8` pixel is `0xAABBGGRR`, which is the order of the bytes in memory
+//! on little-endian targets, the same as the channel orders of the `formats` feature's table. The
+//! channels are moved with shifts and masks, so there is no intermediate struct per pixel, and the
+//! functions are `const`.
+//!
+//! ```rust
+//! use swizzle::packed::{rgba8_to_bgra8, rgba8_to_bgra8_in_place, swizzle_u32};
+//!
+//! assert_eq!(rgba8_to_bgra8(0x4433_2211), 0x4411_2233);
+//! assert_eq!(swizzle_u32(0x4433_2211, [3, 0, 1, 2]), 0x3322_1144);
+//!
+//! let mut pixels = [0x4433_2211; 4];
+//! rgba8_to_bgra8_in_place(&mut pixels);
+//! assert_eq!(pixels, [0x4411_2233; 4]);
+//! ```
+//!
+//! # Layouts
+//!
+//! The same channel order names different `u32` values depending on whether it is read from the
+//! bytes in memory, such as a `&[u32]` cast from a byte buffer, or written as a number, such as
+//! `0xRRGGBBAA`. [`Layout`] converts between these and the layout of this module, with the
+//! endianness of the target taken into account.
+//!
+//! ```rust
+//! use swizzle::packed::{Layout, from_bytes, rgba8_to_bgra8};
+//!
+//! // RGBA as a number, e.g. `SDL_PIXELFORMAT_RGBA8888`, is `abgr8` here.
+//! let red = Layout::Msb.to_lsb(0xFF00_00FF);
+//! assert_eq!(red, from_bytes([0xFF, 0, 0, 0xFF]));
+//!
+//! // RGBA bytes in memory, loaded as native-endian `u32`s on any target.
+//! let loaded = u32::from_ne_bytes([0xFF, 0, 0, 0xFF]);
+//! let bgra = rgba8_to_bgra8(Layout::Memory.to_lsb(loaded));
+//! assert_eq!(Layout::Memory.from_lsb(bgra).to_ne_bytes(), [0, 0, 0xFF, 0xFF]);
+//! ```
+//!
+//! # 16-bit Formats
+//!
+//! Pixels packed into a `u16` are named from the most significant bits, as is conventional for
+//! them, so an `rgb565` pixel is `0bRRRRRGGGGGGBBBBB`, the same as `VK_FORMAT_R5G6B5_UNORM_PACK16`
+//! and `SDL_PIXELFORMAT_RGB565`. They are unpacked to and packed from arrays of 8-bit channels in
+//! `r`, `g`, `b`, `a` order, whatever their order in the pixel, which convert directly to and from
+//! the prebuilt `Rgb<u8>` and `Rgba<u8>` colors with `from_array` and `to_array`.
+//!
+//! ```rust
+//! use swizzle::packed::{pack_rgb565, rgb565_to_bgr565, unpack_argb4444, unpack_rgb565};
+//!
+//! assert_eq!(unpack_rgb565(0xF800), [255, 0, 0]);
+//! assert_eq!(pack_rgb565([255, 0, 0]), 0xF800);
+//! assert_eq!(rgb565_to_bgr565(0xF800), 0x001F);
+//! assert_eq!(unpack_argb4444(0xF08C), [0, 0x88, 0xCC, 0xFF]);
+//! ```
+//!
+//! # 10-bit Formats
+//!
+//! The 10:10:10:2 formats of HDR swapchains are named from the most significant bits like the
+//! 16-bit formats, so an `a2r10g10b10` pixel is the same as `VK_FORMAT_A2R10G10B10_UNORM_PACK32`,
+//! and are unpacked to and packed from their channels in `r`, `g`, `b`, `a` order, without
+//! changing their bits.
+//!
+//! ```rust
+//! use swizzle::packed::{a2r10g10b10_to_a2b10g10r10, pack_a2r10g10b10, unpack_a2b10g10r10};
+//!
+//! let pixel = pack_a2r10g10b10([1023, 512, 0, 3]);
+//! assert_eq!(unpack_a2b10g10r10(a2r10g10b10_to_a2b10g10r10(pixel)), [1023, 512, 0, 3]);
+//! ```
+//!
+//! # Half-float Formats
+//!
+//! With the `half` feature enabled, `R16G16B16A16_SFLOAT` texels, and their BGRA order, packed
+//! into a `u64` with the channels named from the least significant bits like the 8-bit formats,
+//! are unpacked to and packed from `f32` channels in `r`, `g`, `b`, `a` order, e.g. for
+//! `Rgba<f32>`.
+//!
+//! ```rust
+//! # #[cfg(feature = "half")] {
+//! use swizzle::packed::{pack_rgba16f, rgba16f_to_bgra16f, unpack_bgra16f};
+//!
+//! let texel = pack_rgba16f([1.0, 0.5, 0.25, 1.0]);
+//! assert_eq!(unpack_bgra16f(rgba16f_to_bgra16f(texel)), [1.0, 0.5, 0.25, 1.0]);
+//! # }
+//! ```
+
+/// Returns the pixel with the byte at each position taken from the byte of `pixel` at the index,
+/// counting from the least significant byte.
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+#[must_use]
+#[inline]
+pub const fn swizzle_u32(pixel: u32, idx: [usize; 4]) -> u32 {
+    let mut swizzled = 0;
+    let mut i = 0;
+    while i < 4 {
+        assert!(idx[i] < 4, "swizzle index out of range");
+        swizzled |= ((pixel >> (8 * idx[i])) & 0xFF) << (8 * i);
+        i += 1;
+    }
+    swizzled
+}
+
+/// Swizzle every pixel of `pixels` in place, as [`swizzle_u32`].
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range.
+pub fn swizzle_u32_in_place(pixels: &mut [u32], idx: [usize; 4]) {
+    assert!(idx.iter().all(|&i| i < 4), "swizzle index out of range");
+    for pixel in pixels {
+        *pixel = swizzle_u32(*pixel, idx);
+    }
+}
+
+/// Returns the pixel with the channels of `bytes` in memory order, so the first byte is the least
+/// significant, on any target.
+#[must_use]
+#[inline]
+pub const fn from_bytes(bytes: [u8; 4]) -> u32 {
+    u32::from_le_bytes(bytes)
+}
+
+/// Returns the channels of the pixel in memory order, from the least significant byte.
+#[must_use]
+#[inline]
+pub const fn to_bytes(pixel: u32) -> [u8; 4] {
+    pixel.to_le_bytes()
+}
+
+/// How the channels of a `u32` pixel are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Channels named from the least significant byte, the layout of this module, e.g.
+    /// `0xAABBGGRR` for RGBA.
+    Lsb,
+    /// Channels named from the most significant byte, as a number is written, e.g. `0xRRGGBBAA`
+    /// for RGBA, as in `SDL_PIXELFORMAT_RGBA8888` or the ARGB colors of many APIs.
+    Msb,
+    /// Channels named in memory order, with the pixel loaded as a native-endian `u32`, e.g. from a
+    /// `&[u32]` cast from a buffer of RGBA bytes. The same as `Lsb` on little-endian targets, and
+    /// `Msb` on big-endian targets.
+    Memory,
+}
+
+impl Layout {
+    /// Convert a pixel from this layout to [`Layout::Lsb`].
+    #[must_use]
+    #[inline]
+    pub const fn to_lsb(self, pixel: u32) -> u32 {
+        match self {
+            Self::Lsb => pixel,
+            Self::Msb => pixel.swap_bytes(),
+            Self::Memory => u32::from_le(pixel),
+        }
+    }
+
+    /// Convert a pixel from [`Layout::Lsb`] to this layout.
+    #[must_use]
+    #[inline]
+    pub const fn from_lsb(self, pixel: u32) -> u32 {
+        match self {
+            Self::Lsb => pixel,
+            Self::Msb => pixel.swap_bytes(),
+            Self::Memory => pixel.to_le(),
+        }
+    }
+
+    /// Convert a pixel from this layout to the `to` layout.
+    #[must_use]
+    #[inline]
+    pub const fn convert(self, to: Self, pixel: u32) -> u32 {
+        to.from_lsb(self.to_lsb(pixel))
+    }
+
+    /// Convert every pixel of `pixels` from this layout to the `to` layout in place.
+    pub fn convert_in_place(self, to: Self, pixels: &mut [u32]) {
+        for pixel in pixels {
+            *pixel = self.convert(to, *pixel);
+        }
+    }
+}
+
+/// Returns the index of the channel of `src` that each channel of `dst` is taken from.
+const fn channel_indices(src: &[u8; 4], dst: &[u8; 4]) -> [usize; 4] {
+    let mut idx = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        let mut j = 0;
+        while src[j] != dst[i] {
+            j += 1;
+        }
+        idx[i] = j;
+        i += 1;
+    }
+    idx
+}
+
+/// Generates the conversions from each channel order to the others, for single pixels and in
+/// place for slices of pixels.
+macro_rules! packed_swizzles {
+    ($( $src:ident => $( $dst:ident ),+; )+) => {
+        $( $(
+            $crate::__private::paste! {
+                #[doc = concat!(
+                    "Convert a `", stringify!($src), "8` pixel to `", stringify!($dst), "8`."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<$src 8_to_ $dst 8>](pixel: u32) -> u32 {
+                    const IDX: [usize; 4] = channel_indices(
+                        stringify!($src).as_bytes().first_chunk().unwrap(),
+                        stringify!($dst).as_bytes().first_chunk().unwrap(),
+                    );
+                    swizzle_u32(pixel, IDX)
+                }
+
+                #[doc = concat!(
+                    "Convert every `", stringify!($src), "8` pixel of `pixels` to `",
+                    stringify!($dst), "8` in place."
+                )]
+                #[inline]
+                pub fn [<$src 8_to_ $dst 8_in_place>](pixels: &mut [u32]) {
+                    for pixel in pixels {
+                        *pixel = [<$src 8_to_ $dst 8>](*pixel);
+                    }
+                }
+            }
+        )+ )+
+    };
+}
+
+packed_swizzles! {
+    rgba => bgra, argb, abgr;
+    bgra => rgba, argb, abgr;
+    argb => rgba, bgra, abgr;
+    abgr => rgba, bgra, argb;
+}
+
+/// The shift and number of bits of each channel of a 16-bit or 32-bit format, in `r`, `g`, `b`,
+/// `a` order, with no bits for a missing alpha channel.
+#[derive(Clone, Copy)]
+struct PackedFormat {
+    shift: [u32; 4],
+    bits: [u32; 4],
+}
+
+impl PackedFormat {
+    const fn new() -> Self {
+        Self {
+            shift: [0; 4],
+            bits: [0; 4],
+        }
+    }
+
+    const fn with(mut self, channel: &str, shift: u32, bits: u32) -> Self {
+        let i = match channel.as_bytes()[0] {
+            b'r' => 0,
+            b'g' => 1,
+            b'b' => 2,
+            _ => 3,
+        };
+        self.shift[i] = shift;
+        self.bits[i] = bits;
+        self
+    }
+
+    /// Returns the 8-bit channels of the pixel, with the bits of each channel repeated to fill the
+    /// 8 bits, so the maximum of each channel unpacks to 255.
+    const fn unpack<const N: usize>(self, pixel: u16) -> [u8; N] {
+        let mut channels = [0; N];
+        let mut i = 0;
+        while i < N {
+            let bits = self.bits[i];
+            let value = (pixel as u32 >> self.shift[i]) & ((1 << bits) - 1);
+            let mut expanded = 0;
+            let mut shift = 8 - bits as i32;
+            while shift > -(bits as i32) {
+                expanded |= if shift >= 0 {
+                    value << shift
+                } else {
+                    value >> -shift
+                };
+                shift -= bits as i32;
+            }
+            channels[i] = expanded as u8;
+            i += 1;
+        }
+        channels
+    }
+
+    /// Returns the pixel of the 8-bit channels, with each channel rounded to the nearest value of
+    /// its bits.
+    const fn pack<const N: usize>(self, channels: [u8; N]) -> u16 {
+        let mut pixel = 0;
+        let mut i = 0;
+        while i < N {
+            let max = (1 << self.bits[i]) - 1;
+            pixel |= ((channels[i] as u32 * max + 127) / 255) << self.shift[i];
+            i += 1;
+        }
+        pixel as u16
+    }
+
+    /// Returns the channels of the pixel, unchanged.
+    const fn unpack_raw<const N: usize>(self, pixel: u32) -> [u16; N] {
+        let mut channels = [0; N];
+        let mut i = 0;
+        while i < N {
+            channels[i] = ((pixel >> self.shift[i]) & ((1 << self.bits[i]) - 1)) as u16;
+            i += 1;
+        }
+        channels
+    }
+
+    /// Returns the pixel of the channels, unchanged.
+    const fn pack_raw<const N: usize>(self, channels: [u16; N]) -> u32 {
+        let mut pixel = 0;
+        let mut i = 0;
+        while i < N {
+            assert!(
+                (channels[i] as u32) < 1 << self.bits[i],
+                "packed channel out of range"
+            );
+            pixel |= (channels[i] as u32) << self.shift[i];
+            i += 1;
+        }
+        pixel
+    }
+
+    /// Moves the channels of the pixel to their positions in `dst`, which must have the same bits
+    /// for each channel.
+    const fn reorder(self, dst: Self, pixel: u32) -> u32 {
+        let mut reordered = 0;
+        let mut i = 0;
+        while i < 4 {
+            let value = (pixel >> self.shift[i]) & ((1 << self.bits[i]) - 1);
+            reordered |= value << dst.shift[i];
+            i += 1;
+        }
+        reordered
+    }
+}
+
+/// Generates the packing and unpacking of each 16-bit format, from the shift and bits of each of
+/// its channels.
+macro_rules! formats16 {
+    ($( $format:ident: [ $( $channel:ident $shift:literal $bits:literal ),+ ]; )+) => {
+        $crate::__private::paste! {
+            $(
+                const [<$format:upper>]: PackedFormat = PackedFormat::new()
+                    $( .with(stringify!($channel), $shift, $bits) )+;
+
+                #[doc = concat!(
+                    "Unpack an `", stringify!($format), "` pixel to its 8-bit channels, in `r`, `g`, ",
+                    "`b`, `a` order."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<unpack_ $format>](pixel: u16) -> [u8; [$( stringify!($channel) ),+].len()] {
+                    [<$format:upper>].unpack(pixel)
+                }
+
+                #[doc = concat!(
+                    "Pack 8-bit channels, in `r`, `g`, `b`, `a` order, to an `", stringify!($format),
+                    "` pixel, rounding each to the nearest value of its bits."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<pack_ $format>](channels: [u8; [$( stringify!($channel) ),+].len()]) -> u16 {
+                    [<$format:upper>].pack(channels)
+                }
+            )+
+        }
+    };
+}
+
+formats16! {
+    rgb565: [r 11 5, g 5 6, b 0 5];
+    bgr565: [b 11 5, g 5 6, r 0 5];
+    rgba5551: [r 11 5, g 6 5, b 1 5, a 0 1];
+    argb1555: [a 15 1, r 10 5, g 5 5, b 0 5];
+    rgba4444: [r 12 4, g 8 4, b 4 4, a 0 4];
+    argb4444: [a 12 4, r 8 4, g 4 4, b 0 4];
+}
+
+/// Generates the packing and unpacking of each 32-bit format of more than 8 bits per channel, from
+/// the shift and bits of each of its channels.
+macro_rules! formats32 {
+    ($( $format:ident: [ $( $channel:ident $shift:literal $bits:literal ),+ ]; )+) => {
+        $crate::__private::paste! {
+            $(
+                const [<$format:upper>]: PackedFormat = PackedFormat::new()
+                    $( .with(stringify!($channel), $shift, $bits) )+;
+
+                #[doc = concat!(
+                    "Unpack an `", stringify!($format), "` pixel to its channels, in `r`, `g`, `b`, ",
+                    "`a` order."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<unpack_ $format>](pixel: u32) -> [u16; [$( stringify!($channel) ),+].len()] {
+                    [<$format:upper>].unpack_raw(pixel)
+                }
+
+                #[doc = concat!(
+                    "Pack channels, in `r`, `g`, `b`, `a` order, to an `", stringify!($format),
+                    "` pixel.\n\n# Panics\n\nPanics if any channel doesn't fit in its bits."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<pack_ $format>](channels: [u16; [$( stringify!($channel) ),+].len()]) -> u32 {
+                    [<$format:upper>].pack_raw(channels)
+                }
+            )+
+        }
+    };
+}
+
+formats32! {
+    a2r10g10b10: [a 30 2, r 20 10, g 10 10, b 0 10];
+    a2b10g10r10: [a 30 2, b 20 10, g 10 10, r 0 10];
+}
+
+/// Generates the conversions between formats with the same channels in different orders.
+macro_rules! reorders {
+    ($pixel:ty; $( $src:ident => $dst:ident; )+) => {
+        $crate::__private::paste! {
+            $(
+                #[doc = concat!(
+                    "Convert an `", stringify!($src), "` pixel to `", stringify!($dst), "`."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<$src _to_ $dst>](pixel: $pixel) -> $pixel {
+                    [<$src:upper>].reorder([<$dst:upper>], pixel as u32) as $pixel
+                }
+            )+
+        }
+    };
+}
+
+reorders! {
+    u16;
+    rgb565 => bgr565;
+    bgr565 => rgb565;
+    rgba5551 => argb1555;
+    argb1555 => rgba5551;
+    rgba4444 => argb4444;
+    argb4444 => rgba4444;
+}
+
+reorders! {
+    u32;
+    a2r10g10b10 => a2b10g10r10;
+    a2b10g10r10 => a2r10g10b10;
+}
+
+/// Returns the texel with the 16-bit lane at each position taken from the lane of `texel` at the
+/// index, counting from the least significant lane.
+#[cfg(feature = "half")]
+const fn swizzle_u64_lanes(texel: u64, idx: [usize; 4]) -> u64 {
+    let mut swizzled = 0;
+    let mut i = 0;
+    while i < 4 {
+        swizzled |= ((texel >> (16 * idx[i])) & 0xFFFF) << (16 * i);
+        i += 1;
+    }
+    swizzled
+}
+
+/// Generates the packing and unpacking of each half-float format, and the conversions between
+/// them.
+#[cfg(feature = "half")]
+macro_rules! formats_f16 {
+    ($( $format:ident: $order:literal => $( $dst:ident ),+; )+) => {
+        $crate::__private::paste! {
+            $(
+                #[doc = concat!(
+                    "Unpack an `", stringify!($format), "16f` texel to `f32` channels, in `r`, `g`, ",
+                    "`b`, `a` order."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<unpack_ $format 16f>](texel: u64) -> [f32; 4] {
+                    const IDX: [usize; 4] = channel_indices($order, b"rgba");
+                    let rgba = swizzle_u64_lanes(texel, IDX);
+                    let mut channels = [0.0; 4];
+                    let mut i = 0;
+                    while i < 4 {
+                        channels[i] = half::f16::from_bits((rgba >> (16 * i)) as u16).to_f32_const();
+                        i += 1;
+                    }
+                    channels
+                }
+
+                #[doc = concat!(
+                    "Pack `f32` channels, in `r`, `g`, `b`, `a` order, to an `", stringify!($format),
+                    "16f` texel, rounding each to the nearest half-float."
+                )]
+                #[must_use]
+                #[inline]
+                pub const fn [<pack_ $format 16f>](channels: [f32; 4]) -> u64 {
+                    const IDX: [usize; 4] = channel_indices(b"rgba", $order);
+                    let mut rgba = 0;
+                    let mut i = 0;
+                    while i < 4 {
+                        rgba |= (half::f16::from_f32_const(channels[i]).to_bits() as u64) << (16 * i);
+                        i += 1;
+                    }
+                    swizzle_u64_lanes(rgba, IDX)
+                }
+
+                $(
+                    #[doc = concat!(
+                        "Convert an `", stringify!($format), "16f` texel to `", stringify!($dst), "16f`."
+                    )]
+                    #[must_use]
+                    #[inline]
+                    pub const fn [<$format 16f_to_ $dst 16f>](texel: u64) -> u64 {
+                        const IDX: [usize; 4] = channel_indices(
+                            $order,
+                            stringify!($dst).as_bytes().first_chunk().unwrap(),
+                        );
+                        swizzle_u64_lanes(texel, IDX)
+                    }
+                )+
+            )+
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+formats_f16! {
+    rgba: b"rgba" => bgra;
+    bgra: b"bgra" => rgba;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swizzle_u32() {
+        const BGRA: u32 = swizzle_u32(0x4433_2211, [2, 1, 0, 3]);
+        assert_eq!(BGRA, 0x4411_2233);
+        assert_eq!(swizzle_u32(0x4433_2211, [0, 0, 0, 3]), 0x4411_1111);
+
+        let mut pixels = [0x4433_2211, 0x8877_6655];
+        swizzle_u32_in_place(&mut pixels, [3, 2, 1, 0]);
+        assert_eq!(pixels, [0x1122_3344, 0x5566_7788]);
+    }
+
+    #[test]
+    fn test_named_conversions() {
+        let rgba = u32::from_le_bytes(*b"rgba");
+        assert_eq!(rgba8_to_bgra8(rgba), u32::from_le_bytes(*b"bgra"));
+        assert_eq!(rgba8_to_argb8(rgba), u32::from_le_bytes(*b"argb"));
+        assert_eq!(
+            bgra8_to_abgr8(u32::from_le_bytes(*b"bgra")),
+            u32::from_le_bytes(*b"abgr")
+        );
+        assert_eq!(argb8_to_rgba8(rgba8_to_argb8(rgba)), rgba);
+        assert_eq!(abgr8_to_bgra8(rgba8_to_abgr8(rgba)), rgba8_to_bgra8(rgba));
+
+        let mut pixels = [rgba; 3];
+        rgba8_to_abgr8_in_place(&mut pixels);
+        assert_eq!(pixels, [u32::from_le_bytes(*b"abgr"); 3]);
+    }
+
+    #[test]
+    fn test_layouts() {
+        let rgba = from_bytes(*b"rgba");
+        assert_eq!(to_bytes(rgba), *b"rgba");
+        assert_eq!(Layout::Lsb.to_lsb(rgba), rgba);
+        assert_eq!(Layout::Msb.from_lsb(rgba), u32::from_be_bytes(*b"rgba"));
+        assert_eq!(Layout::Memory.to_lsb(u32::from_ne_bytes(*b"rgba")), rgba);
+        assert_eq!(Layout::Memory.from_lsb(rgba).to_ne_bytes(), *b"rgba");
+
+        let native = if cfg!(target_endian = "little") {
+            Layout::Lsb
+        } else {
+            Layout::Msb
+        };
+        for from in [Layout::Lsb, Layout::Msb, Layout::Memory] {
+            assert_eq!(
+                from.convert(Layout::Memory, rgba),
+                from.convert(native, rgba)
+            );
+            for to in [Layout::Lsb, Layout::Msb, Layout::Memory] {
+                assert_eq!(to.convert(from, from.convert(to, rgba)), rgba);
+            }
+        }
+
+        let mut pixels = [u32::from_be_bytes(*b"argb"); 2];
+        Layout::Msb.convert_in_place(Layout::Lsb, &mut pixels);
+        assert_eq!(pixels, [from_bytes(*b"argb"); 2]);
+    }
+
+    #[test]
+    fn test_formats16() {
+        assert_eq!(unpack_rgb565(0xFFFF), [255; 3]);
+        assert_eq!(unpack_rgb565(0x07E0), [0, 255, 0]);
+        assert_eq!(unpack_bgr565(0x07E0 | 0x001F), [255, 255, 0]);
+        assert_eq!(unpack_rgba5551(0x0001), [0, 0, 0, 255]);
+        assert_eq!(unpack_argb1555(0x8000), [0, 0, 0, 255]);
+        assert_eq!(unpack_rgba4444(0x1234), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(unpack_argb4444(0x1234), [0x22, 0x33, 0x44, 0x11]);
+
+        assert_eq!(pack_rgb565([0x80, 0x80, 0x80]), 0x8410);
+        assert_eq!(pack_rgba5551([255, 0, 0, 200]), 0xF801);
+        assert_eq!(pack_argb4444([0x22, 0x33, 0x44, 0x11]), 0x1234);
+
+        // Every pixel survives a round trip through 8-bit channels.
+        for pixel in 0..=u16::MAX {
+            assert_eq!(pack_rgb565(unpack_rgb565(pixel)), pixel);
+            assert_eq!(pack_argb1555(unpack_argb1555(pixel)), pixel);
+            assert_eq!(pack_rgba4444(unpack_rgba4444(pixel)), pixel);
+        }
+    }
+
+    #[test]
+    fn test_reorders16() {
+        for pixel in [0x0000, 0x1234, 0xF800, 0xABCD, 0xFFFF] {
+            assert_eq!(unpack_bgr565(rgb565_to_bgr565(pixel)), unpack_rgb565(pixel));
+            assert_eq!(bgr565_to_rgb565(rgb565_to_bgr565(pixel)), pixel);
+            assert_eq!(
+                unpack_argb1555(rgba5551_to_argb1555(pixel)),
+                unpack_rgba5551(pixel)
+            );
+            assert_eq!(argb1555_to_rgba5551(rgba5551_to_argb1555(pixel)), pixel);
+            assert_eq!(
+                unpack_rgba4444(argb4444_to_rgba4444(pixel)),
+                unpack_argb4444(pixel)
+            );
+            assert_eq!(rgba4444_to_argb4444(argb4444_to_rgba4444(pixel)), pixel);
+        }
+    }
+
+    #[test]
+    fn test_formats32() {
+        assert_eq!(pack_a2r10g10b10([1023, 0, 0, 3]), 0xFFF0_0000);
+        assert_eq!(pack_a2b10g10r10([1023, 0, 0, 3]), 0xC000_03FF);
+        assert_eq!(unpack_a2r10g10b10(0x4010_0C03), [1, 3, 3, 1]);
+
+        for channels in [[0, 0, 0, 0], [1023, 512, 1, 2], [5, 1000, 700, 3]] {
+            let argb = pack_a2r10g10b10(channels);
+            let abgr = a2r10g10b10_to_a2b10g10r10(argb);
+            assert_eq!(abgr, pack_a2b10g10r10(channels));
+            assert_eq!(unpack_a2b10g10r10(abgr), channels);
+            assert_eq!(a2b10g10r10_to_a2r10g10b10(abgr), argb);
+        }
+    }
+
+    #[test]
+    #[should_panic = "packed channel out of range"]
+    fn test_pack_out_of_range() {
+        let _ = pack_a2r10g10b10([0, 0, 1024, 0]);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_formats_f16() {
+        let texel = pack_rgba16f([1.0, 0.5, -2.0, 1.0]);
+        assert_eq!(texel, 0x3C00_C000_3800_3C00);
+        assert_eq!(unpack_rgba16f(texel), [1.0, 0.5, -2.0, 1.0]);
+
+        let bgra = rgba16f_to_bgra16f(texel);
+        assert_eq!(bgra, 0x3C00_3C00_3800_C000);
+        assert_eq!(bgra, pack_bgra16f([1.0, 0.5, -2.0, 1.0]));
+        assert_eq!(unpack_bgra16f(bgra), [1.0, 0.5, -2.0, 1.0]);
+        assert_eq!(bgra16f_to_rgba16f(bgra), texel);
+
+        // Rounded to the nearest half-float.
+        assert_eq!(
+            unpack_rgba16f(pack_rgba16f([0.1, 65504.0, 1e-8, 1.0]))[..2],
+            [0.099975586, 65504.0]
+        );
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_swizzle_u32_out_of_range() {
+        let _ = swizzle_u32(0, [0, 1, 2, 4]);
+    }
+}
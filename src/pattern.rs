@@ -0,0 +1,207 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// A swizzle pattern of a struct, implemented by the pattern types generated by `swizzle_dyn!`.
+///
+/// This allows patterns to be enumerated generically, e.g. by a [`PermutationIter`].
+pub trait SwizzlePattern: Copy {
+    /// The number of distinct patterns, `n^n` for `n` fields, or `None` if it doesn't fit in a
+    /// `usize`.
+    const COUNT: Option<usize>;
+
+    /// The index of the pattern in lexicographic order, from `0` to `COUNT - 1`, or `None` if it
+    /// doesn't fit in a `usize`.
+    #[must_use]
+    fn rank(&self) -> Option<usize>;
+
+    /// The pattern with the given index in lexicographic order, or `None` if the index is not less
+    /// than `COUNT`.
+    #[must_use]
+    fn unrank(rank: usize) -> Option<Self>;
+
+    /// The sign of the pattern: `1` if it is an even permutation, `-1` if it is an odd
+    /// permutation, and `0` if it isn't a pure permutation.
+    #[must_use]
+    fn sign(&self) -> i8;
+
+    /// Sample a pattern uniformly at random.
+    ///
+    /// The default implementation samples a rank, so it must be overridden by patterns whose
+    /// `COUNT` doesn't fit in a `usize`, as it is by the pattern types of `swizzle_dyn!`.
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics if `COUNT` is `None`.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let count = Self::COUNT.expect("too many patterns to sample by rank");
+        Self::unrank(rng.random_range(0..count)).expect("rank is less than COUNT")
+    }
+
+    /// Sample a pattern uniformly at random from the pure permutations.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    fn random_permutation<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let pattern = Self::random(rng);
+            if pattern.sign() != 0 {
+                return pattern;
+            }
+        }
+    }
+}
+
+/// Iterator over every pattern of a struct in lexicographic order, optionally restricted to the
+/// pure permutations.
+///
+/// ```rust
+/// use swizzle::{PermutationIter, swizzle_dyn};
+///
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// swizzle_dyn!(Rgb: u8 { r, g, b });
+///
+/// // Find the channel order that turns one pixel into the other.
+/// let from = Rgb { r: 10, g: 20, b: 30 };
+/// let to = Rgb { r: 30, g: 10, b: 20 };
+///
+/// let order = PermutationIter::<RgbPattern>::permutations()
+///     .find(|pattern| {
+///         let swizzled = pattern.apply(&from);
+///         (swizzled.r, swizzled.g, swizzled.b) == (to.r, to.g, to.b)
+///     })
+///     .unwrap();
+/// assert_eq!(order.to_string(), "brg");
+/// ```
+#[derive(Debug)]
+pub struct PermutationIter<P> {
+    next: usize,
+    permutations_only: bool,
+    pattern: PhantomData<fn() -> P>,
+}
+
+impl<P: SwizzlePattern> PermutationIter<P> {
+    /// Iterate over every pattern.
+    #[must_use]
+    pub const fn all() -> Self {
+        Self {
+            next: 0,
+            permutations_only: false,
+            pattern: PhantomData,
+        }
+    }
+
+    /// Iterate over the patterns that are pure permutations, using every field exactly once.
+    #[must_use]
+    pub const fn permutations() -> Self {
+        Self {
+            next: 0,
+            permutations_only: true,
+            pattern: PhantomData,
+        }
+    }
+}
+
+impl<P> Clone for PermutationIter<P> {
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            permutations_only: self.permutations_only,
+            pattern: PhantomData,
+        }
+    }
+}
+
+impl<P: SwizzlePattern> Iterator for PermutationIter<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        loop {
+            let pattern = P::unrank(self.next)?;
+            self.next += 1;
+            if !self.permutations_only || pattern.sign() != 0 {
+                return Some(pattern);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = P::COUNT.map(|count| count.saturating_sub(self.next));
+        if self.permutations_only {
+            (0, remaining)
+        } else {
+            (remaining.unwrap_or(usize::MAX), remaining)
+        }
+    }
+}
+
+impl<P: SwizzlePattern> FusedIterator for PermutationIter<P> {}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::swizzle_dyn;
+
+    struct TestStruct {
+        a: u8,
+        b: u8,
+        c: u8,
+    }
+
+    swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+    #[test]
+    fn test_permutation_iter_all() {
+        let mut iter = PermutationIter::<TestStructPattern>::all();
+        assert_eq!(iter.size_hint(), (27, Some(27)));
+        assert_eq!(iter.next().and_then(|p| p.rank()), Some(0));
+        assert_eq!(iter.size_hint(), (26, Some(26)));
+        assert_eq!(iter.clone().count(), 26);
+        assert!(iter.enumerate().all(|(i, p)| p.rank() == Some(i + 1)));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_pattern() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut seen = [false; 27];
+        for _ in 0..1000 {
+            seen[TestStructPattern::random(&mut rng).rank().unwrap()] = true;
+            assert_ne!(TestStructPattern::random_permutation(&mut rng).sign(), 0);
+            let _: TestStructAxis = rng.random();
+        }
+        assert!(seen.iter().all(|&seen| seen));
+
+        let pattern: TestStructPattern = rng.random();
+        assert!(pattern.rank() < TestStructPattern::COUNT);
+    }
+
+    #[test]
+    fn test_permutation_iter_permutations() {
+        use TestStructAxis::{A, B, C};
+
+        let permutations: [TestStructPattern; 6] = [
+            TestStructPattern::new([A, B, C]),
+            TestStructPattern::new([A, C, B]),
+            TestStructPattern::new([B, A, C]),
+            TestStructPattern::new([B, C, A]),
+            TestStructPattern::new([C, A, B]),
+            TestStructPattern::new([C, B, A]),
+        ];
+
+        let mut iter = TestStructPattern::permutations();
+        for expected in permutations {
+            assert_eq!(iter.next(), Some(expected));
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
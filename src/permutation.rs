@@ -0,0 +1,475 @@
+use crate::{Components, SwizzleError};
+use core::str::FromStr;
+
+/// Runtime swizzle by field index, implemented for every type implementing
+/// [`Components<N>`](crate::Components), including the structs declared to `swizzle_dyn!`.
+///
+/// This allows swizzles to be applied generically, e.g. by a [`Permutation`].
+pub trait SwizzleDyn<const N: usize>: Sized {
+    /// Create an instance with the values swizzled according to the given field indices, where
+    /// the value at each position is taken from the field at the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of range.
+    #[must_use]
+    fn swizzle_dyn(&self, idx: [usize; N]) -> Self;
+}
+
+impl<T: Components<N>, const N: usize> SwizzleDyn<N> for T {
+    #[inline]
+    fn swizzle_dyn(&self, idx: [usize; N]) -> Self {
+        crate::algo::swizzle(self, idx)
+    }
+}
+
+/// Object-safe runtime swizzle, implemented by `swizzle_dyn!`.
+///
+/// Unlike [`SwizzleDyn`], the number of fields isn't part of the type, so values of different
+/// types can be swizzled through `dyn DynSwizzle`, e.g. in a heterogeneous collection. The swizzle
+/// is applied in place, so no allocation is needed.
+///
+/// For the same reason it can't be implemented for every [`Components`](crate::Components)
+/// implementor, but a hand-written implementation can delegate to
+/// [`algo::try_swizzle_in_place`](crate::algo::try_swizzle_in_place).
+///
+/// ```rust
+/// use swizzle::{DynSwizzle, swizzle_dyn};
+///
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// swizzle_dyn!(Vec2: f32 { x, y });
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// let mut v2 = Vec2 { x: 1.0, y: 2.0 };
+/// let mut v3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let values: [&mut dyn DynSwizzle; 2] = [&mut v2, &mut v3];
+///
+/// for value in values {
+///     // Swap the first two fields.
+///     let mut idx = [0, 1, 2];
+///     idx.swap(0, 1);
+///     value.try_swizzle_in_place(&idx[..value.swizzle_arity()]).unwrap();
+/// }
+///
+/// assert_eq!((v2.x, v2.y), (2.0, 1.0));
+/// assert_eq!((v3.x, v3.y, v3.z), (2.0, 1.0, 3.0));
+/// ```
+pub trait DynSwizzle {
+    /// The number of fields, and so the number of indices expected by the swizzle.
+    fn swizzle_arity(&self) -> usize;
+
+    /// Swizzle the values in place according to the given field indices, where the value at each
+    /// position is taken from the field at the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwizzleError::WrongArity`] if the number of indices doesn't match the number of
+    /// fields, or [`SwizzleError::IndexOutOfRange`] if any index is out of range. The value is
+    /// unchanged on error.
+    fn try_swizzle_in_place(&mut self, idx: &[usize]) -> Result<(), SwizzleError>;
+}
+
+/// A permutation of `N` fields, stored as the index of the field that the value at each position
+/// is taken from. Every index is in range and appears exactly once.
+///
+/// Permutations can be stored, composed, and inverted as values, then applied to any struct with
+/// `N` fields that implements [`SwizzleDyn`].
+///
+/// ```rust
+/// use swizzle::{Permutation, swizzle_dyn};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// swizzle_dyn!(Vec3: f32 { x, y, z });
+///
+/// let rotate = Permutation::new([1, 2, 0]).unwrap();
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+///
+/// assert_eq!(rotate.apply(&v), Vec3 { x: 2.0, y: 3.0, z: 1.0 });
+/// assert_eq!(rotate.then(rotate).apply(&v), Vec3 { x: 3.0, y: 1.0, z: 2.0 });
+/// assert_eq!(rotate.inverse().apply(&rotate.apply(&v)), v);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permutation<const N: usize>([usize; N]);
+
+impl<const N: usize> Permutation<N> {
+    /// The permutation leaving every field in place.
+    pub const IDENTITY: Self = {
+        let mut idx = [0; N];
+        let mut i = 0;
+        while i < N {
+            idx[i] = i;
+            i += 1;
+        }
+        Self(idx)
+    };
+
+    /// Create a permutation from the index of the field that the value at each position is taken
+    /// from.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwizzleError::IndexOutOfRange`] if any index is out of range, or
+    /// [`SwizzleError::DuplicateIndex`] if any index appears more than once.
+    pub const fn new(idx: [usize; N]) -> Result<Self, SwizzleError> {
+        let mut seen = [false; N];
+        let mut i = 0;
+        while i < N {
+            let index = idx[i];
+            if index >= N {
+                return Err(SwizzleError::IndexOutOfRange { index, len: N });
+            }
+            if seen[index] {
+                return Err(SwizzleError::DuplicateIndex { index });
+            }
+            seen[index] = true;
+            i += 1;
+        }
+        Ok(Self(idx))
+    }
+
+    /// The index of the field that the value at each position is taken from.
+    #[must_use]
+    #[inline]
+    pub const fn indices(&self) -> [usize; N] {
+        self.0
+    }
+
+    /// The permutation that undoes this one.
+    #[must_use]
+    pub const fn inverse(&self) -> Self {
+        let mut idx = [0; N];
+        let mut i = 0;
+        while i < N {
+            idx[self.0[i]] = i;
+            i += 1;
+        }
+        Self(idx)
+    }
+
+    /// The permutation equivalent to applying this one followed by `next`.
+    #[must_use]
+    pub const fn then(&self, next: Self) -> Self {
+        let mut idx = [0; N];
+        let mut i = 0;
+        while i < N {
+            idx[i] = self.0[next.0[i]];
+            i += 1;
+        }
+        Self(idx)
+    }
+
+    /// The sign of the permutation: `1` if it is made of an even number of swaps, and `-1` if it
+    /// is made of an odd number. Swapping two axes flips the handedness of a coordinate system, so
+    /// the sign tells whether the winding order of triangles, or the direction of normals, needs
+    /// fixing after a permutation.
+    #[must_use]
+    pub const fn sign(&self) -> i8 {
+        let mut sign = 1;
+        let mut i = 0;
+        while i < N {
+            let mut j = i + 1;
+            while j < N {
+                if self.0[i] > self.0[j] {
+                    sign = -sign;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        sign
+    }
+
+    /// Create an instance with the values of `value` permuted.
+    #[must_use]
+    #[inline]
+    pub fn apply<T: SwizzleDyn<N>>(&self, value: &T) -> T {
+        value.swizzle_dyn(self.0)
+    }
+}
+
+impl<const N: usize> Default for Permutation<N> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<const N: usize> TryFrom<[usize; N]> for Permutation<N> {
+    type Error = SwizzleError;
+
+    fn try_from(idx: [usize; N]) -> Result<Self, Self::Error> {
+        Self::new(idx)
+    }
+}
+
+impl<const N: usize> From<Permutation<N>> for [usize; N] {
+    fn from(permutation: Permutation<N>) -> Self {
+        permutation.0
+    }
+}
+
+impl<const N: usize> AsRef<[usize]> for Permutation<N> {
+    fn as_ref(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+/// Parses a permutation from the axis names `x`, `y`, `z`, and `w`, for the indices 0 to 3, e.g.
+/// `"zxy"` is the permutation `[2, 0, 1]`.
+impl<const N: usize> FromStr for Permutation<N> {
+    type Err = SwizzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut idx = [0; N];
+        let mut found = 0;
+        for c in s.chars() {
+            let index = "xyzw".find(c).ok_or(SwizzleError::InvalidAxis)?;
+            if let Some(slot) = idx.get_mut(found) {
+                *slot = index;
+            }
+            found += 1;
+        }
+        if found != N {
+            return Err(SwizzleError::WrongArity { expected: N, found });
+        }
+        Self::new(idx)
+    }
+}
+
+/// Builds the table of inverses of the self-swizzles of `len` attributes, in the order they are
+/// generated by `swizzle!`, where the attributes chosen for each position are the digits of the
+/// index in base `len`, most significant first. Used by the macro expansions.
+#[doc(hidden)]
+#[must_use]
+pub const fn inverse_table<const COUNT: usize>(len: usize) -> [Option<usize>; COUNT] {
+    let mut table = [None; COUNT];
+    let mut index = 0;
+    while index < COUNT {
+        let mut rest = index;
+        let mut position = len;
+        let mut seen = [false; 64];
+        let mut inverse = 0;
+        table[index] = loop {
+            if position == 0 {
+                break Some(inverse);
+            }
+            position -= 1;
+            let digit = rest % len;
+            rest /= len;
+            if seen[digit] {
+                break None;
+            }
+            seen[digit] = true;
+            // The inverse takes the value at `digit` from `position`.
+            inverse += position * len.pow((len - 1 - digit) as u32);
+        };
+        index += 1;
+    }
+    table
+}
+
+/// Builds the table of signs of the self-swizzles of `len` attributes, in the same order as
+/// [`inverse_table`]: `1` for even permutations, `-1` for odd permutations, and `0` for swizzles
+/// that aren't pure permutations. Used by the macro expansions.
+#[doc(hidden)]
+#[must_use]
+pub const fn sign_table<const COUNT: usize>(len: usize) -> [i8; COUNT] {
+    let mut table = [0; COUNT];
+    let mut index = 0;
+    while index < COUNT {
+        let mut rest = index;
+        let mut position = len;
+        let mut seen = [false; 64];
+        let mut sign = 1;
+        table[index] = loop {
+            if position == 0 {
+                break sign;
+            }
+            position -= 1;
+            let digit = rest % len;
+            rest /= len;
+            if seen[digit] {
+                break 0;
+            }
+            seen[digit] = true;
+            // Every smaller digit already seen is in a later position, so is an inversion.
+            let mut smaller = 0;
+            while smaller < digit {
+                if seen[smaller] {
+                    sign = -sign;
+                }
+                smaller += 1;
+            }
+        };
+        index += 1;
+    }
+    table
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::swizzle_dyn;
+
+    #[derive(Debug, PartialEq)]
+    struct TestStruct {
+        a: u8,
+        b: u8,
+        c: u8,
+    }
+
+    swizzle_dyn!(TestStruct: u8 { a, b, c });
+
+    #[test]
+    fn test_permutation_new() {
+        assert_eq!(
+            Permutation::new([2, 0, 1]).map(|p| p.indices()),
+            Ok([2, 0, 1])
+        );
+        assert_eq!(
+            Permutation::new([0, 3, 1]),
+            Err(SwizzleError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        assert_eq!(
+            Permutation::new([0, 1, 0]),
+            Err(SwizzleError::DuplicateIndex { index: 0 })
+        );
+        assert_eq!(Permutation::<3>::IDENTITY.indices(), [0, 1, 2]);
+        assert_eq!(Permutation::<3>::default(), Permutation::IDENTITY);
+        assert_eq!(Permutation::<0>::IDENTITY.indices(), [0; 0]);
+    }
+
+    #[test]
+    fn test_permutation_apply() {
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        let p = Permutation::new([2, 0, 1]).unwrap();
+        assert_eq!(p.apply(&s), TestStruct { a: 3, b: 1, c: 2 });
+        assert_eq!(p.apply(&s), s.swizzle_dyn(p.indices()));
+        assert_eq!(Permutation::IDENTITY.apply(&s), s);
+    }
+
+    #[test]
+    fn test_permutation_compose() {
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        let p = Permutation::new([2, 0, 1]).unwrap();
+        let q = Permutation::new([1, 0, 2]).unwrap();
+
+        assert_eq!(p.then(q).apply(&s), q.apply(&p.apply(&s)));
+        assert_eq!(q.then(p).apply(&s), p.apply(&q.apply(&s)));
+        assert_eq!(p.then(p.inverse()), Permutation::IDENTITY);
+        assert_eq!(p.inverse().then(p), Permutation::IDENTITY);
+        assert_eq!(q.inverse(), q);
+    }
+
+    #[test]
+    fn test_permutation_conversions() {
+        let p: Permutation<3> = [1, 2, 0].try_into().unwrap();
+        assert_eq!(<[usize; 3]>::from(p), [1, 2, 0]);
+        assert_eq!(
+            Permutation::try_from([1, 1, 0]),
+            Err(SwizzleError::DuplicateIndex { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_permutation_from_str() {
+        assert_eq!(
+            "zxy".parse::<Permutation<3>>().unwrap().indices(),
+            [2, 0, 1]
+        );
+        assert_eq!("wzyx".parse(), Permutation::new([3, 2, 1, 0]));
+        assert_eq!(
+            "xy".parse::<Permutation<3>>(),
+            Err(SwizzleError::WrongArity {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            "xz".parse::<Permutation<2>>(),
+            Err(SwizzleError::IndexOutOfRange { index: 2, len: 2 })
+        );
+        assert_eq!(
+            "xxy".parse::<Permutation<3>>(),
+            Err(SwizzleError::DuplicateIndex { index: 0 })
+        );
+        assert_eq!(
+            "xyq".parse::<Permutation<3>>(),
+            Err(SwizzleError::InvalidAxis)
+        );
+    }
+
+    #[test]
+    fn test_dyn_swizzle() {
+        let mut s = TestStruct { a: 1, b: 2, c: 3 };
+        let value: &mut dyn DynSwizzle = &mut s;
+
+        assert_eq!(value.swizzle_arity(), 3);
+        assert_eq!(value.try_swizzle_in_place(&[2, 0, 1]), Ok(()));
+        assert_eq!(
+            value.try_swizzle_in_place(&[2, 0]),
+            Err(SwizzleError::WrongArity {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            value.try_swizzle_in_place(&[0, 0, 3]),
+            Err(SwizzleError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        assert_eq!(s, TestStruct { a: 3, b: 1, c: 2 });
+    }
+
+    #[test]
+    fn test_permutation_sign() {
+        assert_eq!(Permutation::<3>::IDENTITY.sign(), 1);
+        assert_eq!(Permutation::new([1, 0, 2]).unwrap().sign(), -1);
+        assert_eq!(Permutation::new([2, 0, 1]).unwrap().sign(), 1);
+        assert_eq!(Permutation::new([2, 1, 0]).unwrap().sign(), -1);
+        assert_eq!(Permutation::new([3, 2, 1, 0]).unwrap().sign(), 1);
+
+        let p = Permutation::new([2, 0, 1]).unwrap();
+        let q = Permutation::new([1, 0, 2]).unwrap();
+        assert_eq!(p.then(q).sign(), p.sign() * q.sign());
+        assert_eq!(p.inverse().sign(), p.sign());
+    }
+
+    #[test]
+    fn test_sign_table() {
+        assert_eq!(sign_table::<1>(1), [1]);
+        // aa, ab, ba, bb
+        assert_eq!(sign_table::<4>(2), [0, 1, -1, 0]);
+        // The same as the sign of the corresponding permutation.
+        let table = sign_table::<27>(3);
+        assert_eq!(table[5], Permutation::new([0, 1, 2]).unwrap().sign());
+        assert_eq!(table[7], Permutation::new([0, 2, 1]).unwrap().sign());
+        assert_eq!(table[19], Permutation::new([2, 0, 1]).unwrap().sign());
+        assert_eq!(table[21], Permutation::new([2, 1, 0]).unwrap().sign());
+        assert_eq!(table.iter().filter(|&&sign| sign != 0).count(), 6);
+    }
+
+    #[test]
+    fn test_inverse_table() {
+        assert_eq!(inverse_table::<1>(1), [Some(0)]);
+        // aa, ab, ba, bb
+        assert_eq!(inverse_table::<4>(2), [None, Some(1), Some(2), None]);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Components, Permutation, SwizzleError};
+use core::str::FromStr;
+
+/// A swizzle from a struct of `N` fields to a struct of `M` fields, validated once so that it can
+/// be applied many times, e.g. to every pixel of an image, without checking the indices again.
+///
+/// A plan is built from the index of the source field that the value at each position is taken
+/// from, from a [`Permutation`], from a pattern type generated by `swizzle_dyn!`, or parsed from
+/// the axis names `x`, `y`, `z`, and `w`. Unlike a permutation, a field may be repeated or left out,
+/// and the destination may have a different number of fields.
+///
+/// ```rust
+/// use swizzle::{SwizzlePlan, swizzle_dyn};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// swizzle_dyn!(Rgba: u8 { r, g, b, a });
+/// swizzle_dyn!(Rgb: u8 { r, g, b });
+///
+/// let to_bgra: SwizzlePlan<4> = "bgra".parse::<RgbaPattern>().unwrap().into();
+/// let mut pixels = [Rgba { r: 1, g: 2, b: 3, a: 4 }, Rgba { r: 5, g: 6, b: 7, a: 8 }];
+/// to_bgra.apply_slice_in_place(&mut pixels);
+/// assert_eq!(pixels[1], Rgba { r: 7, g: 6, b: 5, a: 8 });
+///
+/// // Drop the alpha channel, from a string read at runtime.
+/// let to_rgb: SwizzlePlan<4, 3> = "zyx".parse().unwrap();
+/// let mut rgb = [Rgb { r: 0, g: 0, b: 0 }, Rgb { r: 0, g: 0, b: 0 }];
+/// to_rgb.apply_slice(&pixels, &mut rgb);
+/// assert_eq!(rgb[0], Rgb { r: 1, g: 2, b: 3 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwizzlePlan<const N: usize, const M: usize = N>([usize; M]);
+
+impl<const N: usize, const M: usize> SwizzlePlan<N, M> {
+    /// Create a plan from the index of the source field that the value at each position is taken
+    /// from.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwizzleError::IndexOutOfRange`] if any index isn't less than `N`.
+    pub const fn new(idx: [usize; M]) -> Result<Self, SwizzleError> {
+        let mut i = 0;
+        while i < M {
+            if idx[i] >= N {
+                return Err(SwizzleError::IndexOutOfRange {
+                    index: idx[i],
+                    len: N,
+                });
+            }
+            i += 1;
+        }
+        Ok(Self(idx))
+    }
+
+    /// The index of the source field that the value at each position is taken from.
+    #[must_use]
+    #[inline]
+    pub const fn indices(&self) -> [usize; M] {
+        self.0
+    }
+
+    /// Create an instance of `U` with the values taken from the fields of `value`.
+    #[must_use]
+    #[inline]
+    pub fn apply<T, U>(&self, value: &T) -> U
+    where
+        T: Components<N>,
+        U: Components<M, Component = T::Component>,
+    {
+        U::from_fn(|i| value.get(self.0[i]))
+    }
+
+    /// Swizzle every item of `src` into the item of `dst` at the same position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn apply_slice<T, U>(&self, src: &[T], dst: &mut [U])
+    where
+        T: Components<N>,
+        U: Components<M, Component = T::Component>,
+    {
+        assert_eq!(src.len(), dst.len(), "swizzle slice lengths differ");
+        for (from, to) in src.iter().zip(dst) {
+            *to = self.apply(from);
+        }
+    }
+}
+
+impl<const N: usize> SwizzlePlan<N> {
+    /// Swizzle every item of `items` in place.
+    pub fn apply_slice_in_place<T: Components<N>>(&self, items: &mut [T]) {
+        for item in items {
+            *item = self.apply(item);
+        }
+    }
+}
+
+impl<const N: usize> From<Permutation<N>> for SwizzlePlan<N> {
+    fn from(permutation: Permutation<N>) -> Self {
+        Self(permutation.indices())
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<[usize; M]> for SwizzlePlan<N, M> {
+    type Error = SwizzleError;
+
+    fn try_from(idx: [usize; M]) -> Result<Self, Self::Error> {
+        Self::new(idx)
+    }
+}
+
+impl<const N: usize, const M: usize> AsRef<[usize]> for SwizzlePlan<N, M> {
+    fn as_ref(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+/// Parses a plan from the axis names `x`, `y`, `z`, and `w`, for the indices 0 to 3, e.g. `"zzx"`
+/// is the plan `[2, 2, 0]`.
+impl<const N: usize, const M: usize> FromStr for SwizzlePlan<N, M> {
+    type Err = SwizzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut idx = [0; M];
+        let mut found = 0;
+        for c in s.chars() {
+            let index = "xyzw".find(c).ok_or(SwizzleError::InvalidAxis)?;
+            if let Some(slot) = idx.get_mut(found) {
+                *slot = index;
+            }
+            found += 1;
+        }
+        if found != M {
+            return Err(SwizzleError::WrongArity { expected: M, found });
+        }
+        Self::new(idx)
+    }
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::swizzle_dyn;
+
+    #[derive(Debug, PartialEq)]
+    struct Vec3 {
+        x: i32,
+        y: i32,
+        z: i32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Vec2 {
+        x: i32,
+        y: i32,
+    }
+
+    swizzle_dyn!(Vec3: i32 { x, y, z });
+    swizzle_dyn!(Vec2: i32 { x, y });
+
+    const V: Vec3 = Vec3 { x: 1, y: 2, z: 3 };
+
+    #[test]
+    fn test_plan_new() {
+        assert_eq!(
+            SwizzlePlan::<3>::new([2, 2, 0]).unwrap().indices(),
+            [2, 2, 0]
+        );
+        assert_eq!(
+            SwizzlePlan::<3, 2>::new([0, 3]),
+            Err(SwizzleError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        assert_eq!(
+            SwizzlePlan::<3>::try_from([1, 0, 2]).unwrap(),
+            Permutation::new([1, 0, 2]).unwrap().into()
+        );
+    }
+
+    #[test]
+    fn test_plan_parse() {
+        let plan: SwizzlePlan<3> = Vec3Pattern::parse("zyx").unwrap().into();
+        assert_eq!(plan.indices(), [2, 1, 0]);
+        assert_eq!(
+            "zzx".parse::<SwizzlePlan<3>>().unwrap().indices(),
+            [2, 2, 0]
+        );
+        assert_eq!("zx".parse::<SwizzlePlan<3, 2>>().unwrap().indices(), [2, 0]);
+        assert_eq!(
+            "xw".parse::<SwizzlePlan<3, 2>>(),
+            Err(SwizzleError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        assert_eq!(
+            "xq".parse::<SwizzlePlan<3, 2>>(),
+            Err(SwizzleError::InvalidAxis)
+        );
+        assert_eq!(
+            "xyz".parse::<SwizzlePlan<3, 2>>(),
+            Err(SwizzleError::WrongArity {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_plan_apply() {
+        let plan: SwizzlePlan<3> = "zxx".parse().unwrap();
+        assert_eq!(plan.apply::<_, Vec3>(&V), Vec3 { x: 3, y: 1, z: 1 });
+
+        let mut items = [V, Vec3 { x: 4, y: 5, z: 6 }];
+        plan.apply_slice_in_place(&mut items);
+        assert_eq!(
+            items,
+            [Vec3 { x: 3, y: 1, z: 1 }, Vec3 { x: 6, y: 4, z: 4 }]
+        );
+
+        let plan: SwizzlePlan<3, 2> = "yz".parse().unwrap();
+        let mut dst = [Vec2 { x: 0, y: 0 }, Vec2 { x: 0, y: 0 }];
+        plan.apply_slice(&items, &mut dst);
+        assert_eq!(dst, [Vec2 { x: 1, y: 1 }, Vec2 { x: 4, y: 4 }]);
+    }
+
+    #[test]
+    #[should_panic = "swizzle slice lengths differ"]
+    fn test_plan_apply_slice_lengths() {
+        let plan = SwizzlePlan::<3, 2>::new([0, 1]).unwrap();
+        plan.apply_slice(&[V], &mut [Vec2 { x: 0, y: 0 }, Vec2 { x: 0, y: 0 }]);
+    }
+}
@@ -0,0 +1,128 @@
+/// Defines a generic struct of the prebuilt types, with the given fields and index of each, its
+/// constructors, its array conversions, and its positional access.
+macro_rules! prebuilt {
+    ($( #[$meta:meta] )* $name:ident($len:literal) { $( $attr:ident: $index:literal ),+ }) => {
+        $( #[$meta] )*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[repr(C)]
+        pub struct $name<T> {
+            $(
+                #[doc = concat!("The `", stringify!($attr), "` component.")]
+                pub $attr: T,
+            )+
+        }
+
+        impl<T> $name<T> {
+            /// Create an instance from its components.
+            #[must_use]
+            #[inline]
+            pub const fn new($( $attr: T ),+) -> Self {
+                Self { $( $attr ),+ }
+            }
+        }
+
+        impl<T: Copy> $name<T> {
+            /// Create an instance with every component set to `value`.
+            #[must_use]
+            #[inline]
+            pub const fn splat(value: T) -> Self {
+                Self { $( $attr: value ),+ }
+            }
+
+            /// Returns the components as an array.
+            #[must_use]
+            #[inline]
+            pub const fn to_array(&self) -> [T; $len] {
+                [ $( self.$attr ),+ ]
+            }
+        }
+
+        impl<T> From<[T; $len]> for $name<T> {
+            fn from([ $( $attr ),+ ]: [T; $len]) -> Self {
+                Self { $( $attr ),+ }
+            }
+        }
+
+        impl<T> From<$name<T>> for [T; $len] {
+            fn from(value: $name<T>) -> Self {
+                [ $( value.$attr ),+ ]
+            }
+        }
+
+        // SAFETY: the struct is `#[repr(C)]` with only fields of type `T`, so it has no padding, and
+        // it is zeroed or has any bit pattern exactly when each of its fields does.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for $name<T> {}
+
+        // SAFETY: as above.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for $name<T> {}
+
+        impl<T: Copy> $crate::Components<$len> for $name<T> {
+            type Component = T;
+
+            #[inline]
+            fn get(&self, i: usize) -> T {
+                match i {
+                    $( $index => self.$attr, )+
+                    _ => panic!("swizzle index out of range"),
+                }
+            }
+
+            #[inline]
+            fn from_fn(mut f: impl FnMut(usize) -> T) -> Self {
+                Self { $( $attr: f($index) ),+ }
+            }
+        }
+    };
+}
+
+pub(crate) use prebuilt;
+
+/// Defines zero-copy views of the leading components of a prebuilt type as a smaller one, e.g.
+/// `xyz_view` of a `Vec4` as a `Vec3`.
+macro_rules! prefix_view {
+    ($type:ident => $dst_type:ident { $( $attr:ident ),+ }) => {
+        $crate::__private::paste! {
+            impl<T> $type<T> {
+                #[doc = "Returns a reference to the leading components as a `" $dst_type "`, without copying."]
+                #[must_use]
+                #[inline]
+                pub const fn [<$( $attr )+ _view>](&self) -> &$dst_type<T> {
+                    const {
+                        $(
+                            assert!(
+                                ::core::mem::offset_of!($type<T>, $attr)
+                                    == ::core::mem::offset_of!($dst_type<T>, $attr)
+                            );
+                        )+
+                    }
+                    // SAFETY: both types are `#[repr(C)]` with only fields of type `T`, and the
+                    // components of the view are the leading components of the struct, as checked
+                    // above.
+                    unsafe { &*(self as *const Self).cast::<$dst_type<T>>() }
+                }
+
+                #[doc = "Returns a mutable reference to the leading components as a `" $dst_type "`, without copying."]
+                #[must_use]
+                #[inline]
+                pub const fn [<$( $attr )+ _view_mut>](&mut self) -> &mut $dst_type<T> {
+                    const {
+                        $(
+                            assert!(
+                                ::core::mem::offset_of!($type<T>, $attr)
+                                    == ::core::mem::offset_of!($dst_type<T>, $attr)
+                            );
+                        )+
+                    }
+                    // SAFETY: as above.
+                    unsafe { &mut *(self as *mut Self).cast::<$dst_type<T>>() }
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use prefix_view;
@@ -0,0 +1,1100 @@
+/// Macro for generating the named channel order conversions of a color struct.
+///
+/// Image formats disagree on the order of the channels, but only a handful of orders are used in
+/// practice. Rather than generating every swizzle, `swizzle_color_orders!` generates a named
+/// conversion to each common order, and its inverse, which is much faster to compile.
+///
+/// The fields are given in the order red, green, blue, and optionally alpha, whatever their names.
+/// Like `swizzle!`, the macro is invoked inside an `impl` block of the struct.
+///
+/// # Basic Usage
+///
+/// ```rust
+/// use swizzle::swizzle_color_orders;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// impl Rgba {
+///     swizzle_color_orders!(Rgba { r, g, b, a });
+/// }
+///
+/// let c = Rgba { r: 1, g: 2, b: 3, a: 4 };
+/// let argb = c.to_argb(); // Rgba { r: 4, g: 1, b: 2, a: 3 }
+/// assert_eq!(argb, Rgba { r: 4, g: 1, b: 2, a: 3 });
+/// assert_eq!(Rgba::from_argb(&argb), c);
+/// ```
+///
+/// # Generated Functions
+///
+/// For a color with alpha, each function returns a color with the values of the fields reordered:
+///
+/// - `to_bgra(&self)` and `from_bgra(&Self)` → swaps the red and blue channels
+/// - `to_argb(&self)` → moves alpha to the first field, and `from_argb(&Self)` moves it back to
+///   the last
+/// - `to_abgr(&self)` and `from_abgr(&Self)` → reverses the channels
+///
+/// For a color without alpha, `to_bgr(&self)` and `from_bgr(&Self)` swap the red and blue channels.
+///
+/// Each `from_*` function undoes the matching `to_*` method, so a color read from a buffer in
+/// another order can be converted with `from_*`, and written back with `to_*`.
+///
+/// # Normalized Conversions
+///
+/// Prefixing the invocation with `normalized`, and naming a color type with the same field names
+/// after `=>`, instead generates conversions to that type that also convert each channel between
+/// normalized integers and floats with [`Normalize`](crate::Normalize), e.g. `u8` from `0` to `255`
+/// to `f32` from `0.0` to `1.0`, or back with rounding. Doing both in one function saves a second
+/// pass over image data. The functions are named after the order, e.g. `bgra_normalized(&self)`,
+/// and are generated for every order, including `rgba_normalized` and `rgb_normalized`, which
+/// keep the channels in place.
+///
+/// Floats are rounded to the nearest integer by default. Graphics APIs specify different
+/// quantization, so a [`Rounding`](crate::Rounding) can be given in parentheses instead, e.g.
+/// `normalized(Trunc)` to match a GPU that truncates.
+///
+/// ```rust
+/// use swizzle::swizzle_color_orders;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Rgba8 { r: u8, g: u8, b: u8, a: u8 }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct LinRgba32 { r: f32, g: f32, b: f32, a: f32 }
+///
+/// impl Rgba8 {
+///     swizzle_color_orders!(normalized Rgba8 => LinRgba32 { r, g, b, a });
+/// }
+///
+/// impl LinRgba32 {
+///     swizzle_color_orders!(normalized LinRgba32 => Rgba8 { r, g, b, a });
+/// }
+///
+/// let c = Rgba8 { r: 255, g: 0, b: 51, a: 255 };
+/// let bgra = c.bgra_normalized();
+/// assert_eq!(bgra, LinRgba32 { r: 0.2, g: 0.0, b: 1.0, a: 1.0 });
+/// assert_eq!(bgra.bgra_normalized(), c);
+///
+/// struct Rgb8 { r: u8, g: u8, b: u8 }
+///
+/// struct LinRgb32 { r: f32, g: f32, b: f32 }
+///
+/// impl LinRgb32 {
+///     swizzle_color_orders!(normalized(Floor) LinRgb32 => Rgb8 { r, g, b });
+/// }
+///
+/// let floored = LinRgb32 { r: 0.5, g: 0.0, b: 1.0 }.bgr_normalized();
+/// assert_eq!([floored.r, floored.g, floored.b], [255, 0, 127]);
+/// ```
+///
+/// # Narrowing Conversions
+///
+/// Prefixing the invocation with `saturating` or `wrapping` instead converts each channel to the
+/// integer type of the other color with [`Narrow`](crate::Narrow), e.g. 16-bit channels to 8-bit,
+/// rather than with the wrapping of `as`. `saturating` clamps channels that don't fit to the
+/// range of the destination, which is what image pipelines almost always want, and `wrapping`
+/// keeps their low bits, like `as`. The functions are named after the order, e.g.
+/// `bgra_saturating(&self)` and `bgra_wrapping(&self)`.
+///
+/// With the `half` feature enabled, the channels may also be narrowed from `f32` or `f64` to
+/// `half::f16`, with `saturating` clamping to the range of `f16` and `wrapping` rounding to
+/// infinity, or widened back from `f16`, for half-precision vertex data. `normalized` conversions
+/// support `f16` like `f32`, and `clamped` conversions can widen `f16` to `f32` or `f64`. With the
+/// `fixed` feature enabled, `saturating` and `wrapping` also convert between integers or floats
+/// and the fixed-point numbers of the `fixed` crate, such as `I16F16`.
+///
+/// ```rust
+/// use swizzle::swizzle_color_orders;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Rgba8 { r: u8, g: u8, b: u8, a: u8 }
+///
+/// struct Rgba16 { r: u16, g: u16, b: u16, a: u16 }
+///
+/// impl Rgba16 {
+///     swizzle_color_orders!(saturating Rgba16 => Rgba8 { r, g, b, a });
+///     swizzle_color_orders!(wrapping Rgba16 => Rgba8 { r, g, b, a });
+/// }
+///
+/// let c = Rgba16 { r: 300, g: 20, b: 10, a: 255 };
+/// assert_eq!(c.bgra_saturating(), Rgba8 { r: 10, g: 20, b: 255, a: 255 });
+/// assert_eq!(c.bgra_wrapping(), Rgba8 { r: 10, g: 20, b: 44, a: 255 });
+/// ```
+///
+/// # Clamped Conversions
+///
+/// Prefixing the invocation with `clamped` and a range in parentheses instead clamps each channel
+/// to the range while reordering, e.g. HDR colors to `0.0..=1.0` before they are stored in an
+/// LDR image, in the same pass. The channels are then converted to the type of the other color
+/// with `From`, so it may have the same or wider channels. The functions are named after the
+/// order, e.g. `bgra_clamped(&self)`, and channels that can't be compared, such as `NaN`, are
+/// passed through unchanged.
+///
+/// ```rust
+/// use swizzle::swizzle_color_orders;
+///
+/// struct HdrRgb { r: f32, g: f32, b: f32 }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct LdrRgb { r: f32, g: f32, b: f32 }
+///
+/// impl HdrRgb {
+///     swizzle_color_orders!(clamped(0.0..=1.0) HdrRgb => LdrRgb { r, g, b });
+/// }
+///
+/// let c = HdrRgb { r: 4.5, g: 0.25, b: -0.1 };
+/// assert_eq!(c.bgr_clamped(), LdrRgb { r: 0.0, g: 0.25, b: 1.0 });
+/// ```
+#[macro_export]
+macro_rules! swizzle_color_orders {
+    (normalized($rounding:ident) $type:path => $dst_type:path { $( $attr:ident ),+ $(,)? }) => {
+        $crate::swizzle_color_orders!(@orders normalized $dst_type { $( $attr ),+ }
+            [$crate::Normalize::normalize_rounded, $crate::Rounding::$rounding]
+            ["between normalized integers and floats, rounding with `Rounding::", stringify!($rounding), "`"]
+        );
+    };
+    (normalized $type:path => $( $rest:tt )+) => {
+        $crate::swizzle_color_orders!(normalized(Round) $type => $( $rest )+);
+    };
+    (saturating $type:path => $dst_type:path { $( $attr:ident ),+ $(,)? }) => {
+        $crate::swizzle_color_orders!(@orders saturating $dst_type { $( $attr ),+ }
+            [$crate::Narrow::saturate]
+            ["clamping each channel to its range"]
+        );
+    };
+    (wrapping $type:path => $dst_type:path { $( $attr:ident ),+ $(,)? }) => {
+        $crate::swizzle_color_orders!(@orders wrapping $dst_type { $( $attr ),+ }
+            [$crate::Narrow::wrap]
+            ["keeping the low bits of each channel, like `as`"]
+        );
+    };
+    (clamped($range:expr) $type:path => $dst_type:path { $( $attr:ident ),+ $(,)? }) => {
+        $crate::swizzle_color_orders!(@orders clamped $dst_type { $( $attr ),+ }
+            [$crate::__private::clamp, &($range)]
+            ["clamping each channel to `", stringify!($range), "`"]
+        );
+    };
+    (@orders $suffix:ident $dst_type:path { $r:ident, $g:ident, $b:ident, $a:ident } $convert:tt $doc:tt) => {
+        $crate::__private::paste! {
+            $crate::swizzle_color_orders!(@convert [<rgba_ $suffix>] "RGBA" $dst_type {
+                $r: $r, $g: $g, $b: $b, $a: $a
+            } $convert $doc);
+            $crate::swizzle_color_orders!(@convert [<bgra_ $suffix>] "BGRA" $dst_type {
+                $r: $b, $g: $g, $b: $r, $a: $a
+            } $convert $doc);
+            $crate::swizzle_color_orders!(@convert [<argb_ $suffix>] "ARGB" $dst_type {
+                $r: $a, $g: $r, $b: $g, $a: $b
+            } $convert $doc);
+            $crate::swizzle_color_orders!(@convert [<abgr_ $suffix>] "ABGR" $dst_type {
+                $r: $a, $g: $b, $b: $g, $a: $r
+            } $convert $doc);
+        }
+    };
+    (@orders $suffix:ident $dst_type:path { $r:ident, $g:ident, $b:ident } $convert:tt $doc:tt) => {
+        $crate::__private::paste! {
+            $crate::swizzle_color_orders!(@convert [<rgb_ $suffix>] "RGB" $dst_type {
+                $r: $r, $g: $g, $b: $b
+            } $convert $doc);
+            $crate::swizzle_color_orders!(@convert [<bgr_ $suffix>] "BGR" $dst_type {
+                $r: $b, $g: $g, $b: $r
+            } $convert $doc);
+        }
+    };
+    (
+        @convert $name:ident $order:literal $dst_type:path {
+            $( $dst_attr:ident: $src_attr:ident ),+
+        } $convert:tt [$( $doc:tt )+]
+    ) => {
+        #[doc = concat!(
+            "Returns the color with its channels in ", $order, " order, converted to `",
+            stringify!($dst_type), "` ", $( $doc )+, "."
+        )]
+        #[must_use]
+        #[inline]
+        pub fn $name(&self) -> $dst_type {
+            $dst_type { $( $dst_attr: $crate::swizzle_color_orders!(@call $convert self.$src_attr) ),+ }
+        }
+    };
+    (@call [$convert:path $(, $arg:expr )*] $value:expr) => {
+        $convert($value $(, $arg )*)
+    };
+    ($type:path { $r:ident, $g:ident, $b:ident, $a:ident $(,)? }) => {
+        /// Returns the color with its channels in BGRA order.
+        #[must_use]
+        #[inline]
+        pub const fn to_bgra(&self) -> Self {
+            Self {
+                $r: self.$b,
+                $g: self.$g,
+                $b: self.$r,
+                $a: self.$a,
+            }
+        }
+
+        /// Returns the color with its channels converted from BGRA order, undoing `to_bgra`.
+        #[must_use]
+        #[inline]
+        pub const fn from_bgra(color: &Self) -> Self {
+            Self {
+                $r: color.$b,
+                $g: color.$g,
+                $b: color.$r,
+                $a: color.$a,
+            }
+        }
+
+        /// Returns the color with its channels in ARGB order.
+        #[must_use]
+        #[inline]
+        pub const fn to_argb(&self) -> Self {
+            Self {
+                $r: self.$a,
+                $g: self.$r,
+                $b: self.$g,
+                $a: self.$b,
+            }
+        }
+
+        /// Returns the color with its channels converted from ARGB order, undoing `to_argb`.
+        #[must_use]
+        #[inline]
+        pub const fn from_argb(color: &Self) -> Self {
+            Self {
+                $r: color.$g,
+                $g: color.$b,
+                $b: color.$a,
+                $a: color.$r,
+            }
+        }
+
+        /// Returns the color with its channels in ABGR order.
+        #[must_use]
+        #[inline]
+        pub const fn to_abgr(&self) -> Self {
+            Self {
+                $r: self.$a,
+                $g: self.$b,
+                $b: self.$g,
+                $a: self.$r,
+            }
+        }
+
+        /// Returns the color with its channels converted from ABGR order, undoing `to_abgr`.
+        #[must_use]
+        #[inline]
+        pub const fn from_abgr(color: &Self) -> Self {
+            Self {
+                $r: color.$a,
+                $g: color.$b,
+                $b: color.$g,
+                $a: color.$r,
+            }
+        }
+    };
+    ($type:path { $r:ident, $g:ident, $b:ident $(,)? }) => {
+        /// Returns the color with its channels in BGR order.
+        #[must_use]
+        #[inline]
+        pub const fn to_bgr(&self) -> Self {
+            Self {
+                $r: self.$b,
+                $g: self.$g,
+                $b: self.$r,
+            }
+        }
+
+        /// Returns the color with its channels converted from BGR order, undoing `to_bgr`.
+        #[must_use]
+        #[inline]
+        pub const fn from_bgr(color: &Self) -> Self {
+            Self {
+                $r: color.$b,
+                $g: color.$g,
+                $b: color.$r,
+            }
+        }
+    };
+}
+
+/// Macro for generating the named conversions between coordinate system conventions of a 3D
+/// vector struct.
+///
+/// Engines and modelling tools disagree on which axis points up, and on the handedness of the
+/// coordinate system. `swizzle_coordinate_systems!` generates the few conversions that interop
+/// needs, with names that say what they do. The fields are given in the order x, y, z, whatever
+/// their names. Like `swizzle!`, the macro is invoked inside an `impl` block of the struct.
+///
+/// # Basic Usage
+///
+/// ```rust
+/// use swizzle::swizzle_coordinate_systems;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// impl Vec3 {
+///     swizzle_coordinate_systems!(negate Vec3 { x, y, z });
+/// }
+///
+/// let up = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
+/// assert_eq!(up.to_z_up(), Vec3 { x: 0.0, y: 0.0, z: 1.0 });
+///
+/// // Right-handed Y-up, e.g. OpenGL, to right-handed Z-up, e.g. Blender.
+/// let forward = Vec3 { x: 0.0, y: 0.0, z: -1.0 };
+/// assert_eq!(forward.to_z_up_same_handedness(), Vec3 { x: 0.0, y: 1.0, z: 0.0 });
+/// ```
+///
+/// # Generated Functions
+///
+/// - `to_z_up()` and `to_y_up()` → swaps the y and z fields, the `xzy` swizzle, converting between
+///   Y-up and Z-up. Swapping two axes also flips the handedness, e.g. from left-handed Y-up to
+///   right-handed Z-up.
+///
+/// Prefixing the invocation with `negate` also generates the conversions that negate an axis, which
+/// require the field type to implement `Neg`:
+///
+/// - `to_z_up_same_handedness()` → `(x, -z, y)`, converting from Y-up to Z-up while keeping the
+///   handedness, and `to_y_up_same_handedness()` → `(x, z, -y)`, undoing it
+/// - `flip_handedness()` → `(x, y, -z)`, converting between left- and right-handed systems with the
+///   same up axis
+#[macro_export]
+macro_rules! swizzle_coordinate_systems {
+    (negate $type:path { $x:ident, $y:ident, $z:ident $(,)? }) => {
+        $crate::swizzle_coordinate_systems!($type { $x, $y, $z });
+
+        /// Returns the vector converted from Y-up to Z-up, keeping the handedness: `(x, -z, y)`.
+        #[must_use]
+        #[inline]
+        pub fn to_z_up_same_handedness(&self) -> Self {
+            Self { $x: self.$x, $y: -self.$z, $z: self.$y }
+        }
+
+        /// Returns the vector converted from Z-up to Y-up, keeping the handedness: `(x, z, -y)`.
+        #[must_use]
+        #[inline]
+        pub fn to_y_up_same_handedness(&self) -> Self {
+            Self { $x: self.$x, $y: self.$z, $z: -self.$y }
+        }
+
+        /// Returns the vector converted between left- and right-handed systems with the same up
+        /// axis: `(x, y, -z)`.
+        #[must_use]
+        #[inline]
+        pub fn flip_handedness(&self) -> Self {
+            Self { $x: self.$x, $y: self.$y, $z: -self.$z }
+        }
+    };
+    ($type:path { $x:ident, $y:ident, $z:ident $(,)? }) => {
+        /// Returns the vector converted from Y-up to Z-up by swapping the y and z fields, which
+        /// also flips the handedness.
+        #[must_use]
+        #[inline]
+        pub const fn to_z_up(&self) -> Self {
+            Self { $x: self.$x, $y: self.$z, $z: self.$y }
+        }
+
+        /// Returns the vector converted from Z-up to Y-up by swapping the y and z fields, which
+        /// also flips the handedness.
+        #[must_use]
+        #[inline]
+        pub const fn to_y_up(&self) -> Self {
+            Self { $x: self.$x, $y: self.$z, $z: self.$y }
+        }
+    };
+}
+
+/// Macro for generating the conversions between the two common storage orders of a quaternion.
+///
+/// Math libraries disagree on whether the scalar part of a quaternion is stored first, `wxyz`, or
+/// last, `xyzw`, and mixing them up silently produces wrong rotations. `swizzle_quaternion_orders!`
+/// generates named conversions between them, for any struct with 4 fields. The fields are given in
+/// the order x, y, z, w, whatever their names. Like `swizzle!`, the macro is invoked inside an
+/// `impl` block of the struct.
+///
+/// # Basic Usage
+///
+/// ```rust
+/// use swizzle::swizzle_quaternion_orders;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Quat {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+///     w: f32,
+/// }
+///
+/// impl Quat {
+///     swizzle_quaternion_orders!(Quat { x, y, z, w });
+/// }
+///
+/// let identity = Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+/// let scalar_first = identity.to_wxyz(); // Quat { x: 1.0, y: 0.0, z: 0.0, w: 0.0 }
+/// assert_eq!(scalar_first.to_xyzw(), identity);
+/// ```
+///
+/// # Generated Functions
+///
+/// - `to_wxyz()` → returns the quaternion with the fields reordered to store the scalar part first
+/// - `to_xyzw()` → returns the quaternion with the fields reordered to store the scalar part last,
+///   undoing `to_wxyz()`
+#[macro_export]
+macro_rules! swizzle_quaternion_orders {
+    ($type:path { $x:ident, $y:ident, $z:ident, $w:ident $(,)? }) => {
+        /// Returns the quaternion with the fields reordered to store the scalar part first, in
+        /// `wxyz` order.
+        #[must_use]
+        #[inline]
+        pub const fn to_wxyz(&self) -> Self {
+            Self { $x: self.$w, $y: self.$x, $z: self.$y, $w: self.$z }
+        }
+
+        /// Returns the quaternion with the fields reordered to store the scalar part last, in
+        /// `xyzw` order, undoing `to_wxyz`.
+        #[must_use]
+        #[inline]
+        pub const fn to_xyzw(&self) -> Self {
+            Self { $x: self.$y, $y: self.$z, $z: self.$w, $w: self.$x }
+        }
+    };
+}
+
+/// Macro for generating the flips of a 2D texture coordinate struct.
+///
+/// Graphics APIs disagree on whether the texture V axis points up, e.g. OpenGL, or down, e.g.
+/// DirectX and Vulkan. `swizzle_uv_flips!` generates the flips needed to convert between them. The
+/// fields are given in the order u, v, whatever their names. Like `swizzle!`, the macro is invoked
+/// inside an `impl` block of the struct.
+///
+/// # Basic Usage
+///
+/// ```rust
+/// use swizzle::swizzle_uv_flips;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Uv {
+///     u: f32,
+///     v: f32,
+/// }
+///
+/// impl Uv {
+///     swizzle_uv_flips!(normalized Uv: f32 { u, v });
+/// }
+///
+/// let uv = Uv { u: 0.25, v: 0.75 };
+/// assert_eq!(uv.flip_y(), Uv { u: 0.25, v: 0.25 });
+/// assert_eq!(uv.transpose(), Uv { u: 0.75, v: 0.25 });
+/// ```
+///
+/// # Generated Functions
+///
+/// - `transpose()` → swaps the u and v fields, the `vu` swizzle
+/// - `flip_x()` → negates u, which requires the field type to implement `Neg`
+/// - `flip_y()` → negates v, which requires the field type to implement `Neg`
+///
+/// Prefixing the invocation with `normalized`, and giving the field type, flips normalized
+/// coordinates in the range `0..=1` instead, with `flip_x()` returning `1 - u` and `flip_y()`
+/// returning `1 - v`. This requires the field type to implement `From<u8>` and `Sub`.
+#[macro_export]
+macro_rules! swizzle_uv_flips {
+    (normalized $type:path: $component:ty { $u:ident, $v:ident $(,)? }) => {
+        $crate::swizzle_uv_flips!(@transpose $u, $v);
+
+        /// Returns the coordinates flipped horizontally: `(1 - u, v)`.
+        #[must_use]
+        #[inline]
+        pub fn flip_x(&self) -> Self {
+            Self {
+                $u: <$component as ::core::convert::From<u8>>::from(1) - self.$u,
+                $v: self.$v,
+            }
+        }
+
+        /// Returns the coordinates flipped vertically: `(u, 1 - v)`.
+        #[must_use]
+        #[inline]
+        pub fn flip_y(&self) -> Self {
+            Self {
+                $u: self.$u,
+                $v: <$component as ::core::convert::From<u8>>::from(1) - self.$v,
+            }
+        }
+    };
+    ($type:path { $u:ident, $v:ident $(,)? }) => {
+        $crate::swizzle_uv_flips!(@transpose $u, $v);
+
+        /// Returns the coordinates flipped horizontally: `(-u, v)`.
+        #[must_use]
+        #[inline]
+        pub fn flip_x(&self) -> Self {
+            Self { $u: -self.$u, $v: self.$v }
+        }
+
+        /// Returns the coordinates flipped vertically: `(u, -v)`.
+        #[must_use]
+        #[inline]
+        pub fn flip_y(&self) -> Self {
+            Self { $u: self.$u, $v: -self.$v }
+        }
+    };
+    (@transpose $u:ident, $v:ident) => {
+        /// Returns the coordinates with u and v swapped.
+        #[must_use]
+        #[inline]
+        pub const fn transpose(&self) -> Self {
+            Self { $u: self.$v, $v: self.$u }
+        }
+    };
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_color_orders() {
+        #[derive(Debug, PartialEq)]
+        struct Argb {
+            alpha: u8,
+            red: u8,
+            green: u8,
+            blue: u8,
+        }
+
+        impl Argb {
+            swizzle_color_orders!(Argb {
+                red,
+                green,
+                blue,
+                alpha
+            });
+        }
+
+        let c = Argb {
+            alpha: 4,
+            red: 1,
+            green: 2,
+            blue: 3,
+        };
+
+        let fields = |c: Argb| [c.red, c.green, c.blue, c.alpha];
+        assert_eq!(fields(c.to_bgra()), [3, 2, 1, 4]);
+        assert_eq!(fields(c.to_argb()), [4, 1, 2, 3]);
+        assert_eq!(fields(c.to_abgr()), [4, 3, 2, 1]);
+        assert_eq!(Argb::from_bgra(&c.to_bgra()), c);
+        assert_eq!(Argb::from_argb(&c.to_argb()), c);
+        assert_eq!(Argb::from_abgr(&c.to_abgr()), c);
+    }
+
+    #[test]
+    fn test_color_orders_without_alpha() {
+        #[derive(Debug, PartialEq)]
+        struct Rgb<T> {
+            r: T,
+            g: T,
+            b: T,
+        }
+
+        impl<T: Copy> Rgb<T> {
+            swizzle_color_orders!(Rgb<T> { r, g, b });
+        }
+
+        let c = Rgb { r: 1, g: 2, b: 3 };
+        assert_eq!(c.to_bgr(), Rgb { r: 3, g: 2, b: 1 });
+        assert_eq!(Rgb::from_bgr(&c.to_bgr()), c);
+    }
+
+    #[test]
+    fn test_color_orders_normalized() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Rgba<T> {
+            r: T,
+            g: T,
+            b: T,
+            a: T,
+        }
+
+        impl Rgba<u8> {
+            swizzle_color_orders!(normalized Rgba<u8> => Rgba<f32> { r, g, b, a });
+        }
+
+        impl Rgba<f32> {
+            swizzle_color_orders!(normalized Rgba<f32> => Rgba<u8> { r, g, b, a });
+        }
+
+        let c = Rgba {
+            r: 0_u8,
+            g: 51,
+            b: 102,
+            a: 255,
+        };
+        let channels = |c: Rgba<f32>| [c.r, c.g, c.b, c.a];
+        assert_eq!(channels(c.rgba_normalized()), [0.0, 0.2, 0.4, 1.0]);
+        assert_eq!(channels(c.bgra_normalized()), [0.4, 0.2, 0.0, 1.0]);
+        assert_eq!(channels(c.argb_normalized()), [1.0, 0.0, 0.2, 0.4]);
+        assert_eq!(channels(c.abgr_normalized()), [1.0, 0.4, 0.2, 0.0]);
+        assert_eq!(c.rgba_normalized().rgba_normalized(), c);
+        assert_eq!(c.bgra_normalized().bgra_normalized(), c);
+
+        let f = Rgba {
+            r: 0.5_f32,
+            g: -1.0,
+            b: 2.0,
+            a: 0.999,
+        };
+        assert_eq!(
+            f.rgba_normalized(),
+            Rgba {
+                r: 128,
+                g: 0,
+                b: 255,
+                a: 255
+            }
+        );
+    }
+
+    #[test]
+    fn test_color_orders_normalized_rounding() {
+        #[derive(Debug, PartialEq)]
+        struct Rgba<T> {
+            r: T,
+            g: T,
+            b: T,
+            a: T,
+        }
+
+        impl Rgba<f32> {
+            swizzle_color_orders!(normalized(Ceil) Rgba<f32> => Rgba<i8> { r, g, b, a });
+        }
+
+        impl Rgba<f64> {
+            swizzle_color_orders!(normalized(Trunc) Rgba<f64> => Rgba<i8> { r, g, b, a });
+        }
+
+        let c = Rgba {
+            r: 0.5_f32,
+            g: -0.5,
+            b: 1.0,
+            a: 0.0,
+        };
+        assert_eq!(
+            c.rgba_normalized(),
+            Rgba {
+                r: 64,
+                g: -63,
+                b: 127,
+                a: 0
+            }
+        );
+
+        let c = Rgba {
+            r: 0.5_f64,
+            g: -0.5,
+            b: 1.0,
+            a: 0.0,
+        };
+        assert_eq!(
+            c.abgr_normalized(),
+            Rgba {
+                r: 0,
+                g: 127,
+                b: -63,
+                a: 63
+            }
+        );
+    }
+
+    #[test]
+    fn test_color_orders_narrowing() {
+        #[derive(Debug, PartialEq)]
+        struct Rgba<T> {
+            r: T,
+            g: T,
+            b: T,
+            a: T,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Rgb<T> {
+            r: T,
+            g: T,
+            b: T,
+        }
+
+        impl Rgba<i32> {
+            swizzle_color_orders!(saturating Rgba<i32> => Rgba<u8> { r, g, b, a });
+            swizzle_color_orders!(wrapping Rgba<i32> => Rgba<u8> { r, g, b, a });
+        }
+
+        impl Rgb<u16> {
+            swizzle_color_orders!(saturating Rgb<u16> => Rgb<i8> { r, g, b });
+        }
+
+        let c = Rgba {
+            r: -1,
+            g: 256,
+            b: 128,
+            a: 1000,
+        };
+        assert_eq!(
+            c.rgba_saturating(),
+            Rgba {
+                r: 0,
+                g: 255,
+                b: 128,
+                a: 255
+            }
+        );
+        assert_eq!(
+            c.argb_saturating(),
+            Rgba {
+                r: 255,
+                g: 0,
+                b: 255,
+                a: 128
+            }
+        );
+        assert_eq!(
+            c.abgr_wrapping(),
+            Rgba {
+                r: 232,
+                g: 128,
+                b: 0,
+                a: 255
+            }
+        );
+
+        let c = Rgb { r: 1, g: 2, b: 500 };
+        assert_eq!(c.bgr_saturating(), Rgb { r: 127, g: 2, b: 1 });
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_color_orders_f16() {
+        use half::f16;
+
+        #[derive(Debug, PartialEq)]
+        struct Rgb<T> {
+            r: T,
+            g: T,
+            b: T,
+        }
+
+        impl Rgb<f32> {
+            swizzle_color_orders!(saturating Rgb<f32> => Rgb<f16> { r, g, b });
+            swizzle_color_orders!(wrapping Rgb<f32> => Rgb<f16> { r, g, b });
+        }
+
+        impl Rgb<f16> {
+            swizzle_color_orders!(saturating Rgb<f16> => Rgb<f64> { r, g, b });
+            swizzle_color_orders!(clamped(f16::ZERO..=f16::ONE) Rgb<f16> => Rgb<f32> { r, g, b });
+        }
+
+        impl Rgb<u8> {
+            swizzle_color_orders!(normalized Rgb<u8> => Rgb<f16> { r, g, b });
+        }
+
+        let c = Rgb {
+            r: 0.5_f32,
+            g: 1e6,
+            b: -0.25,
+        };
+        let half = c.bgr_saturating();
+        assert_eq!(
+            half,
+            Rgb {
+                r: f16::from_f32(-0.25),
+                g: f16::MAX,
+                b: f16::from_f32(0.5)
+            }
+        );
+        assert_eq!(c.rgb_wrapping().g, f16::INFINITY);
+        assert_eq!(
+            half.bgr_saturating(),
+            Rgb {
+                r: 0.5,
+                g: 65504.0,
+                b: -0.25
+            }
+        );
+        assert_eq!(
+            half.rgb_clamped(),
+            Rgb {
+                r: 0.0,
+                g: 1.0,
+                b: 0.5
+            }
+        );
+
+        let c = Rgb {
+            r: 255_u8,
+            g: 0,
+            b: 51,
+        };
+        assert_eq!(
+            c.bgr_normalized(),
+            Rgb {
+                r: f16::from_f32(0.2),
+                g: f16::ZERO,
+                b: f16::ONE
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn test_color_orders_fixed() {
+        use fixed::types::{I8F8, U0F16};
+
+        #[derive(Debug, PartialEq)]
+        struct Rgb<T> {
+            r: T,
+            g: T,
+            b: T,
+        }
+
+        impl Rgb<f32> {
+            swizzle_color_orders!(saturating Rgb<f32> => Rgb<U0F16> { r, g, b });
+        }
+
+        impl Rgb<I8F8> {
+            swizzle_color_orders!(saturating Rgb<I8F8> => Rgb<f32> { r, g, b });
+            swizzle_color_orders!(wrapping Rgb<I8F8> => Rgb<u8> { r, g, b });
+        }
+
+        let c = Rgb {
+            r: 0.5_f32,
+            g: 2.0,
+            b: -0.25,
+        };
+        assert_eq!(
+            c.bgr_saturating(),
+            Rgb {
+                r: U0F16::ZERO,
+                g: U0F16::MAX,
+                b: U0F16::from_num(0.5)
+            }
+        );
+
+        let c = Rgb {
+            r: I8F8::from_num(1.5),
+            g: I8F8::from_num(-3),
+            b: I8F8::from_num(100.25),
+        };
+        assert_eq!(
+            c.bgr_saturating(),
+            Rgb {
+                r: 100.25,
+                g: -3.0,
+                b: 1.5
+            }
+        );
+        assert_eq!(
+            c.rgb_wrapping(),
+            Rgb {
+                r: 1,
+                g: 253,
+                b: 100
+            }
+        );
+    }
+
+    #[test]
+    fn test_color_orders_clamped() {
+        #[derive(Debug, PartialEq)]
+        struct Rgba<T> {
+            r: T,
+            g: T,
+            b: T,
+            a: T,
+        }
+
+        const MAX: u8 = 200;
+
+        impl Rgba<f32> {
+            swizzle_color_orders!(clamped(0.0..=1.0) Rgba<f32> => Rgba<f64> { r, g, b, a });
+        }
+
+        impl Rgba<u8> {
+            swizzle_color_orders!(clamped(16..=MAX) Rgba<u8> => Rgba<u8> { r, g, b, a });
+        }
+
+        let c = Rgba {
+            r: 2.0_f32,
+            g: 0.5,
+            b: -1.0,
+            a: f32::NAN,
+        };
+        let argb = c.argb_clamped();
+        assert_eq!([argb.g, argb.b, argb.a], [1.0, 0.5, 0.0]);
+        assert!(argb.r.is_nan());
+
+        let c = Rgba {
+            r: 0_u8,
+            g: 100,
+            b: 255,
+            a: 200,
+        };
+        assert_eq!(
+            c.bgra_clamped(),
+            Rgba {
+                r: 200,
+                g: 100,
+                b: 16,
+                a: 200
+            }
+        );
+    }
+
+    #[test]
+    fn test_color_orders_normalized_without_alpha() {
+        #[derive(Debug, PartialEq)]
+        struct Bgr16 {
+            b: u16,
+            g: u16,
+            r: u16,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Rgb32 {
+            r: f32,
+            g: f32,
+            b: f32,
+        }
+
+        impl Bgr16 {
+            swizzle_color_orders!(normalized Bgr16 => Rgb32 { r, g, b });
+        }
+
+        let c = Bgr16 {
+            b: u16::MAX,
+            g: 0,
+            r: 0,
+        };
+        assert_eq!(
+            c.rgb_normalized(),
+            Rgb32 {
+                r: 0.0,
+                g: 0.0,
+                b: 1.0
+            }
+        );
+        assert_eq!(
+            c.bgr_normalized(),
+            Rgb32 {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_coordinate_systems() {
+        #[derive(Debug, PartialEq)]
+        struct Vec3 {
+            x: i32,
+            y: i32,
+            z: i32,
+        }
+
+        impl Vec3 {
+            swizzle_coordinate_systems!(negate Vec3 { x, y, z });
+        }
+
+        let v = Vec3 { x: 1, y: 2, z: 3 };
+        assert_eq!(v.to_z_up(), Vec3 { x: 1, y: 3, z: 2 });
+        assert_eq!(v.to_z_up().to_y_up(), v);
+        assert_eq!(v.to_z_up_same_handedness(), Vec3 { x: 1, y: -3, z: 2 });
+        assert_eq!(v.to_z_up_same_handedness().to_y_up_same_handedness(), v);
+        assert_eq!(v.flip_handedness(), Vec3 { x: 1, y: 2, z: -3 });
+    }
+
+    #[test]
+    fn test_coordinate_systems_without_negation() {
+        #[derive(Debug, PartialEq)]
+        struct Vec3 {
+            x: u8,
+            y: u8,
+            z: u8,
+        }
+
+        impl Vec3 {
+            swizzle_coordinate_systems!(Vec3 { x, y, z });
+        }
+
+        const V: Vec3 = Vec3 { x: 1, y: 2, z: 3 }.to_z_up();
+        assert_eq!(V, Vec3 { x: 1, y: 3, z: 2 });
+    }
+
+    #[test]
+    fn test_quaternion_orders() {
+        #[derive(Debug, PartialEq)]
+        struct Quat {
+            i: u8,
+            j: u8,
+            k: u8,
+            r: u8,
+        }
+
+        impl Quat {
+            swizzle_quaternion_orders!(Quat { i, j, k, r });
+        }
+
+        let q = Quat {
+            i: 1,
+            j: 2,
+            k: 3,
+            r: 4,
+        };
+        let wxyz = q.to_wxyz();
+        assert_eq!([wxyz.i, wxyz.j, wxyz.k, wxyz.r], [4, 1, 2, 3]);
+        assert_eq!(wxyz.to_xyzw(), q);
+    }
+
+    #[test]
+    fn test_uv_flips() {
+        #[derive(Debug, PartialEq)]
+        struct Uv {
+            s: i32,
+            t: i32,
+        }
+
+        impl Uv {
+            swizzle_uv_flips!(Uv { s, t });
+        }
+
+        let uv = Uv { s: 1, t: 2 };
+        assert_eq!(uv.transpose(), Uv { s: 2, t: 1 });
+        assert_eq!(uv.flip_x(), Uv { s: -1, t: 2 });
+        assert_eq!(uv.flip_y(), Uv { s: 1, t: -2 });
+        assert_eq!(uv.flip_y().flip_y(), uv);
+    }
+
+    #[test]
+    fn test_uv_flips_normalized() {
+        #[derive(Debug, PartialEq)]
+        struct Uv<T> {
+            u: T,
+            v: T,
+        }
+
+        impl<T: Copy + From<u8> + core::ops::Sub<Output = T>> Uv<T> {
+            swizzle_uv_flips!(normalized Uv<T>: T { u, v });
+        }
+
+        let uv = Uv { u: 0.25, v: 0.5 };
+        assert_eq!(uv.flip_x(), Uv { u: 0.75, v: 0.5 });
+        assert_eq!(uv.flip_y(), Uv { u: 0.25, v: 0.5 });
+        assert_eq!(uv.transpose().flip_x(), Uv { u: 0.5, v: 0.25 });
+        assert_eq!(Uv { u: 0u8, v: 1 }.flip_y(), Uv { u: 0, v: 0 });
+    }
+}
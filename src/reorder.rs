@@ -0,0 +1,378 @@
+//! Reordering of the elements of slices, the bulk analogue of swizzling the fields of a struct,
+//! e.g. the rows of an image, the planes of a planar buffer, or the vertices of a mesh.
+//!
+//! As for swizzles, the element at each position is taken from the element at the index. The
+//! in-place permutations follow the cycles of the permutation, so they swap each element at most
+//! once and don't allocate, but they mark the indices as they go, so they take them mutably and
+//! restore them before returning.
+//!
+//! ```rust
+//! use swizzle::reorder;
+//!
+//! let mut rows = [10, 11, 20, 21, 30, 31];
+//! let mut idx = [2, 0, 1];
+//! reorder::permute_rows_in_place(&mut rows, 2, &mut idx).unwrap();
+//! assert_eq!(rows, [30, 31, 10, 11, 20, 21]);
+//! assert_eq!(idx, [2, 0, 1]);
+//!
+//! let mut gathered = [0; 3];
+//! reorder::gather(&[10, 20, 30], [2, 2, 0], &mut gathered);
+//! assert_eq!(gathered, [30, 30, 10]);
+//!
+//! let mut scattered = [0; 3];
+//! reorder::scatter(&[10, 20, 30], [2, 0, 1], &mut scattered);
+//! assert_eq!(scattered, [20, 30, 10]);
+//! ```
+
+use crate::{Permutation, SwizzleError};
+
+/// Set on an index once its position has been visited.
+const VISITED: usize = 1 << (usize::BITS - 1);
+
+/// Permute the elements of `items` in place, taking the element at each position from the
+/// element at the index.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::WrongArity`] if the number of indices doesn't match the number of
+/// elements, [`SwizzleError::IndexOutOfRange`] if any index is out of range, or
+/// [`SwizzleError::DuplicateIndex`] if any index appears more than once. The elements are
+/// unchanged on error.
+pub fn permute_in_place<T>(items: &mut [T], idx: &mut [usize]) -> Result<(), SwizzleError> {
+    check_permutation(idx, items.len())?;
+    for_each_swap(idx, |a, b| items.swap(a, b));
+    Ok(())
+}
+
+/// Permute the rows of `row_len` elements of `items` in place, taking the row at each position
+/// from the row at the index.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::WrongArity`] if the number of indices doesn't match the number of
+/// rows, [`SwizzleError::IndexOutOfRange`] if any index is out of range, or
+/// [`SwizzleError::DuplicateIndex`] if any index appears more than once. The elements are
+/// unchanged on error.
+///
+/// # Panics
+///
+/// Panics if the number of elements isn't a multiple of `row_len`.
+pub fn permute_rows_in_place<T>(
+    items: &mut [T],
+    row_len: usize,
+    idx: &mut [usize],
+) -> Result<(), SwizzleError> {
+    assert!(
+        row_len > 0 && items.len() % row_len == 0,
+        "element count is not a multiple of the row length"
+    );
+    check_permutation(idx, items.len() / row_len)?;
+    for_each_swap(idx, |a, b| swap_rows(items, row_len, a, b));
+    Ok(())
+}
+
+/// Permute the `N` equal blocks of `items` in place, e.g. the planes of a planar image, taking
+/// the block at each position from the block at the permutation's index.
+///
+/// # Panics
+///
+/// Panics if the number of elements isn't a multiple of `N`.
+pub fn permute_blocks_in_place<T, const N: usize>(items: &mut [T], permutation: Permutation<N>) {
+    assert!(
+        N > 0 && items.len() % N == 0,
+        "element count is not a multiple of the block count"
+    );
+    let block_len = items.len() / N;
+    let mut idx = permutation.indices();
+    if block_len > 0 {
+        for_each_swap(&mut idx, |a, b| swap_rows(items, block_len, a, b));
+    }
+}
+
+/// Fill `dst` with the elements of `src`, taking the element at each position from the element
+/// of `src` at the index. Unlike the permutations, an element may be taken more than once or not
+/// at all. The indices may be given as a slice or array, a [`Permutation`], or a
+/// [`SwizzlePlan`](crate::SwizzlePlan).
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range, or if there isn't one index for each element of
+/// `dst`.
+pub fn gather<T: Clone>(src: &[T], idx: impl AsRef<[usize]>, dst: &mut [T]) {
+    let idx = idx.as_ref();
+    assert_eq!(
+        idx.len(),
+        dst.len(),
+        "gather index and slice lengths differ"
+    );
+    assert!(
+        idx.iter().all(|&index| index < src.len()),
+        "gather index out of range"
+    );
+    for (to, &index) in dst.iter_mut().zip(idx) {
+        *to = src[index].clone();
+    }
+}
+
+/// Like [`gather`], but without checking that the indices are in range.
+///
+/// # Safety
+///
+/// All of the indices must be less than the length of `src`. This is checked by debug assertions,
+/// but calling it with an out of range index in a release build is undefined behavior.
+///
+/// # Panics
+///
+/// Panics if there isn't one index for each element of `dst`.
+pub unsafe fn gather_unchecked<T: Clone>(src: &[T], idx: impl AsRef<[usize]>, dst: &mut [T]) {
+    let idx = idx.as_ref();
+    assert_eq!(
+        idx.len(),
+        dst.len(),
+        "gather index and slice lengths differ"
+    );
+    debug_assert!(
+        idx.iter().all(|&index| index < src.len()),
+        "gather index out of range"
+    );
+    for (to, &index) in dst.iter_mut().zip(idx) {
+        // SAFETY: the caller guarantees that the index is in range.
+        *to = unsafe { src.get_unchecked(index) }.clone();
+    }
+}
+
+/// Write each element of `src` to the position of `dst` at the index, the inverse of [`gather`].
+/// If an index appears more than once, the last element written to it is kept, and positions that
+/// no index refers to are left unchanged. The indices may be given as a slice or array, a
+/// [`Permutation`], or a [`SwizzlePlan`](crate::SwizzlePlan).
+///
+/// # Panics
+///
+/// Panics if any of the indices is out of range, or if there isn't one index for each element of
+/// `src`.
+pub fn scatter<T: Clone>(src: &[T], idx: impl AsRef<[usize]>, dst: &mut [T]) {
+    let idx = idx.as_ref();
+    assert_eq!(
+        idx.len(),
+        src.len(),
+        "scatter index and slice lengths differ"
+    );
+    assert!(
+        idx.iter().all(|&index| index < dst.len()),
+        "scatter index out of range"
+    );
+    for (from, &index) in src.iter().zip(idx) {
+        dst[index] = from.clone();
+    }
+}
+
+/// Like [`scatter`], but without checking that the indices are in range.
+///
+/// # Safety
+///
+/// All of the indices must be less than the length of `dst`. This is checked by debug assertions,
+/// but calling it with an out of range index in a release build is undefined behavior.
+///
+/// # Panics
+///
+/// Panics if there isn't one index for each element of `src`.
+pub unsafe fn scatter_unchecked<T: Clone>(src: &[T], idx: impl AsRef<[usize]>, dst: &mut [T]) {
+    let idx = idx.as_ref();
+    assert_eq!(
+        idx.len(),
+        src.len(),
+        "scatter index and slice lengths differ"
+    );
+    debug_assert!(
+        idx.iter().all(|&index| index < dst.len()),
+        "scatter index out of range"
+    );
+    for (from, &index) in src.iter().zip(idx) {
+        // SAFETY: the caller guarantees that the index is in range.
+        *unsafe { dst.get_unchecked_mut(index) } = from.clone();
+    }
+}
+
+/// Check that `idx` is a permutation of `0..len`, leaving it unchanged.
+fn check_permutation(idx: &mut [usize], len: usize) -> Result<(), SwizzleError> {
+    if idx.len() != len {
+        return Err(SwizzleError::WrongArity {
+            expected: len,
+            found: idx.len(),
+        });
+    }
+    if let Some(&index) = idx.iter().find(|&&index| index >= len) {
+        return Err(SwizzleError::IndexOutOfRange { index, len });
+    }
+    let mut result = Ok(());
+    for i in 0..len {
+        let index = idx[i] & !VISITED;
+        if idx[index] & VISITED != 0 {
+            result = Err(SwizzleError::DuplicateIndex { index });
+            break;
+        }
+        idx[index] |= VISITED;
+    }
+    for index in idx.iter_mut() {
+        *index &= !VISITED;
+    }
+    result
+}
+
+/// Call `swap` with the pairs of positions to swap, in order, to apply the permutation `idx`,
+/// following each cycle from its first position. The indices are restored before returning.
+fn for_each_swap(idx: &mut [usize], mut swap: impl FnMut(usize, usize)) {
+    for start in 0..idx.len() {
+        if idx[start] & VISITED != 0 {
+            continue;
+        }
+        // The position `start` holds its original element until the end of the cycle, which takes
+        // it, and each swap moves the element for the current position into place.
+        let mut current = start;
+        loop {
+            let next = idx[current];
+            idx[current] |= VISITED;
+            if next == start {
+                break;
+            }
+            swap(current, next);
+            current = next;
+        }
+    }
+    for index in idx.iter_mut() {
+        *index &= !VISITED;
+    }
+}
+
+/// Swap the distinct rows `a` and `b` of `row_len` elements of `items`.
+fn swap_rows<T>(items: &mut [T], row_len: usize, a: usize, b: usize) {
+    let (low, high) = (a.min(b), a.max(b));
+    let (head, tail) = items.split_at_mut(high * row_len);
+    head[low * row_len..][..row_len].swap_with_slice(&mut tail[..row_len]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SwizzlePlan;
+
+    #[test]
+    fn test_permute_in_place() {
+        let mut items = ['a', 'b', 'c', 'd', 'e', 'f'];
+        let mut idx = [3, 0, 4, 1, 2, 5];
+        permute_in_place(&mut items, &mut idx).unwrap();
+        assert_eq!(items, ['d', 'a', 'e', 'b', 'c', 'f']);
+        assert_eq!(idx, [3, 0, 4, 1, 2, 5]);
+
+        let mut empty: [u8; 0] = [];
+        permute_in_place(&mut empty, &mut []).unwrap();
+    }
+
+    #[test]
+    fn test_permute_in_place_every_permutation() {
+        // Every permutation of 4 elements, compared with the out-of-place gather.
+        for rank in 0..256 {
+            let mut idx = [rank / 64, rank / 16 % 4, rank / 4 % 4, rank % 4];
+            let mut items = [1, 2, 3, 4];
+            let mut expected = [0; 4];
+            gather(&items, idx, &mut expected);
+            match permute_in_place(&mut items, &mut idx) {
+                Ok(()) => assert_eq!(items, expected),
+                Err(error) => {
+                    assert!(matches!(error, SwizzleError::DuplicateIndex { .. }));
+                    assert_eq!(items, [1, 2, 3, 4]);
+                }
+            }
+            assert_eq!(idx, [rank / 64, rank / 16 % 4, rank / 4 % 4, rank % 4]);
+        }
+    }
+
+    #[test]
+    fn test_permute_in_place_invalid() {
+        let mut items = [1, 2, 3];
+        assert_eq!(
+            permute_in_place(&mut items, &mut [0, 1]),
+            Err(SwizzleError::WrongArity {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            permute_in_place(&mut items, &mut [0, 3, 1]),
+            Err(SwizzleError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        let mut idx = [2, 0, 2];
+        assert_eq!(
+            permute_in_place(&mut items, &mut idx),
+            Err(SwizzleError::DuplicateIndex { index: 2 })
+        );
+        assert_eq!(idx, [2, 0, 2]);
+        assert_eq!(items, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_permute_rows_in_place() {
+        let mut rows = [1, 2, 3, 4, 5, 6, 7, 8];
+        permute_rows_in_place(&mut rows, 2, &mut [3, 2, 0, 1]).unwrap();
+        assert_eq!(rows, [7, 8, 5, 6, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic = "element count is not a multiple of the row length"]
+    fn test_permute_rows_in_place_partial_row() {
+        permute_rows_in_place(&mut [1, 2, 3], 2, &mut [0]).unwrap();
+    }
+
+    #[test]
+    fn test_permute_blocks_in_place() {
+        let mut planes = [1, 1, 2, 2, 3, 3];
+        permute_blocks_in_place(&mut planes, Permutation::new([2, 1, 0]).unwrap());
+        assert_eq!(planes, [3, 3, 2, 2, 1, 1]);
+
+        let mut empty: [u8; 0] = [];
+        permute_blocks_in_place(&mut empty, Permutation::new([1, 0]).unwrap());
+    }
+
+    #[test]
+    fn test_gather_scatter() {
+        let src = ['a', 'b', 'c', 'd'];
+        let rotate = Permutation::new([1, 2, 3, 0]).unwrap();
+        let mut gathered = ['-'; 4];
+        gather(&src, rotate, &mut gathered);
+        assert_eq!(gathered, ['b', 'c', 'd', 'a']);
+
+        // Scattering by a permutation undoes gathering by it.
+        let mut scattered = ['-'; 4];
+        scatter(&gathered, rotate, &mut scattered);
+        assert_eq!(scattered, src);
+
+        let plan: SwizzlePlan<4, 2> = "wx".parse().unwrap();
+        let mut gathered = ['-'; 2];
+        // SAFETY: the plan's indices are less than 4.
+        unsafe { gather_unchecked(&src, plan, &mut gathered) };
+        assert_eq!(gathered, ['d', 'a']);
+
+        let mut scattered = ['-'; 4];
+        // SAFETY: the indices are less than 4.
+        unsafe { scatter_unchecked(&gathered, [3, 3], &mut scattered) };
+        assert_eq!(scattered, ['-', '-', '-', 'a']);
+    }
+
+    #[test]
+    #[should_panic = "gather index out of range"]
+    fn test_gather_out_of_range() {
+        gather(&[1, 2], [2], &mut [0]);
+    }
+
+    #[test]
+    #[should_panic = "scatter index out of range"]
+    fn test_scatter_out_of_range() {
+        scatter(&[1], &[2][..], &mut [0, 0]);
+    }
+
+    #[test]
+    #[should_panic = "scatter index and slice lengths differ"]
+    fn test_scatter_lengths() {
+        scatter(&[1, 2], [0], &mut [0, 0]);
+    }
+}
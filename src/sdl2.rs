@@ -0,0 +1,248 @@
+//! Swizzles between SDL2 pixel formats that differ only by the order of their channels.
+//!
+//! The formats are the raw `SDL_PixelFormatEnum` values, decoded from the type, channel order, and
+//! layout that SDL packs into each value, so they work with any SDL2 binding, e.g. with
+//! `PixelFormatEnum::ARGB8888 as u32` and `surface.pixel_format_enum() as u32` in `sdl2`.
+//!
+//! The channels of packed formats, such as `SDL_PIXELFORMAT_ARGB8888`, are listed from their least
+//! significant bits, so their channel order is the reverse of their names, and the channels of
+//! array formats, such as `SDL_PIXELFORMAT_RGB24`, are listed in memory order, the same as their
+//! names.
+//!
+//! ```rust
+//! use swizzle::sdl2::{channel_order, swizzle_between, swizzle_pixels};
+//!
+//! const ARGB8888: u32 = 0x1636_2004;
+//! const ABGR8888: u32 = 0x1676_2004;
+//!
+//! assert_eq!(channel_order(ARGB8888), Some("bgra"));
+//! assert_eq!(swizzle_between::<4>(ARGB8888, ABGR8888).unwrap().indices(), [2, 1, 0, 3]);
+//!
+//! // Convert a 2x1 ARGB8888 surface with a pitch of 12 bytes to ABGR8888 in place.
+//! let mut pixels = 0x4411_2233_u32.to_ne_bytes().repeat(3);
+//! swizzle_pixels::<4>(&mut pixels, 2, 12, ARGB8888, ABGR8888).unwrap();
+//! assert_eq!(pixels[..4], 0x4433_2211_u32.to_ne_bytes());
+//! assert_eq!(pixels[8..], 0x4411_2233_u32.to_ne_bytes());
+//! ```
+
+use crate::{Permutation, SwizzleError};
+
+/// The channels of a format, from the least significant bits of packed formats or in memory
+/// order of array formats, and the bits of each channel.
+fn layout(format: u32) -> Option<(&'static str, &'static [u8])> {
+    if format >> 28 != 1 {
+        return None;
+    }
+    let order = (format >> 20) & 0xF;
+    match (format >> 24) & 0xF {
+        // SDL_PIXELTYPE_PACKED8, PACKED16, and PACKED32.
+        4..=6 => {
+            let bits: &'static [u8] = match (format >> 16) & 0xF {
+                1 => &[2, 3, 3],
+                2 => &[4; 4],
+                3 => &[5, 5, 5, 1],
+                4 => &[1, 5, 5, 5],
+                5 => &[5, 6, 5],
+                6 => &[8; 4],
+                7 => &[10, 10, 10, 2],
+                8 => &[2, 10, 10, 10],
+                _ => return None,
+            };
+            let channels = match order {
+                1 => "bgrx",
+                2 => "xbgr",
+                3 => "bgra",
+                4 => "abgr",
+                5 => "rgbx",
+                6 => "xrgb",
+                7 => "rgba",
+                8 => "argb",
+                _ => return None,
+            };
+            // Layouts of 3 channels have no bits for the padding.
+            let channels = match (bits.len(), channels) {
+                (4, _) => channels,
+                (_, "bgrx" | "xbgr") => "bgr",
+                (_, "rgbx" | "xrgb") => "rgb",
+                _ => return None,
+            };
+            Some((channels, bits))
+        }
+        // SDL_PIXELTYPE_ARRAYU8, ARRAYU16, ARRAYU32, ARRAYF16, and ARRAYF32.
+        ty @ 7..=11 => {
+            let channels = match order {
+                1 => "rgb",
+                2 => "rgba",
+                3 => "argb",
+                4 => "bgr",
+                5 => "bgra",
+                6 => "abgr",
+                _ => return None,
+            };
+            let bits: &'static [u8] = match ty {
+                7 => &[8; 4],
+                8 | 10 => &[16; 4],
+                _ => &[32; 4],
+            };
+            Some((channels, &bits[..channels.len()]))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the channels of the pixel format as the letters `r`, `g`, `b`, `a`, and `x` for
+/// padding, or `None` if it isn't a packed or array format, such as an indexed or YUV format.
+#[must_use]
+pub fn channel_order(format: u32) -> Option<&'static str> {
+    layout(format).map(|(channels, _)| channels)
+}
+
+/// Returns the swizzle converting the pixels of the `src` format to `dst`, taking the channel at
+/// each position of `dst` from the channel of `src` at the index.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::IncompatibleFormats`] if the formats aren't both packed or array
+/// formats of the same type, with the same `N` channels of the same sizes.
+pub fn swizzle_between<const N: usize>(src: u32, dst: u32) -> Result<Permutation<N>, SwizzleError> {
+    let (Some((src_channels, src_bits)), Some((dst_channels, dst_bits))) =
+        (layout(src), layout(dst))
+    else {
+        return Err(SwizzleError::IncompatibleFormats);
+    };
+    if (src >> 24) & 0xF != (dst >> 24) & 0xF || src_channels.len() != N || dst_channels.len() != N
+    {
+        return Err(SwizzleError::IncompatibleFormats);
+    }
+    let mut idx = [0; N];
+    for (i, channel) in dst_channels.bytes().enumerate() {
+        idx[i] = src_channels
+            .bytes()
+            .position(|c| c == channel)
+            .filter(|&index| src_bits[index] == dst_bits[i])
+            .ok_or(SwizzleError::IncompatibleFormats)?;
+    }
+    Permutation::new(idx).map_err(|_| SwizzleError::IncompatibleFormats)
+}
+
+/// Convert the pixels of a surface or locked texture from the `src` format to `dst` in place,
+/// for formats of `N` 8-bit channels. `width` is the number of pixels in each row, and `pitch` is
+/// the number of bytes from the start of one row to the next, which may include padding.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::IncompatibleFormats`] if the formats don't differ only by the order of
+/// `N` 8-bit channels, as for [`swizzle_between`]. The pixels are unchanged on error.
+///
+/// # Panics
+///
+/// Panics if `pitch` is less than the `width` of a row, or if the last row is shorter than
+/// `width` pixels.
+pub fn swizzle_pixels<const N: usize>(
+    pixels: &mut [u8],
+    width: usize,
+    pitch: usize,
+    src: u32,
+    dst: u32,
+) -> Result<(), SwizzleError> {
+    let idx = swizzle_between::<N>(src, dst)?.indices();
+    if src & 0xFF != N as u32 || layout(src).is_some_and(|(_, bits)| bits.iter().any(|&b| b != 8)) {
+        return Err(SwizzleError::IncompatibleFormats);
+    }
+    // The channels of packed formats are in memory order on little-endian targets only.
+    let packed = (4..=6).contains(&((src >> 24) & 0xF));
+    let idx = if packed && cfg!(target_endian = "big") {
+        core::array::from_fn(|i| N - 1 - idx[N - 1 - i])
+    } else {
+        idx
+    };
+    assert!(
+        pitch > 0 && pitch >= width * N,
+        "pixel pitch is less than the width of a row"
+    );
+    for row in pixels.chunks_mut(pitch) {
+        crate::arch::swizzle_bytes(&mut row[..width * N], idx);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RGB332: u32 = 0x1411_0801;
+    const XRGB8888: u32 = 0x1616_1804;
+    const XBGR8888: u32 = 0x1656_1804;
+    const ARGB8888: u32 = 0x1636_2004;
+    const RGBA8888: u32 = 0x1646_2004;
+    const ARGB2101010: u32 = 0x1637_2004;
+    const RGB565: u32 = 0x1515_1002;
+    const BGR565: u32 = 0x1555_1002;
+    const RGB24: u32 = 0x1710_1803;
+    const BGR24: u32 = 0x1740_1803;
+    const YV12: u32 = 0x3231_5659;
+
+    #[test]
+    fn test_channel_order() {
+        assert_eq!(channel_order(ARGB8888), Some("bgra"));
+        assert_eq!(channel_order(RGBA8888), Some("abgr"));
+        assert_eq!(channel_order(XRGB8888), Some("bgrx"));
+        assert_eq!(channel_order(RGB565), Some("bgr"));
+        assert_eq!(channel_order(RGB332), Some("bgr"));
+        assert_eq!(channel_order(RGB24), Some("rgb"));
+        assert_eq!(channel_order(YV12), None);
+    }
+
+    #[test]
+    fn test_swizzle_between() {
+        let p = swizzle_between::<4>(ARGB8888, RGBA8888).unwrap();
+        assert_eq!(p.indices(), [3, 0, 1, 2]);
+        assert_eq!(
+            swizzle_between::<4>(XRGB8888, XBGR8888).unwrap().indices(),
+            [2, 1, 0, 3]
+        );
+        assert_eq!(
+            swizzle_between::<3>(RGB565, BGR565).unwrap().indices(),
+            [2, 1, 0]
+        );
+        assert_eq!(
+            swizzle_between::<3>(RGB24, BGR24).unwrap().indices(),
+            [2, 1, 0]
+        );
+
+        for (src, dst) in [
+            (XRGB8888, ARGB8888),
+            (ARGB8888, ARGB2101010),
+            (RGB24, RGB565),
+            (ARGB8888, YV12),
+        ] {
+            assert_eq!(
+                swizzle_between::<4>(src, dst),
+                Err(SwizzleError::IncompatibleFormats)
+            );
+            assert_eq!(
+                swizzle_between::<3>(src, dst),
+                Err(SwizzleError::IncompatibleFormats)
+            );
+        }
+    }
+
+    #[test]
+    fn test_swizzle_pixels() {
+        let mut pixels = [1, 2, 3, 4, 5, 6, 0, 0, 7, 8, 9, 10, 11, 12, 0];
+        swizzle_pixels::<3>(&mut pixels, 2, 8, RGB24, BGR24).unwrap();
+        assert_eq!(pixels, [3, 2, 1, 6, 5, 4, 0, 0, 9, 8, 7, 12, 11, 10, 0]);
+
+        assert_eq!(
+            swizzle_pixels::<3>(&mut pixels, 2, 8, RGB565, BGR565),
+            Err(SwizzleError::IncompatibleFormats)
+        );
+        assert_eq!(pixels[..3], [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic = "pixel pitch is less than the width of a row"]
+    fn test_swizzle_pixels_short_pitch() {
+        let _ = swizzle_pixels::<3>(&mut [0; 12], 2, 4, RGB24, BGR24);
+    }
+}
@@ -0,0 +1,220 @@
+//! Swizzles spelled as WGSL or GLSL source, for code generators that emit shaders from the same
+//! description of a swizzle as the CPU side.
+//!
+//! ```rust
+//! use swizzle::shader::{ShaderLanguage, ShaderSwizzle};
+//!
+//! let zyx = ShaderSwizzle::new([2, 1, 0]).unwrap();
+//! assert_eq!(zyx.to_string(), ".zyx");
+//! assert_eq!(zyx.expr("v").to_string(), "v.zyx");
+//! assert_eq!(
+//!     zyx.constructor::<f32>(ShaderLanguage::Glsl, "v").to_string(),
+//!     "vec3(v.z, v.y, v.x)"
+//! );
+//! assert_eq!(
+//!     zyx.constructor::<u32>(ShaderLanguage::Wgsl, "v").to_string(),
+//!     "vec3<u32>(v.z, v.y, v.x)"
+//! );
+//! ```
+
+use core::fmt;
+
+/// The component names of a shader vector, in index order.
+const NAMES: [u8; 4] = *b"xyzw";
+
+/// A shading language to format a swizzle for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderLanguage {
+    /// The WebGPU Shading Language.
+    Wgsl,
+    /// The OpenGL Shading Language.
+    Glsl,
+}
+
+/// A scalar type of shader vectors, naming the vector types of each language.
+pub trait ShaderScalar {
+    /// The WGSL name of the scalar type, e.g. `f32`.
+    const WGSL: &'static str;
+
+    /// The prefix of the GLSL vector types, e.g. `i` for `ivec3`.
+    const GLSL_PREFIX: &'static str;
+}
+
+macro_rules! impl_shader_scalar {
+    ($( $type:ty: $prefix:literal ),+) => {
+        $(
+            impl ShaderScalar for $type {
+                const WGSL: &'static str = stringify!($type);
+                const GLSL_PREFIX: &'static str = $prefix;
+            }
+        )+
+    };
+}
+
+impl_shader_scalar!(f32: "", i32: "i", u32: "u", bool: "b");
+
+/// A swizzle of 1 to 4 components of a shader vector, spelled with the `xyzw` component names,
+/// which are the same in WGSL and GLSL. `Display` writes the swizzle suffix, e.g. `.zyx`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShaderSwizzle<const N: usize>([u8; N]);
+
+impl<const N: usize> ShaderSwizzle<N> {
+    /// Create the swizzle taking the component at each position from the component at the
+    /// index. Returns `None` if there aren't 1 to 4 indices, or any of them is out of range of a
+    /// 4-component vector.
+    #[must_use]
+    pub const fn new(idx: [usize; N]) -> Option<Self> {
+        if N == 0 || N > 4 {
+            return None;
+        }
+        let mut names = [0; N];
+        let mut i = 0;
+        while i < N {
+            if idx[i] >= NAMES.len() {
+                return None;
+            }
+            names[i] = NAMES[idx[i]];
+            i += 1;
+        }
+        Some(Self(names))
+    }
+
+    /// The index of the component that each position is taken from.
+    #[must_use]
+    pub const fn indices(&self) -> [usize; N] {
+        let mut idx = [0; N];
+        let mut i = 0;
+        while i < N {
+            idx[i] = match self.0[i] {
+                b'x' => 0,
+                b'y' => 1,
+                b'z' => 2,
+                _ => 3,
+            };
+            i += 1;
+        }
+        idx
+    }
+
+    /// The component names, e.g. `"zyx"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.0) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// The swizzle of the vector expression `vector`, e.g. `v.zyx`. The same in WGSL and GLSL.
+    #[must_use]
+    pub const fn expr(self, vector: &str) -> ShaderExpr<'_, N> {
+        ShaderExpr {
+            swizzle: self,
+            vector,
+            constructor: None,
+        }
+    }
+
+    /// The swizzle of `vector` spelled as a constructor of its components, e.g.
+    /// `vec3(v.z, v.y, v.x)` in GLSL or `vec3<f32>(v.z, v.y, v.x)` in WGSL, for generators that
+    /// spell out each component. A single component is written without a constructor, e.g. `v.z`.
+    #[must_use]
+    pub const fn constructor<T: ShaderScalar>(
+        self,
+        language: ShaderLanguage,
+        vector: &str,
+    ) -> ShaderExpr<'_, N> {
+        ShaderExpr {
+            swizzle: self,
+            vector,
+            constructor: Some(match language {
+                ShaderLanguage::Wgsl => ("", T::WGSL),
+                ShaderLanguage::Glsl => (T::GLSL_PREFIX, ""),
+            }),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for ShaderSwizzle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ".{}", self.as_str())
+    }
+}
+
+/// A swizzle of a vector expression, formatted as shader source by `Display`. Created by
+/// [`ShaderSwizzle::expr`] and [`ShaderSwizzle::constructor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShaderExpr<'a, const N: usize> {
+    swizzle: ShaderSwizzle<N>,
+    vector: &'a str,
+    /// The GLSL type prefix and WGSL type parameter of the constructor, if it's spelled out.
+    constructor: Option<(&'static str, &'static str)>,
+}
+
+impl<const N: usize> fmt::Display for ShaderExpr<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vector = self.vector;
+        match self.constructor {
+            Some((prefix, scalar)) if N > 1 => {
+                write!(f, "{prefix}vec{N}")?;
+                if !scalar.is_empty() {
+                    write!(f, "<{scalar}>")?;
+                }
+                f.write_str("(")?;
+                for (i, &name) in self.swizzle.0.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{vector}.{}", name as char)?;
+                }
+                f.write_str(")")
+            }
+            _ => write!(f, "{vector}{}", self.swizzle),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shader_swizzle() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        const WZYX: Option<ShaderSwizzle<4>> = ShaderSwizzle::new([3, 2, 1, 0]);
+        let wzyx = WZYX.unwrap();
+        assert_eq!(wzyx.as_str(), "wzyx");
+        assert_eq!(wzyx.indices(), [3, 2, 1, 0]);
+        assert_eq!(wzyx.to_string(), ".wzyx");
+        assert_eq!(wzyx.expr("color").to_string(), "color.wzyx");
+
+        assert_eq!(ShaderSwizzle::new([0; 5]), None);
+        assert_eq!(ShaderSwizzle::new([]), None);
+        assert_eq!(ShaderSwizzle::new([0, 4]), None);
+    }
+
+    #[test]
+    fn test_constructor() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let yx = ShaderSwizzle::new([1, 0]).unwrap();
+        assert_eq!(
+            yx.constructor::<i32>(ShaderLanguage::Glsl, "p").to_string(),
+            "ivec2(p.y, p.x)"
+        );
+        assert_eq!(
+            yx.constructor::<bool>(ShaderLanguage::Wgsl, "p")
+                .to_string(),
+            "vec2<bool>(p.y, p.x)"
+        );
+
+        let z = ShaderSwizzle::new([2]).unwrap();
+        assert_eq!(
+            z.constructor::<f32>(ShaderLanguage::Wgsl, "v").to_string(),
+            "v.z"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+/// Macro for generating a struct-of-arrays type of a struct, and conversions to and from arrays
+/// and slices of the struct.
+///
+/// Processing many values a field at a time, e.g. with SIMD, is faster when the values of each
+/// field are together in memory, a struct of arrays (SoA), rather than one struct after another,
+/// an array of structs (AoS). The macro generates `<StructName>Soa<N>`, with a public array of `N`
+/// values for each field, and the transposes between the two, optionally swizzling the fields on
+/// the way like `swizzle_dyn`, where the field at each position is taken from the field at the
+/// index.
+///
+/// All fields must be of the same `Copy` type, which is declared after the struct name, and the
+/// struct must have no other fields. The optional visibility applies to the generated type.
+///
+/// ```rust
+/// use swizzle::swizzle_soa;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// swizzle_soa!(Vec3: f32 { x, y, z });
+///
+/// let points = [Vec3 { x: 1.0, y: 2.0, z: 3.0 }, Vec3 { x: 4.0, y: 5.0, z: 6.0 }];
+/// let soa = Vec3Soa::from_aos(&points);
+/// assert_eq!(soa.x, [1.0, 4.0]);
+/// assert_eq!(soa.to_aos(), points);
+///
+/// // Swap y and z during the transpose.
+/// let swapped = Vec3Soa::from_aos_swizzled(&points, [0, 2, 1]);
+/// assert_eq!(swapped.y, [3.0, 6.0]);
+///
+/// // Transpose a slice in chunks of 4 structs.
+/// let slice = [Vec3 { x: 0.0, y: 0.0, z: 0.0 }; 8];
+/// for chunk in slice.chunks_exact(4) {
+///     let soa = Vec3Soa::<4>::from_slice(chunk).unwrap();
+///     assert_eq!(soa.z, [0.0; 4]);
+/// }
+/// ```
+///
+/// # Generated Functions
+///
+/// - `from_aos(&[StructName; N]) -> Self` and `to_aos(&self) -> [StructName; N]` → transpose
+///   between an array of structs and the struct of arrays
+/// - `from_aos_swizzled` and `to_aos_swizzled` → the same, taking each field from the field at the
+///   index, e.g. `[2, 1, 0]` to reverse the fields
+/// - `from_slice(&[StructName]) -> Result<Self, SwizzleError>` and
+///   `write_slice(&self, &mut [StructName]) -> Result<(), SwizzleError>` → the same for slices of
+///   exactly `N` structs, returning `SwizzleError::WrongArity` otherwise
+#[macro_export]
+macro_rules! swizzle_soa {
+    (
+        $vis:vis $type:ident: $component:ty {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => { $crate::__private::paste! {
+        #[doc = concat!("The fields of `N` [`", stringify!($type), "`] values, as an array per field.")]
+        #[derive(Debug, Clone, PartialEq)]
+        $vis struct [<$type Soa>]<const N: usize> {
+            $(
+                #[doc = concat!("The `", stringify!($attr), "` field of each value.")]
+                pub $attr: [$component; N],
+            )+
+        }
+
+        impl<const N: usize> [<$type Soa>]<N> {
+            /// Transpose an array of structs to a struct of arrays.
+            #[must_use]
+            pub fn from_aos(items: &[$type; N]) -> Self {
+                Self { $( $attr: ::core::array::from_fn(|i| items[i].$attr) ),+ }
+            }
+
+            /// Transpose the struct of arrays to an array of structs.
+            #[must_use]
+            pub fn to_aos(&self) -> [$type; N] {
+                ::core::array::from_fn(|i| $type { $( $attr: self.$attr[i] ),+ })
+            }
+
+            /// Transpose an array of structs to a struct of arrays, where the array of each field
+            /// is taken from the field of the structs at the index.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the indices is out of range.
+            #[must_use]
+            pub fn from_aos_swizzled(
+                items: &[$type; N],
+                idx: [usize; [$( stringify!($attr) ),+].len()],
+            ) -> Self {
+                let fields = [ $( ::core::array::from_fn(|i| items[i].$attr) ),+ ];
+                let [ $( $attr ),+ ] = idx;
+                $( assert!($attr < fields.len(), "swizzle index out of range"); )+
+                Self { $( $attr: fields[$attr] ),+ }
+            }
+
+            /// Transpose the struct of arrays to an array of structs, where each field of the
+            /// structs is taken from the array of the field at the index.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the indices is out of range.
+            #[must_use]
+            pub fn to_aos_swizzled(
+                &self,
+                idx: [usize; [$( stringify!($attr) ),+].len()],
+            ) -> [$type; N] {
+                let fields = [ $( &self.$attr ),+ ];
+                let [ $( $attr ),+ ] = idx;
+                $( assert!($attr < fields.len(), "swizzle index out of range"); )+
+                ::core::array::from_fn(|i| $type { $( $attr: fields[$attr][i] ),+ })
+            }
+
+            /// Transpose a slice of exactly `N` structs to a struct of arrays.
+            ///
+            /// # Errors
+            ///
+            /// Returns `SwizzleError::WrongArity` if the slice doesn't have `N` structs.
+            pub fn from_slice(items: &[$type]) -> ::core::result::Result<Self, $crate::SwizzleError> {
+                let items: &[$type; N] = items.try_into().map_err(|_| $crate::SwizzleError::WrongArity {
+                    expected: N,
+                    found: items.len(),
+                })?;
+                ::core::result::Result::Ok(Self::from_aos(items))
+            }
+
+            /// Transpose the struct of arrays into a slice of exactly `N` structs.
+            ///
+            /// # Errors
+            ///
+            /// Returns `SwizzleError::WrongArity` if the slice doesn't have `N` structs.
+            pub fn write_slice(&self, items: &mut [$type]) -> ::core::result::Result<(), $crate::SwizzleError> {
+                if items.len() != N {
+                    return ::core::result::Result::Err($crate::SwizzleError::WrongArity {
+                        expected: N,
+                        found: items.len(),
+                    });
+                }
+                for (i, item) in items.iter_mut().enumerate() {
+                    *item = $type { $( $attr: self.$attr[i] ),+ };
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SwizzleError;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Rgba {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    swizzle_soa!(Rgba: u8 { r, g, b, a });
+
+    const PIXELS: [Rgba; 3] = [
+        Rgba {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 4,
+        },
+        Rgba {
+            r: 5,
+            g: 6,
+            b: 7,
+            a: 8,
+        },
+        Rgba {
+            r: 9,
+            g: 10,
+            b: 11,
+            a: 12,
+        },
+    ];
+
+    #[test]
+    fn test_soa() {
+        let soa = RgbaSoa::from_aos(&PIXELS);
+        assert_eq!(soa.r, [1, 5, 9]);
+        assert_eq!(soa.a, [4, 8, 12]);
+        assert_eq!(soa.to_aos(), PIXELS);
+
+        let bgra = RgbaSoa::from_aos_swizzled(&PIXELS, [2, 1, 0, 3]);
+        assert_eq!(bgra.r, [3, 7, 11]);
+        assert_eq!(bgra.b, [1, 5, 9]);
+        assert_eq!(bgra.to_aos_swizzled([2, 1, 0, 3]), PIXELS);
+        assert_eq!(
+            soa.to_aos_swizzled([3, 3, 3, 3])[1],
+            Rgba {
+                r: 8,
+                g: 8,
+                b: 8,
+                a: 8
+            }
+        );
+    }
+
+    #[test]
+    fn test_soa_slice() {
+        let soa = RgbaSoa::<2>::from_slice(&PIXELS[1..]).unwrap();
+        assert_eq!(soa.g, [6, 10]);
+
+        let mut pixels = PIXELS;
+        soa.write_slice(&mut pixels[..2]).unwrap();
+        assert_eq!(pixels, [PIXELS[1], PIXELS[2], PIXELS[2]]);
+
+        assert_eq!(
+            RgbaSoa::<2>::from_slice(&PIXELS),
+            Err(SwizzleError::WrongArity {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            soa.write_slice(&mut pixels),
+            Err(SwizzleError::WrongArity {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_soa_index_out_of_range() {
+        let _ = RgbaSoa::from_aos_swizzled(&PIXELS, [0, 1, 2, 4]);
+    }
+}
@@ -105,6 +105,246 @@
 /// let c_grayscale = c.rrrr(); // Color { r: 255, g: 255, b: 255, a: 255 }
 /// ```
 ///
+/// ## Conversion Example
+///
+/// Functions converting to another type list the source fields for each destination field, or
+/// declare them once after `from`, with `*` standing for all of them.
+///
+/// ```rust
+/// use swizzle::swizzle;
+///
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl Vec3 {
+///     swizzle!(Vec2 { x: *, y: * } from (x, y, z));
+///     // Which is equivalent to
+///     // swizzle!(Vec2 { x: (x, y, z), y: (x, y, z) });
+/// }
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let v_zx = v.zx(); // Vec2 { x: 3.0, y: 1.0 }
+/// ```
+///
+/// # Overlapping Invocations
+///
+/// Inherent methods can only be defined once, so layering an explicit swizzle on top of a complete
+/// self-swizzle of the same type is a duplicate definition error. Prefixing the self-swizzle with
+/// `overlap_ok` instead generates the methods as an extension trait named `<Type>Swizzle`, which
+/// must be invoked at module level. Inherent methods take precedence over trait methods, so any
+/// pattern that is also generated explicitly is simply skipped. An optional visibility is applied
+/// to the trait. The trait methods are not `const`.
+///
+/// ```rust
+/// use swizzle::swizzle;
+///
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// swizzle!(overlap_ok pub Vec2 { x, y });
+///
+/// impl Vec2 {
+///     swizzle!(Vec2 { x: y, y: x });
+/// }
+///
+/// let v = Vec2 { x: 1.0, y: 2.0 };
+/// let v_swapped = v.yx();  // Inherent: Vec2 { x: 2.0, y: 1.0 }
+/// let v_repeated = v.xx(); // Vec2Swizzle: Vec2 { x: 1.0, y: 1.0 }
+/// ```
+///
+/// # Arity Limit
+///
+/// A self-swizzle of `n` fields generates `n^n` functions, which quickly becomes too slow to compile.
+/// To avoid appearing to hang the compiler, self-swizzles of more than 5 fields fail with a
+/// compile error. The default of 5 admits every common vector and color type (`Rgba` is 256
+/// functions) and the 3125 functions of 5 fields still expand in seconds, while the 46656 of 6
+/// fields take minutes:
+///
+/// ```compile_fail
+/// use swizzle::swizzle;
+///
+/// struct Vec6 { a: u8, b: u8, c: u8, d: u8, e: u8, f: u8 }
+///
+/// impl Vec6 {
+///     swizzle!(Vec6 { a, b, c, d, e, f });
+/// }
+/// ```
+///
+/// Prefixing the invocation with `max_fields = N`, for `N` from 1 to 6, sets another limit, e.g. to
+/// keep a crate's self-swizzles below 4 fields, and `unbounded` removes it. Both go after the
+/// name of the form, as in `overlap_ok max_fields = 6` or `compact unbounded`:
+///
+/// ```compile_fail
+/// use swizzle::swizzle;
+///
+/// struct Rgba { r: u8, g: u8, b: u8, a: u8 }
+///
+/// impl Rgba {
+///     swizzle!(max_fields = 3 Rgba { r, g, b, a });
+/// }
+/// ```
+///
+/// ```rust,ignore
+/// impl Vec6 {
+///     swizzle!(max_fields = 6 Vec6 { a, b, c, d, e, f }); // 46656 functions
+///     // or
+///     swizzle!(unbounded Vec6 { a, b, c, d, e, f });
+/// }
+/// ```
+///
+/// # Grouped Traits
+///
+/// Prefixing a self-swizzle with `grouped` splits the functions across several extension traits,
+/// one for each field, named `<Type>Swizzle<Field>` and containing the functions that start with
+/// that field. Like `overlap_ok`, it must be invoked at module level, takes an optional visibility
+/// for the traits, and the trait methods are not `const`. Only the traits that are actually used
+/// need to be imported, and the functions of unused traits are easily dropped from the binary.
+///
+/// ```rust
+/// mod vector {
+///     use swizzle::swizzle;
+///
+///     pub struct Vec3 {
+///         pub x: f32,
+///         pub y: f32,
+///         pub z: f32,
+///     }
+///
+///     swizzle!(grouped pub Vec3 { x, y, z });
+/// }
+///
+/// use vector::{Vec3, Vec3SwizzleZ};
+///
+/// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let v_reverse = v.zyx(); // Vec3 { x: 3.0, y: 2.0, z: 1.0 }
+/// ```
+///
+/// # WebAssembly
+///
+/// With the `wasm-bindgen` feature enabled, prefixing a self-swizzle with `wasm` generates the
+/// functions in `#[wasm_bindgen]` impl blocks, so they can be called directly from JavaScript. The
+/// struct itself must be `#[wasm_bindgen]` and `Copy`. Like `overlap_ok`, it must be invoked at
+/// module level, and the functions are not `const`. No function table is generated.
+///
+/// ```rust
+/// # #[cfg(feature = "wasm-bindgen")] {
+/// use swizzle::swizzle;
+/// use wasm_bindgen::prelude::wasm_bindgen;
+///
+/// #[wasm_bindgen]
+/// #[derive(Clone, Copy)]
+/// pub struct Vec2 {
+///     pub x: f32,
+///     pub y: f32,
+/// }
+///
+/// swizzle!(wasm Vec2 { x, y });
+///
+/// let v = Vec2 { x: 1.0, y: 2.0 };
+/// let v_swapped = v.yx(); // Vec2 { x: 2.0, y: 1.0 }, also `v.yx()` in JavaScript
+/// # }
+/// ```
+///
+/// # C Exports
+///
+/// Prefixing a single swizzle with `extern "C"` also exports it to C, as a `#[no_mangle]` free
+/// function named after the struct in snake case and the swizzle, taking and returning the struct
+/// by value, so C and C++ callers of a library can use the same conversions as Rust. A conversion
+/// between types names the source type before `=>`, and the function after both types, e.g.
+/// `vec3_to_vec2_zx`. The structs should be `#[repr(C)]`, and like `overlap_ok`, it must be invoked
+/// at module level. Only the swizzles that are listed are exported, as every exported symbol is
+/// kept in the binary.
+///
+/// ```rust
+/// use swizzle::swizzle;
+///
+/// #[repr(C)]
+/// pub struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// #[repr(C)]
+/// pub struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// // `Vec3 vec3_zyx(Vec3 value);` in C.
+/// swizzle!(extern "C" Vec3 { x: z, y: y, z: x });
+/// // `Vec2 vec3_to_vec2_zx(Vec3 value);` in C.
+/// swizzle!(extern "C" Vec3 => Vec2 { x: z, y: x });
+///
+/// let v = vec3_to_vec2_zx(vec3_zyx(Vec3 { x: 1.0, y: 2.0, z: 3.0 }));
+/// assert_eq!((v.x, v.y), (1.0, 3.0));
+/// ```
+///
+/// # Code Size
+///
+/// Every generated function contains its own copy of the permutation, which adds up for embedded
+/// targets where flash is scarce. Prefixing a self-swizzle with `compact` instead generates a
+/// single private function taking the indices of the pattern, marked `#[inline(never)]`, and
+/// every swizzle function is a thin wrapper around it. This trades a function call per swizzle
+/// for a much smaller binary. All fields must be of the same type.
+///
+/// ```rust
+/// use swizzle::swizzle;
+///
+/// struct Vec4 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+///     w: f32,
+/// }
+///
+/// impl Vec4 {
+///     swizzle!(compact Vec4 { x, y, z, w });
+/// }
+///
+/// let v = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+/// let v_reverse = v.wzyx(); // Vec4 { x: 4.0, y: 3.0, z: 2.0, w: 1.0 }
+/// ```
+///
+/// # Function Table
+///
+/// Prefixing a self-swizzle with `table`, as in `table Vec2 { x, y }` or
+/// `table compact Vec2 { x, y }`, also generates an associated const `SWIZZLE_FNS` pairing the name
+/// of every function with a pointer to it, in the order the functions are generated. This allows
+/// dispatch by name at runtime. The table is opt-in, as it takes a second pass of the generation to
+/// build and is only possible when the type being swizzled is the type of the `impl` block. The names alone are
+/// listed by `SWIZZLE_NAMES`, and `swizzles()` iterates over every swizzle of a value.
+/// `SWIZZLE_INVERSES` maps every function that is a pure permutation to the one that undoes it, and
+/// `SWIZZLE_SIGNS` gives its sign (`1` or `-1`, and `0` for functions that aren't permutations).
+/// `SWIZZLE_COUNT` is the number of functions generated.
+///
+/// ```rust
+/// use swizzle::swizzle;
+///
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl Vec2 {
+///     swizzle!(table Vec2 { x, y });
+/// }
+///
+/// let (name, f) = Vec2::SWIZZLE_FNS[2];
+/// let v = f(&Vec2 { x: 1.0, y: 2.0 }); // Vec2 { x: 2.0, y: 1.0 }
+/// assert_eq!(name, "yx");
+/// ```
+///
 /// # Performance
 ///
 /// All generated functions are marked as `#[inline]` and `#[must_use]` for optimal performance.
@@ -114,7 +354,7 @@
 ///
 /// - Field names must be valid Rust identifiers
 /// - All fields must be of the same type or types that can be copied
-/// - The macro generates a lot of functions for structs with many fields (n^n functions). It's extremely slow for n>5 though it doesn't stop you from trying.
+/// - The macro generates a lot of functions for structs with many fields (n^n functions). Self-swizzles of more than 5 fields are rejected unless prefixed with `max_fields = N` or `unbounded`.
 ///
 /// # Use Cases
 ///
@@ -139,10 +379,60 @@
 #[macro_export]
 macro_rules! swizzle {
 
-    // Simple case to generate a single swizzle function. Also the terminal case for the more complex invocations.
+    // Case for a swizzle exported to C, as a free function taking and returning the structs by
+    // value. Must be invoked at module level. These come first, as `extern` can't be parsed as the
+    // path of the other cases.
+    // ```
+    // swizzle!(extern "C" Vec2 {x: y, y: x}) =>
+    //     #[unsafe(no_mangle)]
+    //     pub extern "C" fn vec2_yx(value: Vec2) -> Vec2 { Vec2 { x: value.y, y: value.x } }
+    // swizzle!(extern "C" Vec3 => Vec2 {x: z, y: x}) =>
+    //     #[unsafe(no_mangle)]
+    //     pub extern "C" fn vec3_to_vec2_zx(value: Vec3) -> Vec2 { Vec2 { x: value.z, y: value.x } }
+    // ```
+    (
+        extern "C" $type:ident {
+            $( $dst_attr:ident: $src_attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::__private::paste! {
+            $crate::swizzle!(
+                @extern [< $type:snake _ $( $src_attr )+ >] $type => $type { $( $dst_attr: $src_attr ),+ }
+            );
+        }
+    };
+    (
+        extern "C" $type:ident => $dst_type:ident {
+            $( $dst_attr:ident: $src_attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::__private::paste! {
+            $crate::swizzle!(
+                @extern [< $type:snake _to_ $dst_type:snake _ $( $src_attr )+ >] $type => $dst_type {
+                    $( $dst_attr: $src_attr ),+
+                }
+            );
+        }
+    };
+    (
+        @extern $name:ident $type:ident => $dst_type:ident { $( $dst_attr:ident: $src_attr:ident ),+ }
+    ) => {
+        #[doc = concat!(
+            "Create an instance of `", stringify!($dst_type), "` from a `", stringify!($type),
+            "` with the values swizzled: `", $( stringify!($src_attr), )+ "`, for callers in C."
+        )]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $name(value: $type) -> $dst_type {
+            $dst_type { $( $dst_attr: value.$src_attr ),+ }
+        }
+    };
+
+    // Simple case to generate a single swizzle function.
     // ```
-    // swizzle!(Vec2 {x: x, y: y}) =>
-    //     pub const fn xx(&self) -> Vec2 { Vec2 { x: x, y: y } }
+    // swizzle!(Vec2 {x: y, y: x}) =>
+    //     pub const fn yx(&self) -> Vec2 { Vec2 { x: self.y, y: self.x } }
     // ```
     (
         $dst_type:path {
@@ -150,20 +440,89 @@ macro_rules! swizzle {
             $(,)?
         }
     ) => {
-        paste::paste! {
-            #[doc = "Create an instance of `" ]
-            #[doc = stringify!( $dst_type ) ]
-            #[doc = "` with the values swizzled: ["  [< $($src_attr)+ >] "]" ]
-            #[must_use]
-            #[inline]
-            pub const fn [< $($src_attr)+ >](&self) -> $dst_type {
-                $dst_type { $($dst_attr: self.$src_attr),* }
-            }
+        $crate::swizzle!(@emit { inherent } $dst_type; [ $( $dst_attr: $src_attr, )* ] []);
+    };
+
+    // Case for a self-swizzle, or a `compact` one, that also generates the table of its functions
+    // and the metadata derived from it, see the `@table` case below. The table is opt-in, as it
+    // is only valid when the destination type is `Self`, and costs a second pass of the
+    // generation recursion.
+    // ```
+    // swizzle!(table Vec2 {x, y}) =>
+    //     swizzle!(Vec2 {x, y})
+    //     pub const SWIZZLE_FNS: [(&'static str, fn(&Self) -> Self); 4] = [("xx", Self::xx), ...];
+    //     ...
+    // ```
+    (
+        table unbounded $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
         }
+    ) => {
+        $crate::swizzle!(@self { inherent } $dst_type; ( $( $attr ),* ); $( $attr )*);
+        $crate::swizzle!(@table $dst_type; ( $( $attr ),* ); $( $attr )*);
+    };
+    (
+        table max_fields = $max:tt $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "table "; $max; [ $( $attr )* ];
+            $crate::swizzle!(@self { inherent } $dst_type; ( $( $attr ),* ); $( $attr )*);
+            $crate::swizzle!(@table $dst_type; ( $( $attr ),* ); $( $attr )*);
+        );
+    };
+    (
+        table compact unbounded $dst_type:path {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(@compact $dst_type; ( $( $attr )+ ); []; [0]; $( $attr )+);
+        $crate::swizzle!(@table $dst_type; ( $( $attr ),+ ); $( $attr )+);
+    };
+    (
+        table compact max_fields = $max:tt $dst_type:path {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "table compact "; $max; [ $( $attr )+ ];
+            $crate::swizzle!(@compact $dst_type; ( $( $attr )+ ); []; [0]; $( $attr )+);
+            $crate::swizzle!(@table $dst_type; ( $( $attr ),+ ); $( $attr )+);
+        );
+    };
+    (
+        table compact $dst_type:path {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "table compact "; 5; [ $( $attr )+ ];
+            $crate::swizzle!(@compact $dst_type; ( $( $attr )+ ); []; [0]; $( $attr )+);
+            $crate::swizzle!(@table $dst_type; ( $( $attr ),+ ); $( $attr )+);
+        );
+    };
+    (
+        table $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "table "; 5; [ $( $attr )* ];
+            $crate::swizzle!(@self { inherent } $dst_type; ( $( $attr ),* ); $( $attr )*);
+            $crate::swizzle!(@table $dst_type; ( $( $attr ),* ); $( $attr )*);
+        );
     };
 
     // Case for a swizzle function that creates new instances of it's own type with all
-    // combinations of attributes.
+    // combinations of attributes. Self-swizzles of more than 5 attributes are rejected unless
+    // prefixed with `max_fields = N` or `unbounded`, see the `@checked` case below.
     // ```
     // swizzle!(Vec2 {x, y}) =>
     //     pub const fn xx(&self) -> Vec2 { Vec2 { x: self.x, y: self.x } }
@@ -171,180 +530,658 @@ macro_rules! swizzle {
     //     pub const fn yx(&self) -> Vec2 { Vec2 { x: self.y, y: self.x } }
     //     pub const fn yy(&self) -> Vec2 { Vec2 { x: self.y, y: self.y } }
     // ```
+    (
+        unbounded $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(@self { inherent } $dst_type; ( $( $attr ),* ); $( $attr )*);
+    };
+    (
+        max_fields = $max:tt $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked ""; $max; [ $( $attr )* ];
+            $crate::swizzle!(@self { inherent } $dst_type; ( $( $attr ),* ); $( $attr )*);
+        );
+    };
     (
         $dst_type:path {
             $( $attr:ident ),*
             $(,)?
         }
-    ) => {
-        // Before we can generate the swizzle functions we need to build out the list of source
-        // attributes, which is a copy of the destination attributes for each destination
-        // attribute.
-        swizzle!(
-            $dst_type;
-            @bld { $( $attr ),* }
-            @src { }
-            @dst { $( $attr ),* }
-            @out { }
-        );
+    ) => {
+        $crate::swizzle!(
+            @checked ""; 5; [ $( $attr )* ];
+            $crate::swizzle!(@self { inherent } $dst_type; ( $( $attr ),* ); $( $attr )*);
+        );
+    };
+
+    // Case for a self-swizzle that tolerates overlapping invocations. Rather than inherent methods,
+    // the swizzles are generated as an extension trait `<Type>Swizzle` implemented for the type, so
+    // any inherent method with the same name (e.g. from an explicit single method invocation) takes
+    // precedence instead of producing a duplicate definition error. Must be invoked at module level.
+    // ```
+    // swizzle!(overlap_ok pub Vec2 {x, y}) =>
+    //     pub trait Vec2Swizzle {
+    //         fn xx(&self) -> Vec2;
+    //         ...
+    //     }
+    //     impl Vec2Swizzle for Vec2 {
+    //         fn xx(&self) -> Vec2 { Vec2 { x: self.x, y: self.x } }
+    //         ...
+    //     }
+    // ```
+    (
+        overlap_ok unbounded $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(@overlap $vis $dst_type { $( $attr ),+ });
+    };
+    (
+        overlap_ok max_fields = $max:tt $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "overlap_ok "; $max; [ $( $attr )+ ];
+            $crate::swizzle!(@overlap $vis $dst_type { $( $attr ),+ });
+        );
+    };
+    (
+        overlap_ok $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "overlap_ok "; 5; [ $( $attr )+ ];
+            $crate::swizzle!(@overlap $vis $dst_type { $( $attr ),+ });
+        );
+    };
+
+    // Case for a self-swizzle split across several extension traits, one per leading attribute,
+    // rather than one large inherent block. Unused traits, and the functions in them, can then be
+    // dropped as a whole. Must be invoked at module level.
+    // ```
+    // swizzle!(grouped pub Vec2 {x, y}) =>
+    //     pub trait Vec2SwizzleX {
+    //         fn xx(&self) -> Vec2;
+    //         fn xy(&self) -> Vec2;
+    //     }
+    //     pub trait Vec2SwizzleY {
+    //         fn yx(&self) -> Vec2;
+    //         fn yy(&self) -> Vec2;
+    //     }
+    //     impl Vec2SwizzleX for Vec2 { ... }
+    //     impl Vec2SwizzleY for Vec2 { ... }
+    // ```
+    (
+        grouped unbounded $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(@grouped $vis $dst_type; ( $( $attr ),+ ); ( $( $attr ),+ ); ( $( $attr )+ ));
+    };
+    (
+        grouped max_fields = $max:tt $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "grouped "; $max; [ $( $attr )+ ];
+            $crate::swizzle!(@grouped $vis $dst_type; ( $( $attr ),+ ); ( $( $attr ),+ ); ( $( $attr )+ ));
+        );
+    };
+    (
+        grouped $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "grouped "; 5; [ $( $attr )+ ];
+            $crate::swizzle!(@grouped $vis $dst_type; ( $( $attr ),+ ); ( $( $attr ),+ ); ( $( $attr )+ ));
+        );
+    };
+
+    // Case for a self-swizzle callable from JavaScript. Every function is generated in its own
+    // `#[wasm_bindgen]` impl block, as the attribute would see the unexpanded recursion of the
+    // generation in a single block. Must be invoked at module level.
+    // ```
+    // swizzle!(wasm Vec2 {x, y}) =>
+    //     #[wasm_bindgen]
+    //     impl Vec2 { pub fn xx(&self) -> Vec2 { Vec2 { x: self.x, y: self.x } } }
+    //     #[wasm_bindgen]
+    //     impl Vec2 { pub fn xy(&self) -> Vec2 { Vec2 { x: self.x, y: self.y } } }
+    //     ...
+    // ```
+    (
+        wasm unbounded $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::__swizzle_wasm_bindgen! {
+            $crate::swizzle!(@self { wasm } $dst_type; ( $( $attr ),+ ); $( $attr )+);
+        }
+    };
+    (
+        wasm max_fields = $max:tt $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "wasm "; $max; [ $( $attr )+ ];
+            $crate::swizzle!(wasm unbounded $dst_type { $( $attr ),+ });
+        );
+    };
+    (
+        wasm $dst_type:ident {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "wasm "; 5; [ $( $attr )+ ];
+            $crate::swizzle!(wasm unbounded $dst_type { $( $attr ),+ });
+        );
+    };
+
+    // Case for a self-swizzle optimised for code size rather than speed. The attributes must all be
+    // of the same type. Every generated function is a thin wrapper passing the indices of its
+    // pattern to a single shared function, which is never inlined, so the permutation code exists
+    // only once in the binary.
+    // ```
+    // swizzle!(compact Vec2 {x, y}) =>
+    //     #[inline(never)]
+    //     const fn __swizzle_permute(&self, idx: [usize; 2]) -> Vec2 { ... }
+    //     pub const fn xx(&self) -> Vec2 { self.__swizzle_permute([0, 0]) }
+    //     pub const fn xy(&self) -> Vec2 { self.__swizzle_permute([0, 0 + 1]) }
+    //     pub const fn yx(&self) -> Vec2 { self.__swizzle_permute([0 + 1, 0]) }
+    //     pub const fn yy(&self) -> Vec2 { self.__swizzle_permute([0 + 1, 0 + 1]) }
+    // ```
+    (
+        compact unbounded $dst_type:path {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(@compact $dst_type; ( $( $attr )+ ); []; [0]; $( $attr )+);
+    };
+    (
+        compact max_fields = $max:tt $dst_type:path {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "compact "; $max; [ $( $attr )+ ];
+            $crate::swizzle!(@compact $dst_type; ( $( $attr )+ ); []; [0]; $( $attr )+);
+        );
+    };
+    (
+        compact $dst_type:path {
+            $( $attr:ident ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked "compact "; 5; [ $( $attr )+ ];
+            $crate::swizzle!(@compact $dst_type; ( $( $attr )+ ); []; [0]; $( $attr )+);
+        );
+    };
+
+    // Case for generating multiple swizzle functions where the destination type is created with
+    // attributes set to all combinations of the given source attributes. This method can be used
+    // for converting between different types, with different numbers/names of attributes.
+    // ```
+    // impl Vec3 {
+    //   swizzle!(Vec2 {x: (x, y, z), y: (x, y, z)}) =>
+    //     pub const fn xx(&self) -> Vec2 { Vec2 { x: self.x, y: self.x } }
+    //     pub const fn xy(&self) -> Vec2 { Vec2 { x: self.x, y: self.y } }
+    //     pub const fn xz(&self) -> Vec2 { Vec2 { x: self.x, y: self.z } }
+    //     pub const fn yx(&self) -> Vec2 { Vec2 { x: self.y, y: self.x } }
+    //     pub const fn yy(&self) -> Vec2 { Vec2 { x: self.y, y: self.y } }
+    //     pub const fn yz(&self) -> Vec2 { Vec2 { x: self.y, y: self.z } }
+    //     pub const fn zx(&self) -> Vec2 { Vec2 { x: self.z, y: self.x } }
+    //     pub const fn zy(&self) -> Vec2 { Vec2 { x: self.z, y: self.y } }
+    //     pub const fn zz(&self) -> Vec2 { Vec2 { x: self.z, y: self.z } }
+    // }
+    // ```
+    (
+        $dst_type:path {
+            $(
+                $dst_attr:ident: (
+                    $( $src_attr:ident ),+
+                    $(,)?
+                )
+            ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(@gen { inherent } $dst_type; [] []; [ $( $dst_attr ( $( $src_attr ),+ ) )+ ]);
+    };
+
+    // Case for the multiple method form with the source attributes declared once, after `from`,
+    // and `*` standing for all of them. Destination attributes can still list their own sources.
+    // ```
+    // swizzle!(Vec2 {x: *, y: *} from (x, y, z)) =>
+    //     swizzle!(Vec2 {x: (x, y, z), y: (x, y, z)})
+    // ```
+    (
+        $dst_type:path {
+            $( $dst_attr:ident: $src:tt ),+
+            $(,)?
+        } from $all:tt
+    ) => {
+        $crate::swizzle!(@from $dst_type; $all; []; $( $dst_attr $src )+);
+    };
+
+    // Internal: replace each `*` of the `from` form with the source attributes.
+    (
+        @from $dst_type:path; $all:tt; [ $( $done:tt )* ]; $dst_attr:ident * $( $rest:tt )*
+    ) => {
+        $crate::swizzle!(@from $dst_type; $all; [ $( $done )* $dst_attr $all ]; $( $rest )*);
+    };
+    (
+        @from $dst_type:path; $all:tt; [ $( $done:tt )* ]; $dst_attr:ident $src:tt $( $rest:tt )*
+    ) => {
+        $crate::swizzle!(@from $dst_type; $all; [ $( $done )* $dst_attr $src ]; $( $rest )*);
+    };
+    (
+        @from $dst_type:path; ( $( $src_attr:ident ),+ $(,)? ); [ $( $dst_attr:ident ( $( $src:ident ),+ $(,)? ) )+ ];
+    ) => {
+        $crate::swizzle!(@gen { inherent } $dst_type; [] []; [ $( $dst_attr ( $( $src ),+ ) )+ ]);
+    };
+
+    // Internal: guard against self-swizzles that would take an unreasonable amount of time to
+    // expand. With more attributes than the limit, 5 unless given with `max_fields`, rather than
+    // appearing to hang the compiler, fail fast with a hint to the `max_fields` and `unbounded`
+    // forms of the invocation. Otherwise continue with the given expansion. Each limit is matched
+    // by its own pair of cases so the check is a single expansion step.
+    (@checked $form:literal; 1; [ $a:ident $b:ident $( $rest:ident )* ]; $( $then:tt )*) => {
+        $crate::swizzle!(@too_many $form; 1);
+    };
+    (@checked $form:literal; 1; [ $( $attr:ident )* ]; $( $then:tt )*) => {
+        $( $then )*
+    };
+    (@checked $form:literal; 2; [ $a:ident $b:ident $c:ident $( $rest:ident )* ]; $( $then:tt )*) => {
+        $crate::swizzle!(@too_many $form; 2);
+    };
+    (@checked $form:literal; 2; [ $( $attr:ident )* ]; $( $then:tt )*) => {
+        $( $then )*
+    };
+    (@checked $form:literal; 3; [ $a:ident $b:ident $c:ident $d:ident $( $rest:ident )* ]; $( $then:tt )*) => {
+        $crate::swizzle!(@too_many $form; 3);
+    };
+    (@checked $form:literal; 3; [ $( $attr:ident )* ]; $( $then:tt )*) => {
+        $( $then )*
+    };
+    (@checked $form:literal; 4; [ $a:ident $b:ident $c:ident $d:ident $e:ident $( $rest:ident )* ]; $( $then:tt )*) => {
+        $crate::swizzle!(@too_many $form; 4);
+    };
+    (@checked $form:literal; 4; [ $( $attr:ident )* ]; $( $then:tt )*) => {
+        $( $then )*
+    };
+    (@checked $form:literal; 5; [ $a:ident $b:ident $c:ident $d:ident $e:ident $f:ident $( $rest:ident )* ]; $( $then:tt )*) => {
+        $crate::swizzle!(@too_many $form; 5);
+    };
+    (@checked $form:literal; 5; [ $( $attr:ident )* ]; $( $then:tt )*) => {
+        $( $then )*
+    };
+    (@checked $form:literal; 6; [ $a:ident $b:ident $c:ident $d:ident $e:ident $f:ident $g:ident $( $rest:ident )* ]; $( $then:tt )*) => {
+        $crate::swizzle!(@too_many $form; 6);
+    };
+    (@checked $form:literal; 6; [ $( $attr:ident )* ]; $( $then:tt )*) => {
+        $( $then )*
+    };
+    (@checked $form:literal; $max:tt; $( $rest:tt )*) => {
+        compile_error!(concat!(
+            "swizzle!: `max_fields` must be an integer from 1 to 6, not `", stringify!($max), "`. ",
+            "Use `", $form, "unbounded` to remove the limit."
+        ));
+    };
+    (@too_many $form:literal; $max:tt) => {
+        compile_error!(concat!(
+            "swizzle!: a self-swizzle with more than ", stringify!($max), " fields generates n^n ",
+            "functions, which is extremely slow to compile. Use `", $form, "max_fields = N` to ",
+            "raise the limit, or `", $form, "unbounded` to remove it."
+        ));
+    };
+
+    // Internal: generate the extension traits for the `grouped` form, one for each attribute taking
+    // the place of the first destination attribute.
+    (
+        @grouped $vis:vis $dst_type:ident; ( $( $lead:ident ),+ ); $all:tt; $attrs:tt
+    ) => {
+        $( $crate::swizzle!(@group $vis $dst_type; $lead; $all; $attrs); )+
+    };
+    (
+        @group $vis:vis $dst_type:ident; $lead:ident; $all:tt; ( $first:ident $( $attr:ident )* )
+    ) => {
+        $crate::__private::paste! {
+            #[doc = "Swizzle functions of `" ]
+            #[doc = stringify!( $dst_type ) ]
+            #[doc = "` starting with `" ]
+            #[doc = stringify!( $lead ) ]
+            #[doc = "`." ]
+            $vis trait [< $dst_type Swizzle $lead:camel >] {
+                $crate::swizzle!(@gen { trait_decl } $dst_type; [] [ $first: $lead, ]; [ $( $attr $all )* ]);
+            }
+
+            impl [< $dst_type Swizzle $lead:camel >] for $dst_type {
+                $crate::swizzle!(@gen { trait_impl } $dst_type; [] [ $first: $lead, ]; [ $( $attr $all )* ]);
+            }
+        }
+    };
+
+    // Internal: number the attributes for the `compact` form, then generate the shared function
+    // followed by the wrappers. Each attribute is paired with its index as `{ attr index }`.
+    (
+        @compact $dst_type:path; $attrs:tt; [ $( $done:tt )* ]; [ $( $index:tt )* ];
+        $attr:ident $( $rest:ident )*
+    ) => {
+        $crate::swizzle!(
+            @compact $dst_type; $attrs; [ $( $done )* { $attr $( $index )* } ]; [ $( $index )* + 1 ];
+            $( $rest )*
+        );
+    };
+    (
+        @compact $dst_type:path; ( $( $attr:ident )+ ); [ $( $done:tt )+ ]; $index:tt;
+    ) => {
+        #[inline(never)]
+        const fn __swizzle_permute(&self, idx: [usize; [$( stringify!($attr) ),+].len()]) -> $dst_type {
+            let values = [ $( self.$attr ),+ ];
+            let [ $( $attr ),+ ] = idx;
+            $dst_type { $( $attr: values[$attr] ),+ }
+        }
+
+        $crate::swizzle!(@self { compact } $dst_type; ( $( $done ),+ ); $( $attr )+);
+    };
+
+    // Internal: generate the metadata of an inherent self-swizzle: the number of functions, the
+    // table of all of the functions, an iterator over them, the list of their
+    // names, and the tables of their inverses and signs. The table is
+    // built by the generation recursion as nested arrays, one level per attribute, with the entries
+    // in the same order as the functions.
+    (
+        @table $dst_type:path; $all:tt; $( $attr:ident )*
+    ) => {
+        /// The number of swizzle functions generated for this type.
+        pub const SWIZZLE_COUNT: usize = {
+            let len: usize = [$( stringify!($attr) ),*].len();
+            len.pow(len as u32)
+        };
+
+        /// Every swizzle function of this type, paired with its name.
+        pub const SWIZZLE_FNS: [(&'static str, fn(&Self) -> Self); {
+            let len: usize = [$( stringify!($attr) ),*].len();
+            len.pow(len as u32)
+        }] = {
+            let nested = $crate::swizzle!(@gen { table } $dst_type; [] []; [ $( $attr $all )* ]);
+            let len: usize = [$( stringify!($attr) ),*].len();
+            // Copy the entries out of the nested arrays in order, indexing one level per attribute
+            // by the digits of the flat index in base `len`, the most significant first.
+            let mut fns = [nested $( [{ let _ = stringify!($attr); 0 }] )*; {
+                let len: usize = [$( stringify!($attr) ),*].len();
+                len.pow(len as u32)
+            }];
+            let mut i = 0;
+            while i < fns.len() {
+                let mut place = fns.len();
+                fns[i] = nested $( [{
+                    let _ = stringify!($attr);
+                    place /= len;
+                    i / place % len
+                }] )*;
+                i += 1;
+            }
+            fns
+        };
+
+        /// Iterate over every swizzle of the value, paired with the name of its function.
+        pub fn swizzles(&self) -> impl ::core::iter::Iterator<Item = (&'static str, Self)> {
+            Self::SWIZZLE_FNS.into_iter().map(move |(name, f)| (name, f(self)))
+        }
+
+        /// The names of every swizzle function of this type.
+        pub const SWIZZLE_NAMES: &'static [&'static str] = &{
+            let mut names = [""; {
+                let len: usize = [$( stringify!($attr) ),*].len();
+                len.pow(len as u32)
+            }];
+            let mut i = 0;
+            while i < names.len() {
+                names[i] = Self::SWIZZLE_FNS[i].0;
+                i += 1;
+            }
+            names
+        };
+
+        /// For every swizzle function of this type, the index in `SWIZZLE_FNS` of the function
+        /// that undoes it, or `None` if it isn't a pure permutation.
+        pub const SWIZZLE_INVERSES: [::core::option::Option<usize>; {
+            let len: usize = [$( stringify!($attr) ),*].len();
+            len.pow(len as u32)
+        }] = $crate::__private::inverse_table([$( stringify!($attr) ),*].len());
+
+        /// For every swizzle function of this type, `1` if it is an even permutation, `-1` if it
+        /// is an odd permutation, and `0` if it isn't a pure permutation. Odd permutations of
+        /// coordinate axes flip the handedness of the coordinate system.
+        pub const SWIZZLE_SIGNS: [i8; {
+            let len: usize = [$( stringify!($attr) ),*].len();
+            len.pow(len as u32)
+        }] = $crate::__private::sign_table([$( stringify!($attr) ),*].len());
+    };
+
+    // Internal: generate the extension trait for the `overlap_ok` form.
+    (
+        @overlap $vis:vis $dst_type:ident {
+            $( $attr:ident ),+
+        }
+    ) => {
+        $crate::__private::paste! {
+            #[doc = "Swizzle functions of `" ]
+            #[doc = stringify!( $dst_type ) ]
+            #[doc = "` that may be shadowed by inherent methods of the same name." ]
+            $vis trait [< $dst_type Swizzle >] {
+                $crate::swizzle!(@self { trait_decl } $dst_type; ( $( $attr ),+ ); $( $attr )+);
+            }
+
+            impl [< $dst_type Swizzle >] for $dst_type {
+                $crate::swizzle!(@self { trait_impl } $dst_type; ( $( $attr ),+ ); $( $attr )+);
+            }
+        }
+    };
+
+    // Internal: expand a self-swizzle into the general form, where every destination attribute may
+    // take the value of any of the attributes. The full attribute list is passed as a single token
+    // tree so that it can be repeated once per destination attribute.
+    (
+        @self $mode:tt $dst_type:path; $all:tt; $( $attr:ident )*
+    ) => {
+        $crate::swizzle!(@gen $mode $dst_type; [] []; [ $( $attr $all )* ]);
+    };
+
+    // Internal: the generation recursion, one destination attribute at a time. `@gen` appends the
+    // attribute chosen in the previous step to the accumulated output and takes the next destination
+    // attribute, then `@branch` recurses once for each of its source attributes. Once the last
+    // destination attribute is reached, `@branch` emits the functions directly. The accumulated
+    // output and the remaining attributes are passed on as single token trees, so they are copied
+    // as-is rather than re-parsed at every step, and the recursion depth is two steps per
+    // destination attribute. `@gen` only finds no destination attributes left when the recursion
+    // is started with the first destination attribute already assigned.
+    (
+        @gen $mode:tt $dst_type:path; $out:tt $new:tt; []
+    ) => {
+        $crate::swizzle!(@emit $mode $dst_type; $out $new);
+    };
+    (
+        @gen $mode:tt $dst_type:path; [ $( $out:tt )* ] [ $( $new:tt )* ];
+        [ $dst_attr:ident $src_attrs:tt $( $rest:tt )* ]
+    ) => {
+        $crate::swizzle! {
+            @branch $mode $dst_type; [ $( $out )* $( $new )* ]; $dst_attr $src_attrs; [ $( $rest )* ]
+        }
+    };
+    (
+        @branch { table } $dst_type:path; $out:tt; $dst_attr:ident ( $( $src_attr:tt ),+ ); []
+    ) => {
+        [ $( $crate::swizzle!(@emit { table } $dst_type; $out [ $dst_attr: $src_attr, ]) ),+ ]
+    };
+    (
+        @branch { table } $dst_type:path; $out:tt; $dst_attr:ident ( $( $src_attr:tt ),+ ); $rest:tt
+    ) => {
+        [ $( $crate::swizzle!(@gen { table } $dst_type; $out [ $dst_attr: $src_attr, ]; $rest) ),+ ]
+    };
+    (
+        @branch $mode:tt $dst_type:path; $out:tt; $dst_attr:ident ( $( $src_attr:tt ),+ ); []
+    ) => {
+        $( $crate::swizzle!(@emit $mode $dst_type; $out [ $dst_attr: $src_attr, ]); )+
+    };
+    (
+        @branch $mode:tt $dst_type:path; $out:tt; $dst_attr:ident ( $( $src_attr:tt ),+ ); $rest:tt
+    ) => {
+        $( $crate::swizzle!(@gen $mode $dst_type; $out [ $dst_attr: $src_attr, ]; $rest); )+
+    };
+
+    // Internal: emit an inherent method. The attribute chosen in the last step of the recursion is
+    // passed separately to save merging it into the accumulated output with one more step.
+    (
+        @emit { inherent } $dst_type:path;
+        [ $( $dst_attr:ident: $src_attr:ident, )* ] [ $( $dst_last:ident: $src_last:ident, )? ]
+    ) => {
+        $crate::__private::paste! {
+            #[doc = "Create an instance of `" ]
+            #[doc = stringify!( $dst_type ) ]
+            #[doc = "` with the values swizzled: `"  [< $($src_attr)* $($src_last)? >] "`" ]
+            #[must_use]
+            #[inline]
+            pub const fn [< $($src_attr)* $($src_last)? >](&self) -> $dst_type {
+                $dst_type { $($dst_attr: self.$src_attr,)* $($dst_last: self.$src_last)? }
+            }
+        }
     };
 
-    // Recursive case for building out the list of source attributes.
+    // Internal: emit an entry of the table of functions, as an expression.
     (
-        $dst_type:path;
-        @bld { $head:ident $(, $tail:ident )* $(,)? }
-        @src { $( ( $( $src_attr:ident ),* ) ),* $(,)? }
-        @dst { $( $dst_attr:ident ),* $(,)? }
-        @out { }
+        @emit { table } $dst_type:path;
+        [ $( $dst_attr:ident: $src_attr:ident, )* ] [ $( $dst_last:ident: $src_last:ident, )? ]
     ) => {
-        // Recurse on self with @bld reduced by one and @src extended by one set of @dst attributes.
-        swizzle!(
-            $dst_type;
-            @bld { $( $tail ),* }
-            @src {
-                    ( $( $dst_attr ),* )
-                $(, ( $( $src_attr ),* ) )*
-            }
-            @dst { $( $dst_attr ),* }
-            @out { }
-        );
+        $crate::__private::paste! {
+            (
+                stringify!([< $($src_attr)* $($src_last)? >]),
+                Self::[< $($src_attr)* $($src_last)? >] as fn(&Self) -> Self,
+            )
+        }
     };
 
-    // Terminal case for building out the list of source attributes. @bld is empty.
+    // Internal: emit a wrapper method of the `compact` form.
     (
-        $dst_type:path;
-        @bld { $(,)? }
-        @src { $( ( $( $src_attr:ident ),* ) ),* $(,)? }
-        @dst { $( $dst_attr:ident ),* $(,)? }
-        @out { }
+        @emit { compact } $dst_type:path;
+        [ $( $dst_attr:ident: { $src_attr:ident $index:expr }, )* ]
+        [ $( $dst_last:ident: { $src_last:ident $index_last:expr }, )? ]
     ) => {
-        // Call the main generation function with the final lists.
-        swizzle!(
-            $dst_type;
-            @src { $( ( $( $src_attr ),* ) ),*} ;
-            @dst { $( $dst_attr ),* } ;
-            @out { };
-        );
+        $crate::__private::paste! {
+            #[doc = "Create an instance of `" ]
+            #[doc = stringify!( $dst_type ) ]
+            #[doc = "` with the values swizzled: `"  [< $($src_attr)* $($src_last)? >] "`" ]
+            #[must_use]
+            #[inline]
+            pub const fn [< $($src_attr)* $($src_last)? >](&self) -> $dst_type {
+                self.__swizzle_permute([ $( $index, )* $( $index_last )? ])
+            }
+        }
     };
 
-    // Case for generating multiple swizzle functions where the destination type is created with
-    // attributes set to all combinations of the given source attributes. This method can be used
-    // for converting between different types, with different numbers/names of attributes.
-    // ```
-    // impl Vec3 {
-    //   swizzle!(Vec2 {x: (x, y, z), y: (x, y, z)}) =>
-    //     pub const fn xx(&self) -> Vec2 { Vec2 { x: self.x, y: self.x } }
-    //     pub const fn xy(&self) -> Vec2 { Vec2 { x: self.x, y: self.y } }
-    //     pub const fn xz(&self) -> Vec2 { Vec2 { x: self.x, y: self.z } }
-    //     pub const fn yx(&self) -> Vec2 { Vec2 { x: self.y, y: self.x } }
-    //     pub const fn yy(&self) -> Vec2 { Vec2 { x: self.y, y: self.y } }
-    //     pub const fn yz(&self) -> Vec2 { Vec2 { x: self.y, y: self.z } }
-    //     pub const fn zx(&self) -> Vec2 { Vec2 { x: self.z, y: self.x } }
-    //     pub const fn zy(&self) -> Vec2 { Vec2 { x: self.z, y: self.y } }
-    //     pub const fn zz(&self) -> Vec2 { Vec2 { x: self.z, y: self.z } }
-    // }
-    // ```
+    // Internal: emit a method callable from JavaScript, in an impl block of its own.
     (
-        $dst_type:path {
-            $(
-                $dst_attr:ident: (
-                    $( $src_attr:ident ),+
-                    $(,)?
-                )
-            ),+
-            $(,)?
-        }
+        @emit { wasm } $dst_type:path;
+        [ $( $dst_attr:ident: $src_attr:ident, )* ] [ $( $dst_last:ident: $src_last:ident, )? ]
     ) => {
-        // Reorganize the parameters in the form necessary for the main generation function.
-        swizzle!(
-            $dst_type;
-            @src { $( ( $($src_attr),+ ) ),+ };
-            @dst { $( $dst_attr ),+ };
-            @out { };
-        );
+        $crate::__private::paste! {
+            #[$crate::__private::wasm_bindgen::prelude::wasm_bindgen(
+                wasm_bindgen = $crate::__private::wasm_bindgen
+            )]
+            impl $dst_type {
+                #[doc = "Create an instance of `" ]
+                #[doc = stringify!( $dst_type ) ]
+                #[doc = "` with the values swizzled: `"  [< $($src_attr)* $($src_last)? >] "`" ]
+                #[must_use]
+                #[inline]
+                pub fn [< $($src_attr)* $($src_last)? >](&self) -> $dst_type {
+                    $dst_type { $($dst_attr: self.$src_attr,)* $($dst_last: self.$src_last)? }
+                }
+            }
+        }
     };
 
-    // Main recursive case for generating the swizzle functions.
+    // Internal: emit the method declaration of an extension trait.
     (
-        $dst_type:path;
-        @src{
-                (
-                    $src_attr_head_head:ident
-                    $( , $src_attr_head_tail:ident )* $(,)?
-                )
-            $(, ( $( $src_attr_tail:ident ),+ $(,)? ) )*
-            $(,)?
-        };
-        @dst{
-                $dst_attr_head:ident
-            $(, $dst_attr_tail:ident )*
-            $(,)?
-        };
-        @out{
-            $( $out_dst:ident: $out_src:ident ),*
-            $(,)?
-        };
+        @emit { trait_decl } $dst_type:path;
+        [ $( $dst_attr:ident: $src_attr:ident, )* ] [ $( $dst_last:ident: $src_last:ident, )? ]
     ) => {
-        swizzle!(
-            $dst_type;
-            @src {
-                ( $($src_attr_head_tail),* )
-                $( , ( $( $src_attr_tail ),* ) )*
-            };
-            @dst {
-                $dst_attr_head
-                $( , $dst_attr_tail )*
-            };
-            @out {
-                $( $out_dst: $out_src , )*
-            };
-        );
-        swizzle!(
-            $dst_type;
-            @src {
-                $( ( $( $src_attr_tail ),* ) ),*
-            };
-            @dst {
-                $( $dst_attr_tail ),*
-            };
-            @out {
-                $( $out_dst: $out_src , )*
-                $dst_attr_head: $src_attr_head_head
-            };
-        );
+        $crate::__private::paste! {
+            #[doc = "Create an instance of `" ]
+            #[doc = stringify!( $dst_type ) ]
+            #[doc = "` with the values swizzled: `"  [< $($src_attr)* $($src_last)? >] "`" ]
+            #[must_use]
+            fn [< $($src_attr)* $($src_last)? >](&self) -> $dst_type;
+        }
     };
 
-    // Terminal case for when a list of source attributes is empty.
+    // Internal: emit the method definition of an extension trait implementation.
     (
-        $dst_type:path;
-        @src{
-            ( )
-            $(, ( $( $src_attr_tail:ident ),+ $(,)? ) )*
-            $(,)?
-        };
-        @dst{
-                $dst_attr_head:ident
-            $(, $dst_attr_tail:ident )*
-            $(,)?
-        };
-        @out{
-            $( $out_dst:ident: $out_src:ident ),*
-            $(,)?
-        };
+        @emit { trait_impl } $dst_type:path;
+        [ $( $dst_attr:ident: $src_attr:ident, )* ] [ $( $dst_last:ident: $src_last:ident, )? ]
     ) => {
+        $crate::__private::paste! {
+            #[inline]
+            fn [< $($src_attr)* $($src_last)? >](&self) -> $dst_type {
+                $dst_type { $($dst_attr: self.$src_attr,)* $($dst_last: self.$src_last)? }
+            }
+        }
     };
 
-
-    // Terminal generation case.
+    // Internal: emit a test of a method for `swizzle_tests!`, comparing each field of the result
+    // with the field of the reference value it should be taken from.
     (
-        $dst_type:path;
-        @src{ $(,)? };
-        @dst{ $(,)? };
-        @out{ $( $out_dst:ident: $out_src:ident ),+ $(,)? };
+        @emit { test $value:expr } $dst_type:path;
+        [ $( $dst_attr:ident: $src_attr:ident, )* ] [ $( $dst_last:ident: $src_last:ident, )? ]
     ) => {
-        swizzle!($dst_type { $( $out_dst: $out_src ),+ });
+        $crate::__private::paste! {
+            #[test]
+            fn [< $($src_attr)* $($src_last)? >]() {
+                let value = $value;
+                let swizzled: $dst_type = value.[< $($src_attr)* $($src_last)? >]();
+                $(
+                    assert_eq!(
+                        swizzled.$dst_attr,
+                        value.$src_attr,
+                        concat!("`", stringify!($dst_attr), "` is not `", stringify!($src_attr), "`")
+                    );
+                )*
+                $(
+                    assert_eq!(
+                        swizzled.$dst_last,
+                        value.$src_last,
+                        concat!("`", stringify!($dst_last), "` is not `", stringify!($src_last), "`")
+                    );
+                )?
+            }
+        }
     };
-
 }
 
 #[allow(dead_code)]
@@ -567,6 +1404,299 @@ mod tests {
         assert_eq!((adda.a, adda.b, adda.c, adda.d), (1, 4, 4, 1));
     }
 
+    #[test]
+    fn test_swizzle_fns_table() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(table TestStruct { a, b });
+        }
+
+        let s = TestStruct { a: 1, b: 2 };
+
+        let names = TestStruct::SWIZZLE_FNS.map(|(name, _)| name);
+        assert_eq!(names, ["aa", "ab", "ba", "bb"]);
+        assert_eq!(TestStruct::SWIZZLE_NAMES, ["aa", "ab", "ba", "bb"]);
+        assert_eq!(TestStruct::SWIZZLE_COUNT, 4);
+
+        let mut swizzles = s.swizzles();
+        assert_eq!(swizzles.next(), Some(("aa", TestStruct { a: 1, b: 1 })));
+        assert_eq!(swizzles.next(), Some(("ab", TestStruct { a: 1, b: 2 })));
+        assert_eq!(swizzles.next(), Some(("ba", TestStruct { a: 2, b: 1 })));
+        assert_eq!(swizzles.next(), Some(("bb", TestStruct { a: 2, b: 2 })));
+        assert_eq!(swizzles.next(), None);
+
+        let (_, ba) = TestStruct::SWIZZLE_FNS[2];
+        assert_eq!(ba(&s), s.ba());
+
+        for (name, f) in TestStruct::SWIZZLE_FNS {
+            let v = f(&s);
+            let get = |c| if c == 'a' { s.a } else { s.b };
+            let mut chars = name.chars();
+            assert_eq!(v.a, get(chars.next().unwrap()), "{name}");
+            assert_eq!(v.b, get(chars.next().unwrap()), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_swizzle_fns_table_compact() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(table compact TestStruct { a, b, c });
+        }
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(TestStruct::SWIZZLE_FNS.len(), 27);
+        assert_eq!(TestStruct::SWIZZLE_COUNT, 27);
+        assert_eq!(TestStruct::SWIZZLE_FNS[0].0, "aaa");
+        assert_eq!(TestStruct::SWIZZLE_FNS[26].0, "ccc");
+
+        let index = |name| {
+            TestStruct::SWIZZLE_NAMES
+                .iter()
+                .position(|&n| n == name)
+                .unwrap()
+        };
+        let inverse =
+            |name| TestStruct::SWIZZLE_INVERSES[index(name)].map(|i| TestStruct::SWIZZLE_NAMES[i]);
+        assert_eq!(inverse("abc"), Some("abc"));
+        assert_eq!(inverse("cab"), Some("bca"));
+        assert_eq!(inverse("bca"), Some("cab"));
+        assert_eq!(inverse("acb"), Some("acb"));
+        assert_eq!(inverse("aab"), None);
+        assert_eq!(inverse("ccc"), None);
+        assert_eq!(s.cab().bca(), s);
+
+        let sign = |name| TestStruct::SWIZZLE_SIGNS[index(name)];
+        assert_eq!(sign("abc"), 1);
+        assert_eq!(sign("cab"), 1);
+        assert_eq!(sign("acb"), -1);
+        assert_eq!(sign("cba"), -1);
+        assert_eq!(sign("aab"), 0);
+        assert_eq!(
+            TestStruct::SWIZZLE_INVERSES
+                .iter()
+                .filter(|i| i.is_some())
+                .count(),
+            6
+        );
+        for (name, f) in TestStruct::SWIZZLE_FNS {
+            if name == "cab" {
+                assert_eq!(f(&s), s.cab());
+            }
+        }
+    }
+
+    #[test]
+    fn test_swizzle_overlap_ok() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        swizzle!(overlap_ok TestStruct { a, b });
+
+        impl TestStruct {
+            swizzle!(TestStruct { a: b, b: a });
+            swizzle!(TestStruct { a: b, b: b });
+        }
+
+        let s = TestStruct { a: 1, b: 2 };
+
+        assert_eq!(s.aa(), TestStruct { a: 1, b: 1 });
+        assert_eq!(s.ab(), TestStruct { a: 1, b: 2 });
+        assert_eq!(s.ba(), TestStruct { a: 2, b: 1 });
+        assert_eq!(s.bb(), TestStruct { a: 2, b: 2 });
+
+        // The inherent methods are usable in const contexts, the trait methods are not.
+        const BA: TestStruct = TestStruct { a: 1, b: 2 }.ba();
+        assert_eq!(BA, TestStruct { a: 2, b: 1 });
+        assert_eq!(TestStructSwizzle::ba(&s), TestStruct { a: 2, b: 1 });
+    }
+
+    #[test]
+    fn test_swizzle_self_form_cross_type() {
+        #[derive(Debug, PartialEq)]
+        struct Vec3 {
+            x: u8,
+            y: u8,
+            z: u8,
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Vec2 {
+            x: u8,
+            y: u8,
+        }
+
+        // The destination isn't `Self`, so no table of functions may be generated.
+        impl Vec3 {
+            swizzle!(Vec2 { x, y });
+        }
+
+        let v = Vec3 { x: 1, y: 2, z: 3 };
+
+        assert_eq!(v.yx(), Vec2 { x: 2, y: 1 });
+        assert_eq!(v.xx(), Vec2 { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn test_swizzle_unbounded() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(unbounded TestStruct { a, b });
+        }
+
+        let s = TestStruct { a: 1, b: 2 };
+
+        assert_eq!(s.ba(), TestStruct { a: 2, b: 1 });
+        assert_eq!(s.bb(), TestStruct { a: 2, b: 2 });
+    }
+
+    #[test]
+    fn test_swizzle_max_fields() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(table max_fields = 3 TestStruct { a, b, c });
+        }
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.cba(), TestStruct { a: 3, b: 2, c: 1 });
+        assert_eq!(TestStruct::SWIZZLE_COUNT, 27);
+    }
+
+    #[test]
+    fn test_swizzle_compact() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        impl TestStruct {
+            swizzle!(compact TestStruct { a, b, c });
+        }
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.aaa(), TestStruct { a: 1, b: 1, c: 1 });
+        assert_eq!(s.abc(), TestStruct { a: 1, b: 2, c: 3 });
+        assert_eq!(s.cab(), TestStruct { a: 3, b: 1, c: 2 });
+        assert_eq!(s.bcb(), TestStruct { a: 2, b: 3, c: 2 });
+        assert_eq!(s.cba().cba(), s);
+
+        const CBA: TestStruct = TestStruct { a: 1, b: 2, c: 3 }.cba();
+        assert_eq!(CBA, TestStruct { a: 3, b: 2, c: 1 });
+    }
+
+    #[test]
+    fn test_swizzle_grouped() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        swizzle!(grouped TestStruct { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.aaa(), TestStruct { a: 1, b: 1, c: 1 });
+        assert_eq!(s.abc(), TestStruct { a: 1, b: 2, c: 3 });
+        assert_eq!(s.bca(), TestStruct { a: 2, b: 3, c: 1 });
+        assert_eq!(s.cba(), TestStruct { a: 3, b: 2, c: 1 });
+        assert_eq!(TestStructSwizzleB::bab(&s), TestStruct { a: 2, b: 1, c: 2 });
+    }
+
+    #[cfg(feature = "wasm-bindgen")]
+    #[test]
+    fn test_swizzle_wasm() {
+        use wasm_bindgen::prelude::wasm_bindgen;
+
+        #[wasm_bindgen]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct TestStruct {
+            pub a: u8,
+            pub b: u8,
+            pub c: u8,
+        }
+
+        swizzle!(wasm TestStruct { a, b, c });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+
+        assert_eq!(s.abc(), s);
+        assert_eq!(s.cab(), TestStruct { a: 3, b: 1, c: 2 });
+        assert_eq!(s.bbb(), TestStruct { a: 2, b: 2, c: 2 });
+    }
+
+    #[test]
+    fn test_swizzle_extern() {
+        #[repr(C)]
+        #[derive(Debug, PartialEq)]
+        pub struct TestStruct {
+            a: u8,
+            b: u8,
+            c: u8,
+        }
+
+        #[repr(C)]
+        #[derive(Debug, PartialEq)]
+        pub struct TestPair {
+            a: u8,
+            b: u8,
+        }
+
+        swizzle!(extern "C" TestStruct { a: c, b: a, c: b });
+        swizzle!(extern "C" TestStruct => TestPair { a: b, b: b });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+        assert_eq!(test_struct_cab(s), TestStruct { a: 3, b: 1, c: 2 });
+
+        let s = TestStruct { a: 1, b: 2, c: 3 };
+        assert_eq!(test_struct_to_test_pair_bb(s), TestPair { a: 2, b: 2 });
+    }
+
+    #[test]
+    fn test_swizzle_grouped_1_field() {
+        #[derive(Debug, PartialEq)]
+        struct TestStruct {
+            first: u8,
+        }
+
+        swizzle!(grouped TestStruct { first });
+
+        let s = TestStruct { first: 1 };
+
+        assert_eq!(TestStructSwizzleFirst::first(&s), TestStruct { first: 1 });
+    }
+
     #[test]
     fn test_swizzle_struct_with_different_field_names() {
         struct CustomStruct {
@@ -1025,6 +2155,48 @@ mod tests {
         assert_eq!((vec2_xy.x, vec2_xy.y), (1.0, 2.0));
     }
 
+    #[test]
+    fn test_swizzle_from_source_set() {
+        struct Vec3 {
+            x: f32,
+            y: f32,
+            z: f32,
+        }
+
+        struct Vec2 {
+            x: f32,
+            y: f32,
+        }
+
+        struct Rgb {
+            r: f32,
+            g: f32,
+            b: f32,
+        }
+
+        impl Vec3 {
+            swizzle!(Vec2 { x: *, y: * } from (x, y, z));
+            // Explicit sources can be mixed with `*`.
+            swizzle!(Rgb { r: *, g: (y), b: (x, z) } from (x, y, z));
+        }
+
+        let v = Vec3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+
+        let v_zx = v.zx();
+        assert_eq!((v_zx.x, v_zx.y), (3.0, 1.0));
+        let v_yy = v.yy();
+        assert_eq!((v_yy.x, v_yy.y), (2.0, 2.0));
+
+        let c = v.zyx();
+        assert_eq!((c.r, c.g, c.b), (3.0, 2.0, 1.0));
+        let c = v.xyz();
+        assert_eq!((c.r, c.g, c.b), (1.0, 2.0, 3.0));
+    }
+
     #[test]
     fn test_swizzle_type_conversion_edge_cases() {
         struct Single {
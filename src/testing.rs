@@ -0,0 +1,137 @@
+/// Macro for generating the tests of the functions generated by `swizzle!`.
+///
+/// Given a reference value of the source type and the same spec as a `swizzle!` invocation, the
+/// macro generates a `#[cfg(test)]` module with a test for each generated function, named after
+/// it, which calls the function on the reference value and checks each field of the result
+/// against the field of the reference value it should be taken from. Give the fields of the
+/// reference value different values, so that taking the wrong field fails the test.
+///
+/// The fields must be `PartialEq` and `Debug`. Like `swizzle!`, self-swizzles of more than 5
+/// fields must be prefixed with `max_fields = N` or `unbounded`.
+///
+/// ```rust
+/// use swizzle::{swizzle, swizzle_tests};
+///
+/// struct Vec2 { x: f32, y: f32 }
+/// struct Vec3 { x: f32, y: f32, z: f32 }
+///
+/// impl Vec3 {
+///     swizzle!(Vec3 { x, y, z });
+///     swizzle!(Vec2 { x: (x, y, z), y: (x, y, z) });
+/// }
+///
+/// swizzle_tests!(mod vec3_swizzles, Vec3 { x: 1.0, y: 2.0, z: 3.0 } => Vec3 { x, y, z });
+/// swizzle_tests!(
+///     mod vec3_to_vec2_swizzles,
+///     Vec3 { x: 1.0, y: 2.0, z: 3.0 } => Vec2 { x: (x, y, z), y: (x, y, z) }
+/// );
+/// ```
+#[macro_export]
+macro_rules! swizzle_tests {
+    (
+        mod $name:ident, $value:expr => $dst_type:path {
+            $( $dst_attr:ident: $src_attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle_tests!(@mod $name;
+            $crate::swizzle!(@emit { test $value } $dst_type; [ $( $dst_attr: $src_attr, )* ] []);
+        );
+    };
+    (
+        mod $name:ident, $value:expr => unbounded $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle_tests!(@mod $name;
+            $crate::swizzle!(@self { test $value } $dst_type; ( $( $attr ),* ); $( $attr )*);
+        );
+    };
+    (
+        mod $name:ident, $value:expr => max_fields = $max:tt $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked ""; $max; [ $( $attr )* ];
+            $crate::swizzle_tests!(@mod $name;
+                $crate::swizzle!(@self { test $value } $dst_type; ( $( $attr ),* ); $( $attr )*);
+            );
+        );
+    };
+    (
+        mod $name:ident, $value:expr => $dst_type:path {
+            $( $attr:ident ),*
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle!(
+            @checked ""; 5; [ $( $attr )* ];
+            $crate::swizzle_tests!(@mod $name;
+                $crate::swizzle!(@self { test $value } $dst_type; ( $( $attr ),* ); $( $attr )*);
+            );
+        );
+    };
+    (
+        mod $name:ident, $value:expr => $dst_type:path {
+            $(
+                $dst_attr:ident: (
+                    $( $src_attr:ident ),+
+                    $(,)?
+                )
+            ),+
+            $(,)?
+        }
+    ) => {
+        $crate::swizzle_tests!(@mod $name;
+            $crate::swizzle!(
+                @gen { test $value } $dst_type; [] []; [ $( $dst_attr ( $( $src_attr ),+ ) )+ ]
+            );
+        );
+    };
+    (@mod $name:ident; $( $tests:tt )*) => {
+        #[cfg(test)]
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $( $tests )*
+        }
+    };
+}
+
+#[allow(dead_code)]
+#[cfg(test)]
+mod tests {
+    use crate::swizzle;
+
+    #[derive(Debug, PartialEq)]
+    struct Rgb {
+        r: u8,
+        g: u8,
+        b: u8,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Gray {
+        v: u8,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct GrayAlpha {
+        v: u8,
+        a: u8,
+    }
+
+    impl Rgb {
+        swizzle!(Rgb { r, g, b });
+        swizzle!(Gray { v: (r, g, b) });
+        swizzle!(GrayAlpha { v: g, a: b });
+    }
+
+    swizzle_tests!(mod rgb, Rgb { r: 1, g: 2, b: 3 } => Rgb { r, g, b });
+    swizzle_tests!(mod rgb_to_gray, Rgb { r: 1, g: 2, b: 3 } => Gray { v: (r, g, b) });
+    swizzle_tests!(mod rgb_to_gray_alpha, Rgb { r: 1, g: 2, b: 3 } => GrayAlpha { v: g, a: b });
+}
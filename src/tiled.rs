@@ -0,0 +1,243 @@
+//! Conversions between linear and tiled texture memory, the address swizzles of GPUs and consoles.
+//!
+//! A tiled, or block-linear, texture is split into blocks of pixels, which are stored one after
+//! another in rows of blocks, with the pixels of each block in rows, so the pixels of a block are
+//! close together in memory. Images that aren't a whole number of blocks are padded to the next
+//! block in each direction, and the padding is left unchanged. Linear images are rows of pixels
+//! with a `pitch`, the number of bytes from the start of one row to the next, which may include
+//! padding.
+//!
+//! ```rust
+//! use swizzle::tiled::Tiling;
+//!
+//! // A 4x2 image of 1-byte pixels, in 2x2 blocks.
+//! let tiling = Tiling::new(2, 2, 1).unwrap();
+//! let linear = [0, 1, 2, 3, 4, 5, 6, 7];
+//! let mut tiled = [0; 8];
+//! tiling.linear_to_tiled(&linear, 4, &mut tiled, 4, 2);
+//! assert_eq!(tiled, [0, 1, 4, 5, 2, 3, 6, 7]);
+//!
+//! let mut round_trip = [0; 8];
+//! tiling.tiled_to_linear(&tiled, &mut round_trip, 4, 4, 2);
+//! assert_eq!(round_trip, linear);
+//! ```
+
+/// The blocks of a tiled texture: their width and height in pixels, and the bytes of each pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tiling {
+    block_width: usize,
+    block_height: usize,
+    bytes_per_pixel: usize,
+}
+
+impl Tiling {
+    /// Create the tiling of blocks of `block_width` by `block_height` pixels of `bytes_per_pixel`
+    /// bytes. Returns `None` if any of them is `0`.
+    #[must_use]
+    pub const fn new(
+        block_width: usize,
+        block_height: usize,
+        bytes_per_pixel: usize,
+    ) -> Option<Self> {
+        if block_width == 0 || block_height == 0 || bytes_per_pixel == 0 {
+            return None;
+        }
+        Some(Self {
+            block_width,
+            block_height,
+            bytes_per_pixel,
+        })
+    }
+
+    /// The width of each block in pixels.
+    #[must_use]
+    pub const fn block_width(&self) -> usize {
+        self.block_width
+    }
+
+    /// The height of each block in pixels.
+    #[must_use]
+    pub const fn block_height(&self) -> usize {
+        self.block_height
+    }
+
+    /// The number of bytes of each pixel.
+    #[must_use]
+    pub const fn bytes_per_pixel(&self) -> usize {
+        self.bytes_per_pixel
+    }
+
+    /// The number of bytes of each block.
+    #[must_use]
+    pub const fn block_len(&self) -> usize {
+        self.block_width * self.block_height * self.bytes_per_pixel
+    }
+
+    /// The number of bytes of a tiled image of `width` by `height` pixels, padded to whole blocks.
+    #[must_use]
+    pub const fn tiled_len(&self, width: usize, height: usize) -> usize {
+        width.div_ceil(self.block_width) * height.div_ceil(self.block_height) * self.block_len()
+    }
+
+    /// The offset of the first byte of the pixel at `x`, `y` in a tiled image `width` pixels wide.
+    #[must_use]
+    pub const fn tiled_offset(&self, x: usize, y: usize, width: usize) -> usize {
+        let blocks_per_row = width.div_ceil(self.block_width);
+        let block = (y / self.block_height) * blocks_per_row + x / self.block_width;
+        let pixel = (y % self.block_height) * self.block_width + x % self.block_width;
+        block * self.block_len() + pixel * self.bytes_per_pixel
+    }
+
+    /// Copy the linear image `linear`, of `width` by `height` pixels with rows `pitch` bytes apart,
+    /// to the tiled image `tiled`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pitch` is less than the width of a row, or either image is too short for
+    /// `width` by `height` pixels.
+    pub fn linear_to_tiled(
+        &self,
+        linear: &[u8],
+        pitch: usize,
+        tiled: &mut [u8],
+        width: usize,
+        height: usize,
+    ) {
+        self.check(linear.len(), pitch, tiled.len(), width, height);
+        self.for_each_run(pitch, width, height, |linear_offset, tiled_offset, len| {
+            tiled[tiled_offset..tiled_offset + len]
+                .copy_from_slice(&linear[linear_offset..linear_offset + len]);
+        });
+    }
+
+    /// Copy the tiled image `tiled`, of `width` by `height` pixels, to the linear image `linear`
+    /// with rows `pitch` bytes apart. The padding at the end of each row of `linear` is left
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pitch` is less than the width of a row, or either image is too short for
+    /// `width` by `height` pixels.
+    pub fn tiled_to_linear(
+        &self,
+        tiled: &[u8],
+        linear: &mut [u8],
+        pitch: usize,
+        width: usize,
+        height: usize,
+    ) {
+        self.check(linear.len(), pitch, tiled.len(), width, height);
+        self.for_each_run(pitch, width, height, |linear_offset, tiled_offset, len| {
+            linear[linear_offset..linear_offset + len]
+                .copy_from_slice(&tiled[tiled_offset..tiled_offset + len]);
+        });
+    }
+
+    fn check(
+        &self,
+        linear_len: usize,
+        pitch: usize,
+        tiled_len: usize,
+        width: usize,
+        height: usize,
+    ) {
+        let row_len = width * self.bytes_per_pixel;
+        assert!(
+            pitch >= row_len,
+            "pixel pitch is less than the width of a row"
+        );
+        assert!(
+            height == 0 || linear_len >= (height - 1) * pitch + row_len,
+            "linear image is too short for its size"
+        );
+        assert!(
+            tiled_len >= self.tiled_len(width, height),
+            "tiled image is too short for its size"
+        );
+    }
+
+    /// Calls `copy` with the linear offset, tiled offset, and length of each run of bytes that is
+    /// contiguous in both layouts: the pixels of one row of one block.
+    fn for_each_run(
+        &self,
+        pitch: usize,
+        width: usize,
+        height: usize,
+        mut copy: impl FnMut(usize, usize, usize),
+    ) {
+        for y in 0..height {
+            for x in (0..width).step_by(self.block_width) {
+                let len = self.block_width.min(width - x) * self.bytes_per_pixel;
+                copy(
+                    y * pitch + x * self.bytes_per_pixel,
+                    self.tiled_offset(x, y, width),
+                    len,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiling() {
+        assert_eq!(Tiling::new(0, 4, 4), None);
+        assert_eq!(Tiling::new(4, 0, 4), None);
+        assert_eq!(Tiling::new(4, 4, 0), None);
+
+        let tiling = Tiling::new(4, 2, 2).unwrap();
+        assert_eq!(tiling.block_width(), 4);
+        assert_eq!(tiling.block_height(), 2);
+        assert_eq!(tiling.bytes_per_pixel(), 2);
+        assert_eq!(tiling.block_len(), 16);
+        assert_eq!(tiling.tiled_len(8, 4), 64);
+        assert_eq!(tiling.tiled_len(5, 3), 64);
+        assert_eq!(tiling.tiled_offset(0, 0, 8), 0);
+        assert_eq!(tiling.tiled_offset(1, 1, 8), 10);
+        assert_eq!(tiling.tiled_offset(4, 0, 8), 16);
+        assert_eq!(tiling.tiled_offset(0, 2, 8), 32);
+        assert_eq!(tiling.tiled_offset(5, 3, 8), 58);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // A 5x3 image of 2-byte pixels with a pitch of 12 bytes, in 2x2 blocks padded to 6x4.
+        let tiling = Tiling::new(2, 2, 2).unwrap();
+        let linear: [u8; 36] = core::array::from_fn(|i| if i % 12 < 10 { i as u8 + 1 } else { 0 });
+        let mut tiled = [0xFF; 48];
+        tiling.linear_to_tiled(&linear, 12, &mut tiled, 5, 3);
+        assert_eq!(tiled[..8], [1, 2, 3, 4, 13, 14, 15, 16]);
+        assert_eq!(tiled[16..24], [9, 10, 0xFF, 0xFF, 21, 22, 0xFF, 0xFF]);
+        assert_eq!(tiled[24..32], [25, 26, 27, 28, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        let mut round_trip = [0; 36];
+        tiling.tiled_to_linear(&tiled, &mut round_trip, 12, 5, 3);
+        assert_eq!(round_trip, linear);
+
+        for y in 0..3 {
+            for x in 0..5 {
+                let offset = tiling.tiled_offset(x, y, 5);
+                assert_eq!(tiled[offset], linear[y * 12 + x * 2]);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic = "pixel pitch is less than the width of a row"]
+    fn test_short_pitch() {
+        Tiling::new(2, 2, 1)
+            .unwrap()
+            .linear_to_tiled(&[0; 8], 3, &mut [0; 8], 4, 2);
+    }
+
+    #[test]
+    #[should_panic = "tiled image is too short for its size"]
+    fn test_short_tiled() {
+        Tiling::new(2, 2, 1)
+            .unwrap()
+            .tiled_to_linear(&[0; 6], &mut [0; 6], 3, 3, 2);
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{Components, DynSwizzle, SwizzleError};
+use core::ops::{Index, IndexMut};
+
+/// A vector of `N` values backed by an array, with runtime swizzles for any `N`, and the classic
+/// named swizzles, e.g. `zyx()`, for up to 4 values.
+///
+/// The named swizzles use the axis names `x`, `y`, `z`, and `w`, for the values at index 0 to 3,
+/// and generate every combination of 1 to 4 of the vector's axes.
+///
+/// ```rust
+/// use swizzle::Vector;
+///
+/// let v = Vector::new([1.0, 2.0, 3.0]);
+///
+/// assert_eq!(v.zyx(), Vector::new([3.0, 2.0, 1.0]));
+/// assert_eq!(v.xyzx(), Vector::new([1.0, 2.0, 3.0, 1.0]));
+/// assert_eq!(v.y(), 2.0);
+///
+/// // Runtime swizzles work for any number of values.
+/// let v = Vector::new([1, 2, 3, 4, 5, 6]);
+/// assert_eq!(v.swizzle([5, 0, 0]), Vector::new([6, 1, 1]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Create a vector from an array of values.
+    #[must_use]
+    #[inline]
+    pub const fn new(array: [T; N]) -> Self {
+        Self(array)
+    }
+
+    /// Create a vector where each value is returned by `f` with its index.
+    #[must_use]
+    #[inline]
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self(core::array::from_fn(f))
+    }
+
+    /// Returns the values as an array.
+    #[must_use]
+    #[inline]
+    pub fn to_array(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T: Copy, const N: usize> Vector<T, N> {
+    /// Create a vector of `M` values, where the value at each position is taken from the value at
+    /// the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of range.
+    #[must_use]
+    pub fn swizzle<const M: usize>(&self, idx: [usize; M]) -> Vector<T, M> {
+        Vector::from_fn(|i| {
+            assert!(idx[i] < N, "swizzle index out of range");
+            self.0[idx[i]]
+        })
+    }
+
+    /// Create a vector of `M` values, where the value at each position is taken from the value at
+    /// the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwizzleError::IndexOutOfRange`] if any of the indices is out of range.
+    pub fn try_swizzle<const M: usize>(
+        &self,
+        idx: [usize; M],
+    ) -> Result<Vector<T, M>, SwizzleError> {
+        if let Some(&index) = idx.iter().find(|&&index| index >= N) {
+            return Err(SwizzleError::IndexOutOfRange { index, len: N });
+        }
+        Ok(Vector::from_fn(|i| self.0[idx[i]]))
+    }
+}
+
+impl<T: Default, const N: usize> Default for Vector<T, N> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self(array)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.0
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0[i]
+    }
+}
+
+impl<T: Copy, const N: usize> Components<N> for Vector<T, N> {
+    type Component = T;
+
+    #[inline]
+    fn get(&self, i: usize) -> T {
+        self.0[i]
+    }
+
+    #[inline]
+    fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self::from_fn(f)
+    }
+}
+
+impl<T: Copy, const N: usize> DynSwizzle for Vector<T, N> {
+    #[inline]
+    fn swizzle_arity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn try_swizzle_in_place(&mut self, idx: &[usize]) -> Result<(), SwizzleError> {
+        crate::algo::try_swizzle_in_place(self, idx)
+    }
+}
+
+// SAFETY: the vector is `#[repr(transparent)]` over an array of `T`, so it is zeroed or has any bit
+// pattern exactly when the array does.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const N: usize> bytemuck::Zeroable for Vector<T, N> {}
+
+// SAFETY: as above.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod, const N: usize> bytemuck::Pod for Vector<T, N> {}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for Vector<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for value in &self.0 {
+            tuple.serialize_element(value)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VectorVisitor<T, const N: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de>
+            for VectorVisitor<T, N>
+        {
+            type Value = Vector<T, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "an array of length {N}")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Vector<T, N>, A::Error> {
+                let mut values: [Option<T>; N] = core::array::from_fn(|_| None);
+                for (i, value) in values.iter_mut().enumerate() {
+                    *value = Some(
+                        seq.next_element()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?,
+                    );
+                }
+                Ok(Vector(
+                    values.map(|value| value.expect("every value is set")),
+                ))
+            }
+        }
+
+        deserializer.deserialize_tuple(N, VectorVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Generates the named swizzles of a vector, for every combination of 1 to 4 of the given axes.
+macro_rules! vector_swizzles {
+    ($n:literal: $axes:tt) => {
+        impl<T: Copy> Vector<T, $n> {
+            vector_swizzles!(@extend $axes $axes; []);
+        }
+    };
+    (@extend [ $( ($a:ident $ai:literal) )+ ] $axes:tt; $prefix:tt) => {
+        $( vector_swizzles!(@step $axes; $prefix ($a $ai)); )+
+    };
+    (@step $axes:tt; [ $( ($p:ident $pi:literal) )* ] ($a:ident $ai:literal)) => {
+        vector_swizzles!(@emit $( ($p $pi) )* ($a $ai));
+        vector_swizzles!(@more $axes; [ $( ($p $pi) )* ($a $ai) ]);
+    };
+    (@more $axes:tt; [ $a:tt $b:tt $c:tt $d:tt ]) => {};
+    (@more $axes:tt; $prefix:tt) => {
+        vector_swizzles!(@extend $axes $axes; $prefix);
+    };
+    (@emit ($a:ident $ai:literal)) => {
+        #[doc = concat!("Returns the `", stringify!($a), "` value.")]
+        #[must_use]
+        #[inline]
+        pub const fn $a(&self) -> T {
+            self.0[$ai]
+        }
+    };
+    (@emit $( ($a:ident $ai:literal) )+) => {
+        vector_swizzles!(@emit_len [ $( $ai )+ ] $( ($a $ai) )+);
+    };
+    (@emit_len [ $a:tt $b:tt ] $( $axis:tt )+) => {
+        vector_swizzles!(@emit_fn 2; $( $axis )+);
+    };
+    (@emit_len [ $a:tt $b:tt $c:tt ] $( $axis:tt )+) => {
+        vector_swizzles!(@emit_fn 3; $( $axis )+);
+    };
+    (@emit_len [ $a:tt $b:tt $c:tt $d:tt ] $( $axis:tt )+) => {
+        vector_swizzles!(@emit_fn 4; $( $axis )+);
+    };
+    (@emit_fn $m:literal; $( ($a:ident $ai:literal) )+) => {
+        $crate::__private::paste! {
+            #[doc = concat!("Returns the vector `(", stringify!($( $a ),+), ")`.")]
+            #[must_use]
+            #[inline]
+            pub const fn [<$( $a )+>](&self) -> Vector<T, $m> {
+                Vector([ $( self.0[$ai] ),+ ])
+            }
+        }
+    };
+}
+
+vector_swizzles!(1: [(x 0)]);
+vector_swizzles!(2: [(x 0) (y 1)]);
+vector_swizzles!(3: [(x 0) (y 1) (z 2)]);
+vector_swizzles!(4: [(x 0) (y 1) (z 2) (w 3)]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Permutation;
+
+    #[test]
+    fn test_named_swizzles() {
+        let v = Vector::new([1, 2, 3, 4]);
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (1, 2, 3, 4));
+        assert_eq!(v.wzyx(), Vector::new([4, 3, 2, 1]));
+        assert_eq!(v.xz(), Vector::new([1, 3]));
+        assert_eq!(v.www(), Vector::new([4, 4, 4]));
+
+        let v = Vector::new([1, 2]);
+        assert_eq!(v.yxyx(), Vector::new([2, 1, 2, 1]));
+        assert_eq!(Vector::new([7]).xxx(), Vector::new([7, 7, 7]));
+
+        const V: Vector<u8, 3> = Vector::new([1, 2, 3]).zyx();
+        assert_eq!(V, Vector::new([3, 2, 1]));
+    }
+
+    #[test]
+    fn test_swizzle() {
+        let v: Vector<usize, 9> = Vector::from_fn(|i| i * 10);
+        assert_eq!(v.swizzle([7, 0]), Vector::new([70, 0]));
+        assert_eq!(v.try_swizzle([8, 0]), Ok(Vector::new([80, 0])));
+        assert_eq!(
+            v.try_swizzle([1, 10]),
+            Err(SwizzleError::IndexOutOfRange { index: 10, len: 9 })
+        );
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_swizzle_out_of_range() {
+        let _ = Vector::new([1, 2, 3]).swizzle([3]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn test_bytemuck() {
+        let v = Vector::new([1.0f32, 2.0, 3.0]);
+        assert_eq!(bytemuck::cast_ref::<_, [f32; 3]>(&v), &[1.0, 2.0, 3.0]);
+        let values = [Vector::new([1u8, 2]), Vector::new([3, 4])];
+        assert_eq!(bytemuck::cast_slice::<_, u8>(&values), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "defmt")]
+    fn test_defmt() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<Vector<f32, 3>>();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+        assert_tokens(
+            &Vector::new([1u8, 2, 3]),
+            &[
+                Token::Tuple { len: 3 },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<Vector<u8, 3>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::U8(1),
+                Token::U8(2),
+                Token::TupleEnd,
+            ],
+            "invalid length 2, expected an array of length 3",
+        );
+    }
+
+    #[test]
+    fn test_traits() {
+        let mut v = Vector::new([1, 2, 3, 4, 5]);
+        assert_eq!(
+            Permutation::new([4, 3, 2, 1, 0]).unwrap().apply(&v),
+            Vector::new([5, 4, 3, 2, 1])
+        );
+
+        v[0] = 10;
+        assert_eq!(Components::get(&v, 0), 10);
+        assert_eq!(<[i32; 5]>::from(v), [10, 2, 3, 4, 5]);
+        assert_eq!(Vector::<u8, 2>::default(), Vector::new([0, 0]));
+
+        let swizzle: &mut dyn DynSwizzle = &mut v;
+        assert_eq!(swizzle.swizzle_arity(), 5);
+        assert_eq!(
+            swizzle.try_swizzle_in_place(&[0, 0]),
+            Err(SwizzleError::WrongArity {
+                expected: 5,
+                found: 2
+            })
+        );
+        assert_eq!(swizzle.try_swizzle_in_place(&[1, 1, 1, 1, 1]), Ok(()));
+        assert_eq!(v, Vector::new([2; 5]));
+    }
+}
@@ -0,0 +1,241 @@
+use crate::prebuilt::{prebuilt, prefix_view};
+use crate::swizzle;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+prebuilt! {
+    /// A vector of 2 components, with every swizzle to `Vec2`, `Vec3`, and `Vec4`.
+    ///
+    /// ```rust
+    /// use swizzle::{Vec2, Vec4};
+    ///
+    /// let v = Vec2::new(1, 2);
+    /// assert_eq!(v.yx(), Vec2::new(2, 1));
+    /// assert_eq!(v.xyxy(), Vec4::new(1, 2, 1, 2));
+    /// ```
+    Vec2(2) { x: 0, y: 1 }
+}
+
+prebuilt! {
+    /// A vector of 3 components, with every swizzle to `Vec2`, `Vec3`, and `Vec4`.
+    ///
+    /// ```rust
+    /// use swizzle::{Vec2, Vec3};
+    ///
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.zyx(), Vec3::new(3.0, 2.0, 1.0));
+    /// assert_eq!(v.xz(), Vec2::new(1.0, 3.0));
+    /// ```
+    Vec3(3) { x: 0, y: 1, z: 2 }
+}
+
+prebuilt! {
+    /// A vector of 4 components, with every swizzle to `Vec2`, `Vec3`, and `Vec4`.
+    ///
+    /// ```rust
+    /// use swizzle::{Vec3, Vec4};
+    ///
+    /// let v = Vec4::new(1, 2, 3, 4);
+    /// assert_eq!(v.wzyx(), Vec4::new(4, 3, 2, 1));
+    /// assert_eq!(v.xyz(), Vec3::new(1, 2, 3));
+    /// ```
+    Vec4(4) { x: 0, y: 1, z: 2, w: 3 }
+}
+
+impl<T: Copy> Vec2<T> {
+    swizzle!(table Vec2<T> { x, y });
+    swizzle!(Vec3<T> { x: (x, y), y: (x, y), z: (x, y) });
+    swizzle!(Vec4<T> { x: (x, y), y: (x, y), z: (x, y), w: (x, y) });
+}
+
+impl<T: Copy> Vec3<T> {
+    swizzle!(Vec2<T> { x: (x, y, z), y: (x, y, z) });
+    swizzle!(table Vec3<T> { x, y, z });
+    swizzle!(Vec4<T> { x: (x, y, z), y: (x, y, z), z: (x, y, z), w: (x, y, z) });
+}
+
+impl<T: Copy> Vec4<T> {
+    swizzle!(Vec2<T> { x: (x, y, z, w), y: (x, y, z, w) });
+    swizzle!(Vec3<T> { x: (x, y, z, w), y: (x, y, z, w), z: (x, y, z, w) });
+    swizzle!(table Vec4<T> { x, y, z, w });
+}
+
+prefix_view!(Vec3 => Vec2 { x, y });
+prefix_view!(Vec4 => Vec2 { x, y });
+prefix_view!(Vec4 => Vec3 { x, y, z });
+
+/// Implements the component-wise arithmetic operators of a vector, between vectors and with a
+/// scalar.
+macro_rules! vector_ops {
+    ($name:ident { $( $attr:ident ),+ }) => {
+        vector_ops!(@binary $name { $( $attr ),+ }; Add add AddAssign add_assign);
+        vector_ops!(@binary $name { $( $attr ),+ }; Sub sub SubAssign sub_assign);
+        vector_ops!(@binary $name { $( $attr ),+ }; Mul mul MulAssign mul_assign);
+        vector_ops!(@binary $name { $( $attr ),+ }; Div div DivAssign div_assign);
+        vector_ops!(@scalar $name { $( $attr ),+ }; Mul mul MulAssign mul_assign);
+        vector_ops!(@scalar $name { $( $attr ),+ }; Div div DivAssign div_assign);
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $( $attr: -self.$attr ),+ }
+            }
+        }
+    };
+    (@binary $name:ident { $( $attr:ident ),+ }; $op:ident $method:ident $op_assign:ident $method_assign:ident) => {
+        impl<T: $op<Output = T>> $op for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self { $( $attr: self.$attr.$method(rhs.$attr) ),+ }
+            }
+        }
+
+        impl<T: $op_assign> $op_assign for $name<T> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                $( self.$attr.$method_assign(rhs.$attr); )+
+            }
+        }
+    };
+    (@scalar $name:ident { $( $attr:ident ),+ }; $op:ident $method:ident $op_assign:ident $method_assign:ident) => {
+        impl<T: $op<Output = T> + Copy> $op<T> for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: T) -> Self {
+                Self { $( $attr: self.$attr.$method(rhs) ),+ }
+            }
+        }
+
+        impl<T: $op_assign + Copy> $op_assign<T> for $name<T> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: T) {
+                $( self.$attr.$method_assign(rhs); )+
+            }
+        }
+    };
+}
+
+vector_ops!(Vec2 { x, y });
+vector_ops!(Vec3 { x, y, z });
+vector_ops!(Vec4 { x, y, z, w });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Components, Permutation, algo};
+
+    #[test]
+    fn test_swizzles() {
+        let v = Vec4::new(1, 2, 3, 4);
+        assert_eq!(v.wzyx().xyz().yx().xxyy(), Vec4::new(3, 3, 4, 4));
+        assert_eq!(Vec3::splat(7).xy(), Vec2::new(7, 7));
+        assert_eq!(Vec2::new(1, 2).yyx(), Vec3::new(2, 2, 1));
+
+        const V: Vec3<u8> = Vec3::new(1, 2, 3).zxy();
+        assert_eq!(V, Vec3::new(3, 1, 2));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(4.0, 5.0, 6.0);
+        assert_eq!(a + b, Vec3::new(5.0, 7.0, 9.0));
+        assert_eq!(b - a, Vec3::splat(3.0));
+        assert_eq!(a * b, Vec3::new(4.0, 10.0, 18.0));
+        assert_eq!(b / a, Vec3::new(4.0, 2.5, 2.0));
+        assert_eq!(a * 2.0, Vec3::new(2.0, 4.0, 6.0));
+        assert_eq!(b / 2.0, Vec3::new(2.0, 2.5, 3.0));
+        assert_eq!(-a, Vec3::new(-1.0, -2.0, -3.0));
+        assert_eq!((a + b.zyx()).xy(), Vec2::splat(7.0));
+
+        let mut v = Vec4::splat(1);
+        v += Vec4::new(1, 2, 3, 4);
+        v -= Vec4::splat(1);
+        v *= 3;
+        v /= Vec4::splat(3);
+        assert_eq!(v, Vec4::new(1, 2, 3, 4));
+
+        let mut v = Vec2::new(4, 8);
+        v /= 2;
+        v *= Vec2::new(2, 1);
+        assert_eq!(v, Vec2::new(4, 4));
+    }
+
+    #[test]
+    fn test_function_table() {
+        assert_eq!(Vec2::<u8>::SWIZZLE_COUNT, 4);
+        assert_eq!(Vec3::<u8>::SWIZZLE_COUNT, 27);
+        assert_eq!(Vec4::<u8>::SWIZZLE_COUNT, 256);
+    }
+
+    #[test]
+    fn test_components() {
+        let v = Vec3::new(1, 2, 3);
+        assert_eq!(v.to_array(), [1, 2, 3]);
+        assert_eq!(Vec3::from([1, 2, 3]), v);
+        assert_eq!(<[i32; 4]>::from(Vec4::new(1, 2, 3, 4)), [1, 2, 3, 4]);
+        assert_eq!(algo::reverse(&v), v.zyx());
+        assert_eq!(Permutation::new([1, 2, 0]).unwrap().apply(&v), v.yzx());
+        assert_eq!(Vec2::<f32>::default(), Vec2::splat(0.0));
+    }
+
+    #[test]
+    fn test_prefix_views() {
+        let mut v = Vec4::new(1, 2, 3, 4);
+        assert_eq!(v.xyz_view(), &Vec3::new(1, 2, 3));
+        assert_eq!(v.xy_view(), &Vec2::new(1, 2));
+        v.xyz_view_mut().z = 10;
+        assert_eq!(v, Vec4::new(1, 2, 10, 4));
+        assert_eq!(Vec3::new(1, 2, 3).xy_view().yx(), Vec2::new(2, 1));
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn test_bytemuck() {
+        let v = Vec4::new(1.0f32, 2.0, 3.0, 4.0);
+        assert_eq!(bytemuck::cast_ref::<_, [f32; 4]>(&v), &[1.0, 2.0, 3.0, 4.0]);
+        let vertices = [Vec3::new(1u8, 2, 3), Vec3::new(4, 5, 6)];
+        assert_eq!(bytemuck::cast_slice::<_, u8>(&vertices), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[cfg(feature = "defmt")]
+    fn test_defmt() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<Vec2<f32>>();
+        assert_format::<Vec3<u8>>();
+        assert_format::<Vec4<i32>>();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &Vec2::new(1u8, 2),
+            &[
+                Token::Struct {
+                    name: "Vec2",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::U8(1),
+                Token::Str("y"),
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_components_out_of_range() {
+        let _ = Vec2::new(1, 2).get(2);
+    }
+}
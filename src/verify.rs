@@ -0,0 +1,117 @@
+//! [Kani](https://github.com/model-checking/kani) proof harnesses checking, for every input of a
+//! few small arities, that the different ways of computing a swizzle agree: the named functions,
+//! `SWIZZLE_FNS`, the patterns, `swizzle_dyn`, and the packed-integer reorders. They are only
+//! compiled with the `verify` cfg under Kani:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg verify" cargo kani --lib
+//! ```
+
+use crate::packed::{self, from_bytes, swizzle_u32, to_bytes};
+use crate::swizzle;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vec2 {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vec3 {
+    x: u8,
+    y: u8,
+    z: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vec4 {
+    x: u8,
+    y: u8,
+    z: u8,
+    w: u8,
+}
+
+impl Vec2 {
+    swizzle!(table Vec2 { x, y });
+}
+
+impl Vec3 {
+    swizzle!(table Vec3 { x, y, z });
+}
+
+impl Vec4 {
+    swizzle!(table Vec4 { x, y, z, w });
+}
+
+crate::swizzle_dyn!(Vec2: u8 { x, y });
+crate::swizzle_dyn!(Vec3: u8 { x, y, z });
+crate::swizzle_dyn!(Vec4: u8 { x, y, z, w });
+
+/// Generates a proof that, for every value and every pattern, the function in `SWIZZLE_FNS`, the
+/// pattern with the same rank, and `swizzle_dyn` by the pattern's indices all agree.
+macro_rules! prove_dynamic_matches_named {
+    ($( $proof:ident: $type:ident, $pattern:ident; )+) => {
+        $(
+            #[kani::proof]
+            fn $proof() {
+                let value = $type::from_array(kani::any());
+                let rank: usize = kani::any();
+                kani::assume(rank < $pattern::COUNT.unwrap());
+
+                let (_, swizzle) = $type::SWIZZLE_FNS[rank];
+                let pattern = $pattern::unrank(rank).unwrap();
+                let idx = pattern.axes().map(|axis| axis.index());
+                assert!(pattern.rank() == Some(rank));
+                assert!(swizzle(&value) == value.swizzle_dyn(idx));
+                assert!(pattern.apply(&value) == value.swizzle_dyn(idx));
+            }
+        )+
+    };
+}
+
+prove_dynamic_matches_named! {
+    dynamic_matches_named_2: Vec2, Vec2Pattern;
+    dynamic_matches_named_3: Vec3, Vec3Pattern;
+    dynamic_matches_named_4: Vec4, Vec4Pattern;
+}
+
+/// `swizzle_u32` on a packed pixel is `swizzle_dyn` on its bytes in memory order.
+#[kani::proof]
+fn packed_matches_dynamic() {
+    let value = Vec4::from_array(kani::any());
+    let idx: [usize; 4] = kani::any();
+    kani::assume(idx.iter().all(|&i| i < 4));
+
+    let pixel = from_bytes(value.to_array());
+    assert!(to_bytes(swizzle_u32(pixel, idx)) == value.swizzle_dyn(idx).to_array());
+}
+
+/// Generates a proof that each named packed conversion is the named swizzle of the same fields,
+/// taking `xyzw` as the source order.
+macro_rules! prove_packed_matches_named {
+    ($( $proof:ident: $convert:ident => $swizzle:ident; )+) => {
+        $(
+            #[kani::proof]
+            fn $proof() {
+                let value = Vec4::from_array(kani::any());
+                let pixel = from_bytes(value.to_array());
+                assert!(to_bytes(packed::$convert(pixel)) == value.$swizzle().to_array());
+            }
+        )+
+    };
+}
+
+prove_packed_matches_named! {
+    rgba8_to_bgra8_matches_named: rgba8_to_bgra8 => zyxw;
+    rgba8_to_argb8_matches_named: rgba8_to_argb8 => wxyz;
+    rgba8_to_abgr8_matches_named: rgba8_to_abgr8 => wzyx;
+    bgra8_to_rgba8_matches_named: bgra8_to_rgba8 => zyxw;
+    bgra8_to_argb8_matches_named: bgra8_to_argb8 => wzyx;
+    bgra8_to_abgr8_matches_named: bgra8_to_abgr8 => wxyz;
+    argb8_to_rgba8_matches_named: argb8_to_rgba8 => yzwx;
+    argb8_to_bgra8_matches_named: argb8_to_bgra8 => wzyx;
+    argb8_to_abgr8_matches_named: argb8_to_abgr8 => xwzy;
+    abgr8_to_rgba8_matches_named: abgr8_to_rgba8 => wzyx;
+    abgr8_to_bgra8_matches_named: abgr8_to_bgra8 => yzwx;
+    abgr8_to_argb8_matches_named: abgr8_to_argb8 => xwzy;
+}
@@ -0,0 +1,225 @@
+//! Conversions between swizzles of 4 fields and Vulkan component mappings.
+//!
+//! A swizzle is given as a [`SwizzlePlan<4>`], which a [`Permutation<4>`](crate::Permutation) or
+//! a pattern type of 4 fields generated by `swizzle_dyn!` converts into, and the mapping is a
+//! [`ComponentMapping`] of the raw `VkComponentSwizzle` values of the `r`, `g`, `b`, and `a`
+//! members of a `VkComponentMapping`, so it works with any Vulkan binding. With the `ash` feature
+//! enabled, the plan and the mapping also convert to and from `ash::vk::ComponentMapping`.
+//!
+//! ```rust
+//! use swizzle::{Permutation, SwizzlePlan};
+//! use swizzle::vulkan::{ComponentMapping, from_component_mapping, to_component_mapping};
+//!
+//! // Sample a BGRA texture as RGBA.
+//! let bgra = Permutation::new([2, 1, 0, 3]).unwrap();
+//! let mapping = to_component_mapping(bgra.into());
+//! assert_eq!(mapping, ComponentMapping { r: 5, g: 4, b: 3, a: 6 }); // B, G, R, A
+//! assert_eq!(from_component_mapping(mapping), Ok(SwizzlePlan::from(bgra)));
+//! ```
+
+use crate::{Permutation, SwizzleError, SwizzlePlan};
+
+/// `VK_COMPONENT_SWIZZLE_IDENTITY`, taking each component from the same component.
+const IDENTITY: i32 = 0;
+/// `VK_COMPONENT_SWIZZLE_R`, followed by `G`, `B`, and `A`.
+const R: i32 = 3;
+
+/// A `VkComponentMapping`, as the raw `VkComponentSwizzle` value of each of its members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ComponentMapping {
+    /// The `VkComponentSwizzle` that the red component is taken from.
+    pub r: i32,
+    /// The `VkComponentSwizzle` that the green component is taken from.
+    pub g: i32,
+    /// The `VkComponentSwizzle` that the blue component is taken from.
+    pub b: i32,
+    /// The `VkComponentSwizzle` that the alpha component is taken from.
+    pub a: i32,
+}
+
+impl ComponentMapping {
+    /// Create a mapping from the raw values of its `r`, `g`, `b`, and `a` members.
+    #[must_use]
+    #[inline]
+    pub const fn from_raw(raw: [i32; 4]) -> Self {
+        let [r, g, b, a] = raw;
+        Self { r, g, b, a }
+    }
+
+    /// The raw values of the `r`, `g`, `b`, and `a` members.
+    #[must_use]
+    #[inline]
+    pub const fn to_raw(self) -> [i32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+/// Returns the component mapping taking the component at each position from the component at the
+/// index of the plan.
+#[must_use]
+pub const fn to_component_mapping(plan: SwizzlePlan<4>) -> ComponentMapping {
+    let idx = plan.indices();
+    let mut raw = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        raw[i] = R + idx[i] as i32;
+        i += 1;
+    }
+    ComponentMapping::from_raw(raw)
+}
+
+/// Returns the plan taking the component at each position from the component that the component
+/// mapping takes it from. `VK_COMPONENT_SWIZZLE_IDENTITY` is the index of its own position.
+///
+/// # Errors
+///
+/// Returns [`SwizzleError::UnsupportedComponent`] if any component is `VK_COMPONENT_SWIZZLE_ZERO`
+/// or `VK_COMPONENT_SWIZZLE_ONE`, as they aren't taken from a component, or isn't a valid value.
+pub const fn from_component_mapping(
+    mapping: ComponentMapping,
+) -> Result<SwizzlePlan<4>, SwizzleError> {
+    let raw = mapping.to_raw();
+    let mut idx = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        idx[i] = match raw[i] {
+            IDENTITY => i,
+            value if value >= R && value < R + 4 => (value - R) as usize,
+            _ => return Err(SwizzleError::UnsupportedComponent),
+        };
+        i += 1;
+    }
+    SwizzlePlan::new(idx)
+}
+
+impl From<SwizzlePlan<4>> for ComponentMapping {
+    fn from(plan: SwizzlePlan<4>) -> Self {
+        to_component_mapping(plan)
+    }
+}
+
+impl From<Permutation<4>> for ComponentMapping {
+    fn from(permutation: Permutation<4>) -> Self {
+        to_component_mapping(permutation.into())
+    }
+}
+
+impl TryFrom<ComponentMapping> for SwizzlePlan<4> {
+    type Error = SwizzleError;
+
+    fn try_from(mapping: ComponentMapping) -> Result<Self, Self::Error> {
+        from_component_mapping(mapping)
+    }
+}
+
+#[cfg(feature = "ash")]
+impl From<ComponentMapping> for ash::vk::ComponentMapping {
+    fn from(mapping: ComponentMapping) -> Self {
+        Self {
+            r: ash::vk::ComponentSwizzle::from_raw(mapping.r),
+            g: ash::vk::ComponentSwizzle::from_raw(mapping.g),
+            b: ash::vk::ComponentSwizzle::from_raw(mapping.b),
+            a: ash::vk::ComponentSwizzle::from_raw(mapping.a),
+        }
+    }
+}
+
+#[cfg(feature = "ash")]
+impl From<ash::vk::ComponentMapping> for ComponentMapping {
+    fn from(mapping: ash::vk::ComponentMapping) -> Self {
+        Self {
+            r: mapping.r.as_raw(),
+            g: mapping.g.as_raw(),
+            b: mapping.b.as_raw(),
+            a: mapping.a.as_raw(),
+        }
+    }
+}
+
+#[cfg(feature = "ash")]
+impl From<SwizzlePlan<4>> for ash::vk::ComponentMapping {
+    fn from(plan: SwizzlePlan<4>) -> Self {
+        to_component_mapping(plan).into()
+    }
+}
+
+#[cfg(feature = "ash")]
+impl TryFrom<ash::vk::ComponentMapping> for SwizzlePlan<4> {
+    type Error = SwizzleError;
+
+    fn try_from(mapping: ash::vk::ComponentMapping) -> Result<Self, Self::Error> {
+        from_component_mapping(mapping.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(idx: [usize; 4]) -> SwizzlePlan<4> {
+        SwizzlePlan::new(idx).unwrap()
+    }
+
+    #[test]
+    fn test_component_mapping() {
+        assert_eq!(
+            to_component_mapping(plan([0, 1, 2, 3])),
+            ComponentMapping::from_raw([3, 4, 5, 6])
+        );
+        assert_eq!(
+            to_component_mapping(plan([0, 0, 0, 3])).to_raw(),
+            [3, 3, 3, 6]
+        );
+        assert_eq!(
+            from_component_mapping(ComponentMapping::default()),
+            Ok(plan([0, 1, 2, 3]))
+        );
+        assert_eq!(
+            from_component_mapping(ComponentMapping::from_raw([6, 0, 3, 4])),
+            Ok(plan([3, 1, 0, 1]))
+        );
+
+        for permutation in ["xyzw", "wzyx", "yzwx"] {
+            let permutation = permutation.parse::<Permutation<4>>().unwrap();
+            let mapping = ComponentMapping::from(permutation);
+            assert_eq!(SwizzlePlan::try_from(mapping), Ok(permutation.into()));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_component() {
+        // ZERO, ONE, and values past A.
+        for value in [1, 2, 7, -1] {
+            assert_eq!(
+                from_component_mapping(ComponentMapping::from_raw([3, 4, 5, value])),
+                Err(SwizzleError::UnsupportedComponent)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ash")]
+    fn test_ash_component_mapping() {
+        use ash::vk::{ComponentMapping as VkComponentMapping, ComponentSwizzle};
+
+        // `ComponentSwizzle` is only `Debug` with the `debug` feature of `ash`.
+        let mapping: VkComponentMapping = plan([2, 1, 0, 3]).into();
+        assert!(mapping.r == ComponentSwizzle::B);
+        assert!(mapping.g == ComponentSwizzle::G);
+        assert!(mapping.b == ComponentSwizzle::R);
+        assert!(mapping.a == ComponentSwizzle::A);
+        assert_eq!(SwizzlePlan::try_from(mapping), Ok(plan([2, 1, 0, 3])));
+
+        let identity = VkComponentMapping::default();
+        assert_eq!(SwizzlePlan::try_from(identity), Ok(plan([0, 1, 2, 3])));
+
+        let constant = VkComponentMapping {
+            a: ComponentSwizzle::ONE,
+            ..identity
+        };
+        assert_eq!(
+            SwizzlePlan::try_from(constant),
+            Err(SwizzleError::UnsupportedComponent)
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Named swizzles of the lanes of `wide` SIMD vectors.
+
+use crate::arch::Shuffle4;
+
+/// Generates the named swizzles of the trait, a getter for each lane and a shuffle for every
+/// combination of 4 of the lanes.
+macro_rules! wide_swizzles {
+    ([ $( ($a:ident $ai:literal) )+ ]) => {
+        $(
+            #[doc = concat!("Returns the `", stringify!($a), "` lane.")]
+            #[must_use]
+            #[inline]
+            fn $a(self) -> Self::Lane {
+                self.to_lanes()[$ai]
+            }
+        )+
+        wide_swizzles!(@extend [ $( ($a $ai) )+ ] [ $( ($a $ai) )+ ]; []);
+    };
+    (@extend [ $( $axis:tt )+ ] $axes:tt; $prefix:tt) => {
+        $( wide_swizzles!(@step $axes; $prefix $axis); )+
+    };
+    (@step $axes:tt; [ $( ($p:ident $pi:literal) )* ] ($a:ident $ai:literal)) => {
+        wide_swizzles!(@more $axes; [ $( ($p $pi) )* ($a $ai) ]);
+    };
+    (@more $axes:tt; [ ($a:ident $ai:literal) ($b:ident $bi:literal) ($c:ident $ci:literal) ($d:ident $di:literal) ]) => {
+        $crate::__private::paste! {
+            #[doc = concat!(
+                "Returns the vector `(", stringify!($a, $b, $c, $d), ")`."
+            )]
+            #[must_use]
+            #[inline]
+            fn [<$a $b $c $d>](self) -> Self {
+                self.swizzle_lanes([$ai, $bi, $ci, $di])
+            }
+        }
+    };
+    (@more $axes:tt; $prefix:tt) => {
+        wide_swizzles!(@extend $axes $axes; $prefix);
+    };
+}
+
+/// Named swizzles of the lanes of a 4-lane [`wide`](https://crates.io/crates/wide) vector, e.g.
+/// `v.wzyx()` or `v.xxyy()`, naming the lanes `x`, `y`, `z`, and `w`. The lanes are reordered with
+/// [`Shuffle4`], so with a single shuffle instruction on x86.
+///
+/// Implemented for `f32x4`, `i32x4`, and `u32x4`.
+///
+/// ```rust
+/// use swizzle::WideSwizzle;
+/// use wide::f32x4;
+///
+/// let v = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(v.wzyx().to_array(), [4.0, 3.0, 2.0, 1.0]);
+/// assert_eq!(v.y(), 2.0);
+/// assert_eq!(v.swizzle_lanes([0, 0, 3, 3]).to_array(), [1.0, 1.0, 4.0, 4.0]);
+/// ```
+pub trait WideSwizzle: Copy {
+    /// The type of the lanes.
+    type Lane: Shuffle4;
+
+    /// Returns the lanes as an array.
+    fn to_lanes(self) -> [Self::Lane; 4];
+
+    /// Creates a vector from an array of lanes.
+    fn from_lanes(lanes: [Self::Lane; 4]) -> Self;
+
+    /// Returns the vector with the lane at each position taken from the lane at the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of range.
+    #[must_use]
+    #[inline]
+    fn swizzle_lanes(self, idx: [usize; 4]) -> Self {
+        Self::from_lanes(Shuffle4::shuffle4(self.to_lanes(), idx))
+    }
+
+    wide_swizzles!([(x 0) (y 1) (z 2) (w 3)]);
+}
+
+macro_rules! impl_wide_swizzle {
+    ($( $vector:ident: $lane:ty ),+) => {
+        $(
+            impl WideSwizzle for ::wide::$vector {
+                type Lane = $lane;
+
+                #[inline]
+                fn to_lanes(self) -> [$lane; 4] {
+                    self.to_array()
+                }
+
+                #[inline]
+                fn from_lanes(lanes: [$lane; 4]) -> Self {
+                    Self::new(lanes)
+                }
+            }
+        )+
+    };
+}
+
+impl_wide_swizzle!(f32x4: f32, i32x4: i32, u32x4: u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::wide::{f32x4, i32x4, u32x4};
+
+    #[test]
+    fn test_named_swizzles() {
+        let v = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v.xyzw().to_array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.wzyx().to_array(), [4.0, 3.0, 2.0, 1.0]);
+
+        let v = i32x4::new([1, -2, 3, -4]);
+        assert_eq!(v.yyww().to_array(), [-2, -2, -4, -4]);
+
+        let v = u32x4::new([1, 2, 3, 4]);
+        assert_eq!(v.zxwy().to_array(), [3, 1, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic = "swizzle index out of range"]
+    fn test_swizzle_lanes_out_of_range() {
+        let _ = u32x4::new([1, 2, 3, 4]).swizzle_lanes([0, 1, 2, 4]);
+    }
+}
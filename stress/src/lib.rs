@@ -0,0 +1,69 @@
+//! Swizzle sets of increasing size, each behind its own feature, for measuring how long the
+//! `swizzle!` macro takes to expand. The `swizzle-stress` binary builds each of them in turn and
+//! records the wall-clock time taken.
+
+#[cfg(feature = "fields-3")]
+pub mod fields_3 {
+    use swizzle::swizzle;
+
+    pub struct Vec3 {
+        pub a: u8,
+        pub b: u8,
+        pub c: u8,
+    }
+
+    impl Vec3 {
+        swizzle!(Vec3 { a, b, c });
+    }
+}
+
+#[cfg(feature = "fields-4")]
+pub mod fields_4 {
+    use swizzle::swizzle;
+
+    pub struct Vec4 {
+        pub a: u8,
+        pub b: u8,
+        pub c: u8,
+        pub d: u8,
+    }
+
+    impl Vec4 {
+        swizzle!(Vec4 { a, b, c, d });
+    }
+}
+
+#[cfg(feature = "fields-5")]
+pub mod fields_5 {
+    use swizzle::swizzle;
+
+    pub struct Vec5 {
+        pub a: u8,
+        pub b: u8,
+        pub c: u8,
+        pub d: u8,
+        pub e: u8,
+    }
+
+    impl Vec5 {
+        swizzle!(Vec5 { a, b, c, d, e });
+    }
+}
+
+#[cfg(feature = "fields-6")]
+pub mod fields_6 {
+    use swizzle::swizzle;
+
+    pub struct Vec6 {
+        pub a: u8,
+        pub b: u8,
+        pub c: u8,
+        pub d: u8,
+        pub e: u8,
+        pub f: u8,
+    }
+
+    impl Vec6 {
+        swizzle!(unbounded Vec6 { a, b, c, d, e, f });
+    }
+}
@@ -0,0 +1,98 @@
+//! Compile-time stress harness for the `swizzle!` macro.
+//!
+//! Builds the library of this crate once for each swizzle set feature, and records the wall-clock
+//! time each build takes. The builds use their own target directory, `target/stress`, and the
+//! library is cleaned before each build so that it is always compiled from scratch.
+//!
+//! ```text
+//! cargo run -p swizzle-stress --release [-- fields-3 fields-4 ...]
+//! ```
+
+use std::env;
+use std::path::Path;
+use std::process::{Command, ExitCode};
+use std::time::Instant;
+
+/// The swizzle set features, with the number of functions each generates.
+const SETS: &[(&str, u64)] = &[
+    ("fields-3", 27),
+    ("fields-4", 256),
+    ("fields-5", 3125),
+    ("fields-6", 46656),
+];
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let sets: Vec<(&str, u64)> = if args.is_empty() {
+        SETS.to_vec()
+    } else {
+        let mut sets = Vec::new();
+        for arg in &args {
+            match SETS.iter().find(|(name, _)| name == arg) {
+                Some(set) => sets.push(*set),
+                None => {
+                    eprintln!("unknown swizzle set `{arg}`");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        sets
+    };
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("stress");
+
+    // Build the dependencies first, so they aren't included in the time of the first set.
+    let warm_up = Command::new(&cargo)
+        .args(["check", "--quiet", "--lib"])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status();
+    if !matches!(warm_up, Ok(status) if status.success()) {
+        eprintln!("failed to build the dependencies");
+        return ExitCode::FAILURE;
+    }
+
+    println!("| set      | functions | seconds |");
+    println!("|----------|-----------|---------|");
+    for (name, functions) in sets {
+        let clean = Command::new(&cargo)
+            .args(["clean", "--quiet", "--package", env!("CARGO_PKG_NAME")])
+            .arg("--manifest-path")
+            .arg(&manifest)
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status();
+        if !matches!(clean, Ok(status) if status.success()) {
+            eprintln!("failed to clean before building `{name}`");
+            return ExitCode::FAILURE;
+        }
+
+        let start = Instant::now();
+        let build = Command::new(&cargo)
+            .args(["check", "--quiet", "--lib", "--features", name])
+            .arg("--manifest-path")
+            .arg(&manifest)
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status();
+        let elapsed = start.elapsed();
+        if !matches!(build, Ok(status) if status.success()) {
+            eprintln!("failed to build `{name}`");
+            return ExitCode::FAILURE;
+        }
+
+        println!(
+            "| {name} | {functions:>9} | {:>7.2} |",
+            elapsed.as_secs_f64()
+        );
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,55 @@
+//! Regression check on the expansion cost of the `swizzle!` macro.
+//!
+//! Every step of the generation recursion is a nested macro expansion, so the recursion limit
+//! bounds how much work the macro may do per generated function. The recursion takes two steps per
+//! destination attribute plus a small fixed overhead, so a 5 field self-swizzle fits within a
+//! limit of 16. If this fails to compile, the expansion has become more expensive.
+#![recursion_limit = "16"]
+
+use swizzle::swizzle;
+
+#[derive(Debug, PartialEq)]
+struct Vec5 {
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+}
+
+#[derive(Debug, PartialEq)]
+struct Vec2 {
+    x: u8,
+    y: u8,
+}
+
+impl Vec5 {
+    swizzle!(Vec5 { a, b, c, d, e });
+    swizzle!(Vec2 {
+        x: (a, b, c, d, e),
+        y: (a, b, c, d, e)
+    });
+}
+
+#[test]
+fn test_expansion_within_recursion_limit() {
+    let v = Vec5 {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: 5,
+    };
+
+    assert_eq!(
+        v.edcba(),
+        Vec5 {
+            a: 5,
+            b: 4,
+            c: 3,
+            d: 2,
+            e: 1
+        }
+    );
+    assert_eq!(v.ea(), Vec2 { x: 5, y: 1 });
+}
@@ -0,0 +1,250 @@
+//! Tests of the code paths that use `unsafe` or reinterpret memory, meant to be run under Miri to
+//! check them for undefined behavior:
+//!
+//! ```text
+//! cargo +nightly miri test --test miri --features bytemuck,colors,vectors
+//! cargo +nightly miri test --test miri --target aarch64-unknown-linux-gnu
+//! ```
+//!
+//! Each test exercises every index, length, or view of a path, rather than a few examples, so that
+//! an out of bounds access or a misaligned reference is reached if there is one. They also run as
+//! ordinary tests.
+
+use swizzle::arch::swizzle_bytes;
+use swizzle::channels::{ChannelOrder, reorder_channels};
+use swizzle::packed::{Layout, swizzle_u32, swizzle_u32_in_place};
+use swizzle::reorder::{gather, gather_unchecked, scatter, scatter_unchecked};
+use swizzle::{swizzle, swizzle_dyn};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vec4 {
+    x: u32,
+    y: u32,
+    z: u32,
+    w: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq)]
+struct Vec3 {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+impl Vec4 {
+    swizzle!(table Vec4 { x, y, z, w });
+}
+
+swizzle_dyn!(sse Vec4: u32 { x, y, z, w });
+swizzle_dyn!(view Vec4 => Vec3 { x, y, z });
+
+const VALUE: Vec4 = Vec4 {
+    x: 1,
+    y: 2,
+    z: 3,
+    w: 4,
+};
+
+/// Every pattern of 4 indices, in lexicographic order.
+fn patterns() -> impl Iterator<Item = [usize; 4]> {
+    (0..256).map(|rank| [rank / 64, rank / 16 % 4, rank / 4 % 4, rank % 4])
+}
+
+#[test]
+fn test_swizzle_dyn_unchecked() {
+    for idx in patterns() {
+        // SAFETY: every index is less than 4.
+        let swizzled = unsafe { VALUE.swizzle_dyn_unchecked(idx) };
+        assert_eq!(swizzled, VALUE.swizzle_dyn(idx));
+    }
+}
+
+#[test]
+fn test_swizzle_fns() {
+    for ((name, swizzle), idx) in Vec4::SWIZZLE_FNS.iter().zip(patterns()) {
+        assert_eq!(swizzle(&VALUE), VALUE.swizzle_dyn(idx), "{name}");
+    }
+}
+
+#[test]
+fn test_swizzle_sse() {
+    for idx in patterns() {
+        assert_eq!(VALUE.swizzle_sse(idx), VALUE.swizzle_dyn(idx));
+    }
+}
+
+#[test]
+fn test_prefix_view() {
+    let mut v = VALUE;
+    assert_eq!(v.xyz_view(), &Vec3 { x: 1, y: 2, z: 3 });
+    let view = v.xyz_view_mut();
+    view.z = 10;
+    view.x += view.y;
+    assert_eq!(
+        v,
+        Vec4 {
+            x: 3,
+            y: 2,
+            z: 10,
+            w: 4
+        }
+    );
+}
+
+#[test]
+fn test_swizzle_bytes() {
+    fn check<const N: usize>() {
+        // Lengths both shorter and longer than a SIMD register, with and without a remainder.
+        for chunks in [0, 1, 2, 5, 16, 17] {
+            let mut bytes = [0u8; 17 * 17];
+            let bytes = &mut bytes[..chunks * N];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = i as u8;
+            }
+            let idx: [usize; N] = core::array::from_fn(|i| N - 1 - i);
+            swizzle_bytes(bytes, idx);
+            for (i, &byte) in bytes.iter().enumerate() {
+                assert_eq!(byte, (i / N * N + idx[i % N]) as u8);
+            }
+        }
+    }
+
+    check::<1>();
+    check::<2>();
+    check::<3>();
+    check::<4>();
+    check::<5>();
+    check::<8>();
+    check::<15>();
+    check::<16>();
+    check::<17>();
+}
+
+#[test]
+fn test_reorder_channels() {
+    // Rows of 3 RGBA pixels, padded to 16 bytes, and a short last row.
+    let mut pixels = [0u8; 16 * 3 - 4];
+    for (i, byte) in pixels.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    reorder_channels(
+        &mut pixels,
+        3,
+        3,
+        16,
+        ChannelOrder::Rgba,
+        ChannelOrder::Bgra,
+    )
+    .unwrap();
+    assert_eq!(pixels[..4], [2, 1, 0, 3]);
+    assert_eq!(pixels[12..16], [12, 13, 14, 15]);
+    assert_eq!(pixels[40..44], [42, 41, 40, 43]);
+}
+
+#[test]
+fn test_packed() {
+    let mut pixels = [0x4433_2211; 3];
+    for idx in patterns() {
+        let expected = swizzle_u32(0x4433_2211, idx);
+        let mut swizzled = pixels;
+        swizzle_u32_in_place(&mut swizzled, idx);
+        assert_eq!(swizzled, [expected; 3]);
+    }
+    Layout::Memory.convert_in_place(Layout::Msb, &mut pixels);
+    Layout::Msb.convert_in_place(Layout::Memory, &mut pixels);
+    assert_eq!(pixels, [0x4433_2211; 3]);
+}
+
+#[test]
+fn test_gather_scatter_unchecked() {
+    let src = [1, 2, 3, 4];
+    for idx in patterns() {
+        let (mut checked, mut unchecked) = ([0; 4], [0; 4]);
+        gather(&src, idx, &mut checked);
+        // SAFETY: every index is less than 4.
+        unsafe { gather_unchecked(&src, idx, &mut unchecked) };
+        assert_eq!(unchecked, checked);
+
+        scatter(&src, idx, &mut checked);
+        // SAFETY: every index is less than 4.
+        unsafe { scatter_unchecked(&src, idx, &mut unchecked) };
+        assert_eq!(unchecked, checked);
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use bytemuck::{Pod, Zeroable};
+    use swizzle::swizzle_dyn;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+    struct Rgba {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+    struct Bgra {
+        b: u8,
+        g: u8,
+        r: u8,
+        a: u8,
+    }
+
+    swizzle_dyn!(pod Rgba: u8 { r, g, b, a });
+
+    #[test]
+    fn test_pod_views() {
+        let mut pixel = Rgba {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 4,
+        };
+        assert_eq!(pixel.as_array(), &[1, 2, 3, 4]);
+        pixel.as_array_mut()[0] = 5;
+
+        let bgra: &Bgra = pixel.view();
+        assert_eq!((bgra.b, bgra.r), (5, 3));
+        let bgra: &mut Bgra = pixel.view_mut();
+        bgra.b = 6;
+        assert_eq!(pixel.r, 6);
+    }
+}
+
+#[cfg(feature = "vectors")]
+mod vectors {
+    use swizzle::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn test_prebuilt_views() {
+        let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.xyz_view(), &Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.xy_view(), &Vec2::new(1.0, 2.0));
+        v.xyz_view_mut().z = 5.0;
+        v.xy_view_mut().y = 6.0;
+        assert_eq!(v, Vec4::new(1.0, 6.0, 5.0, 4.0));
+
+        let mut v = Vec3::new(1, 2, 3);
+        v.xy_view_mut().x = 7;
+        assert_eq!(v.xy_view(), &Vec2::new(7, 2));
+    }
+}
+
+#[cfg(feature = "colors")]
+mod colors {
+    use swizzle::{Rgb, Rgba};
+
+    #[test]
+    fn test_prebuilt_views() {
+        let mut c = Rgba::new(1u8, 2, 3, 4);
+        c.rgb_view_mut().g = 5;
+        assert_eq!(c.rgb_view(), &Rgb::new(1, 5, 3));
+    }
+}